@@ -0,0 +1,405 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Vectors of complex numbers.
+//!
+//! Complex vectors store their elements interleaved, with the real part of
+//! each number at an even index of the underlying float vector, and the
+//! imaginary part directly after it. This is the layout of a slice of
+//! `(re, im)` tuples, so no shuffling is needed to load or store them.
+//!
+//! ```
+//! extern crate faster;
+//! use faster::*;
+//!
+//! # fn main() {
+//! let (a, b) = (c32s::splat(1.0, 2.0), c32s::splat(3.0, 4.0));
+//! assert_eq!(a * b, c32s::splat(-5.0, 10.0));
+//! assert_eq!((a * b) / b, a);
+//! assert_eq!(a.conj(), c32s::splat(1.0, -2.0));
+//! assert_eq!(b.abs(), f32s(5.0));
+//! # }
+//! ```
+
+use vecs::*;
+use intrin::{AddSub, Merge, Sqrt, Swizzle};
+use core_or_std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+macro_rules! impl_complex {
+    ($cvec:ident, $vec:ident, $el:ident, $iter:ident, $into:ident) => {
+        /// A SIMD vector of complex numbers, stored as interleaved real and
+        /// imaginary parts.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $cvec(pub $vec);
+
+        impl $cvec {
+            /// The number of complex numbers in this vector.
+            pub const WIDTH: usize = <$vec as Packed>::WIDTH / 2;
+
+            /// Return a vector with every element set to `re + im * i`.
+            #[inline(always)]
+            pub fn splat(re: $el, im: $el) -> Self {
+                $cvec($vec::interleave(re, im))
+            }
+
+            /// Return a vector whose nth element is the complex number
+            /// `func(n)`, given as a `(re, im)` tuple.
+            #[inline(always)]
+            pub fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> ($el, $el) {
+                let mut ret = $vec::splat(0.0);
+                for i in 0..Self::WIDTH {
+                    let (re, im) = func(i);
+                    ret = ret.replace(2 * i as u32, re).replace(2 * i as u32 + 1, im);
+                }
+                $cvec(ret)
+            }
+
+            /// Return the nth element of this vector as a `(re, im)` tuple.
+            #[inline(always)]
+            pub fn extract(&self, idx: usize) -> ($el, $el) {
+                assert!(idx < Self::WIDTH);
+                (self.0.extract(2 * idx as u32), self.0.extract(2 * idx as u32 + 1))
+            }
+
+            /// Return a vector containing the real parts of this vector at
+            /// even indices, and zeroes at odd indices.
+            #[inline(always)]
+            pub fn re(&self) -> $vec {
+                self.0 * $vec::interleave(1.0, 0.0)
+            }
+
+            /// Return a vector containing the imaginary parts of this vector
+            /// at even indices, and zeroes at odd indices.
+            #[inline(always)]
+            pub fn im(&self) -> $vec {
+                self.0.flip() * $vec::interleave(1.0, 0.0)
+            }
+
+            /// Return the complex conjugate of each element of this vector.
+            #[inline(always)]
+            pub fn conj(&self) -> Self {
+                $cvec(self.0 * $vec::interleave(1.0, -1.0))
+            }
+
+            /// Return a vector containing the squared magnitude of each
+            /// element of this vector, repeated at both of its indices.
+            #[inline(always)]
+            pub fn norm_sqr(&self) -> $vec {
+                let sq = self.0 * self.0;
+                sq + sq.flip()
+            }
+
+            /// Return a vector containing the magnitude of each element of
+            /// this vector, repeated at both of its indices.
+            #[inline(always)]
+            pub fn abs(&self) -> $vec {
+                self.norm_sqr().sqrt()
+            }
+
+            /// Return a vector containing `e` raised to the power of each
+            /// element of this vector.
+            ///
+            /// There is no vectorized exponential function yet, so this is
+            /// computed one element at a time.
+            #[inline(always)]
+            #[cfg(not(feature = "no-std"))]
+            pub fn exp(&self) -> Self {
+                let v = *self;
+                Self::from_fn(|i| {
+                    let (re, im) = v.extract(i);
+                    let (sin, cos) = im.sin_cos();
+                    (re.exp() * cos, re.exp() * sin)
+                })
+            }
+        }
+
+        impl Add for $cvec {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, other: Self) -> Self {
+                $cvec(self.0 + other.0)
+            }
+        }
+
+        impl Sub for $cvec {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, other: Self) -> Self {
+                $cvec(self.0 - other.0)
+            }
+        }
+
+        impl Mul for $cvec {
+            type Output = Self;
+
+            /// Return the complex product of `self` and `other`.
+            #[inline(always)]
+            fn mul(self, other: Self) -> Self {
+                // (a + bi)(c + di) = (ac - bd) + (bc + ad)i
+                let re = other.0.merge_interleaved(other.0.flip());
+                let im = other.0.flip().merge_interleaved(other.0);
+                $cvec((self.0 * re).addsub(self.0.flip() * im))
+            }
+        }
+
+        impl Div for $cvec {
+            type Output = Self;
+
+            /// Return the complex quotient of `self` and `other`.
+            #[inline(always)]
+            fn div(self, other: Self) -> Self {
+                $cvec((self * other.conj()).0 / other.norm_sqr())
+            }
+        }
+
+        impl Neg for $cvec {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                $cvec(self.0 * $vec::splat(-1.0))
+            }
+        }
+
+        impl AddAssign for $cvec {
+            #[inline(always)]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $cvec {
+            #[inline(always)]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl MulAssign for $cvec {
+            #[inline(always)]
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl DivAssign for $cvec {
+            #[inline(always)]
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        /// An iterator which packs a slice of `(re, im)` tuples into complex
+        /// vectors.
+        #[derive(Clone, Debug)]
+        pub struct $iter<'a> {
+            data: &'a [($el, $el)],
+            position: usize,
+            default: $cvec,
+        }
+
+        impl<'a> $iter<'a> {
+            /// Load a vector from the slice, with its first `empty_amt`
+            /// elements taken from the default vector.
+            #[inline(always)]
+            fn load(&self, offset: usize, empty_amt: usize) -> $cvec {
+                let data = self.data;
+                let default = self.default;
+                $cvec::from_fn(|i| {
+                    if i < empty_amt { default.extract(i) } else { data[offset + i - empty_amt] }
+                })
+            }
+
+            /// Return the remaining elements of the slice as a right-aligned
+            /// vector padded with elements of the default vector, and the
+            /// number of padding elements. Returns `None` if there are no
+            /// remaining elements.
+            #[inline(always)]
+            pub fn end(&mut self) -> Option<($cvec, usize)> {
+                let len = self.data.len() - self.position;
+                if len == 0 {
+                    None
+                } else {
+                    let empty_amt = $cvec::WIDTH - len;
+                    let ret = self.load(self.position, empty_amt);
+                    self.position = self.data.len();
+                    Some((ret, empty_amt))
+                }
+            }
+
+            /// Return the result of reducing every vector of the slice with
+            /// `func`, including a final vector padded with the default.
+            #[inline(always)]
+            pub fn simd_reduce<A, F>(&mut self, mut start: A, mut func: F) -> A
+                where F : FnMut(A, $cvec) -> A {
+                while let Some(v) = self.next() {
+                    start = func(start, v);
+                }
+                if let Some((v, _)) = self.end() {
+                    start = func(start, v);
+                }
+                start
+            }
+
+            /// Apply `func` to every vector of the slice, and write the
+            /// results into `out`, which must be at least as long as the
+            /// slice.
+            #[inline(always)]
+            pub fn simd_map_fill<F>(&mut self, out: &mut [($el, $el)], mut func: F)
+                where F : FnMut($cvec) -> $cvec {
+                assert!(out.len() >= self.data.len() - self.position);
+                let mut i = 0;
+                while let Some(v) = self.next() {
+                    let ret = func(v);
+                    for n in 0..$cvec::WIDTH {
+                        out[i + n] = ret.extract(n);
+                    }
+                    i += $cvec::WIDTH;
+                }
+                if let Some((v, empty_amt)) = self.end() {
+                    let ret = func(v);
+                    for n in empty_amt..$cvec::WIDTH {
+                        out[i + n - empty_amt] = ret.extract(n);
+                    }
+                }
+            }
+        }
+
+        impl<'a> Iterator for $iter<'a> {
+            type Item = $cvec;
+
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.position + $cvec::WIDTH <= self.data.len() {
+                    let ret = self.load(self.position, 0);
+                    self.position += $cvec::WIDTH;
+                    Some(ret)
+                } else {
+                    None
+                }
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = (self.data.len() - self.position) / $cvec::WIDTH;
+                (len, Some(len))
+            }
+        }
+
+        impl<'a> ExactSizeIterator for $iter<'a> {}
+
+        impl<'a> $into<'a> for [($el, $el)] {
+            #[inline(always)]
+            fn simd_iter(&'a self, default: $cvec) -> $iter<'a> {
+                $iter {
+                    data: self,
+                    position: 0,
+                    default: default,
+                }
+            }
+        }
+    }
+}
+
+/// A trait which transforms a slice of `(f32, f32)` tuples into a stream of
+/// complex vectors.
+pub trait IntoSIMDComplex32Iterator<'a> {
+    /// Return an iterator over this data which will automatically pack
+    /// values into complex vectors.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [(1.0f32, 2.0f32); 33];
+    /// let mut out = [(0.0f32, 0.0f32); 33];
+    /// data.simd_iter(c32s::splat(0.0, 0.0))
+    ///     .simd_map_fill(&mut out, |v| v * c32s::splat(3.0, 4.0));
+    /// assert_eq!(out[..], [(-5.0f32, 10.0f32); 33][..]);
+    /// # }
+    /// ```
+    fn simd_iter(&'a self, default: c32s) -> SIMDComplex32Iter<'a>;
+}
+
+/// A trait which transforms a slice of `(f64, f64)` tuples into a stream of
+/// complex vectors.
+pub trait IntoSIMDComplex64Iterator<'a> {
+    /// Return an iterator over this data which will automatically pack
+    /// values into complex vectors.
+    fn simd_iter(&'a self, default: c64s) -> SIMDComplex64Iter<'a>;
+}
+
+impl_complex!(c32s, f32s, f32, SIMDComplex32Iter, IntoSIMDComplex32Iterator);
+impl_complex!(c64s, f64s, f64, SIMDComplex64Iter, IntoSIMDComplex64Iterator);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complex_mul() {
+        assert_eq!(c32s::splat(1.0, 2.0) * c32s::splat(3.0, 4.0), c32s::splat(-5.0, 10.0));
+        assert_eq!(c64s::splat(0.0, 1.0) * c64s::splat(0.0, 1.0), c64s::splat(-1.0, 0.0));
+        let a = c32s::from_fn(|i| (i as f32, 1.0));
+        let b = c32s::from_fn(|i| (2.0, -(i as f32)));
+        for i in 0..c32s::WIDTH {
+            let n = i as f32;
+            assert_eq!((a * b).extract(i), (2.0 * n + n, 2.0 - n * n));
+        }
+    }
+
+    #[test]
+    fn complex_div() {
+        assert_eq!(c32s::splat(-5.0, 10.0) / c32s::splat(3.0, 4.0), c32s::splat(1.0, 2.0));
+        assert_eq!(c64s::splat(-1.0, 0.0) / c64s::splat(0.0, 1.0), c64s::splat(0.0, 1.0));
+    }
+
+    #[test]
+    fn complex_parts() {
+        assert_eq!(c32s::splat(1.0, 2.0).conj(), c32s::splat(1.0, -2.0));
+        assert_eq!(c64s::splat(3.0, -4.0).abs(), f64s(5.0));
+        assert_eq!(c32s::splat(1.0, 2.0).re(), f32s::interleave(1.0, 0.0));
+        assert_eq!(c32s::splat(1.0, 2.0).im(), f32s::interleave(2.0, 0.0));
+        assert_eq!(-c32s::splat(1.0, 2.0), c32s::splat(-1.0, -2.0));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn complex_exp() {
+        assert_eq!(c64s::splat(0.0, 0.0).exp(), c64s::splat(1.0, 0.0));
+        let (re, im) = c64s::splat(0.0, ::std::f64::consts::PI).exp().extract(0);
+        assert!((re + 1.0).abs() < 1e-12 && im.abs() < 1e-12);
+    }
+
+    #[test]
+    fn complex_iter() {
+        let data = [(1.0f32, 2.0f32); 5];
+        let mut iter = data.simd_iter(c32s::splat(0.0, 0.0));
+        let full = 5 / c32s::WIDTH;
+        assert_eq!(iter.len(), full);
+        assert_eq!(iter.by_ref().count(), full);
+        let tail = 5 % c32s::WIDTH;
+        match iter.end() {
+            Some((v, empty)) => {
+                assert_eq!(empty, c32s::WIDTH - tail);
+                assert_eq!(v.extract(0), (0.0, 0.0));
+                assert_eq!(v.extract(c32s::WIDTH - 1), (1.0, 2.0));
+            },
+            None => assert_eq!(tail, 0),
+        }
+        assert_eq!(iter.end(), None);
+
+        let sum = data.simd_iter(c32s::splat(0.0, 0.0))
+            .simd_reduce(c32s::splat(0.0, 0.0), |acc, v| acc + v);
+        let total = (0..c32s::WIDTH).map(|i| sum.extract(i)).fold((0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1));
+        assert_eq!(total, (5.0, 10.0));
+    }
+}
@@ -0,0 +1,371 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Runtime selection of SIMD code paths.
+//!
+//! The vector types and intrinsics of this crate are chosen when it is
+//! compiled, so a binary built for a baseline target will never use
+//! instructions which that target doesn't guarantee. The `simd_dispatch!`
+//! macro compiles a kernel several times, once for each `SIMDLevel` with the
+//! matching target features enabled, and picks the best copy the running CPU
+//! supports the first time it is called.
+
+use core_or_std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use stdsimd::vendor::*;
+
+/// A set of instruction set extensions which a kernel may be compiled for,
+/// ordered from least to most capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SIMDLevel {
+    /// No SIMD extensions; vectors are emulated with scalar code.
+    Scalar,
+    SSE2,
+    SSE41,
+    AVX2,
+}
+
+impl SIMDLevel {
+    /// Return the name of the target feature which enables this level.
+    pub fn feature(&self) -> &'static str {
+        match *self {
+            SIMDLevel::Scalar => "",
+            SIMDLevel::SSE2 => "sse2",
+            SIMDLevel::SSE41 => "sse4.1",
+            SIMDLevel::AVX2 => "avx2",
+        }
+    }
+
+    #[inline(always)]
+    fn from_usize(level: usize) -> Self {
+        match level {
+            1 => SIMDLevel::Scalar,
+            2 => SIMDLevel::SSE2,
+            3 => SIMDLevel::SSE41,
+            4 => SIMDLevel::AVX2,
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline(always)]
+    fn to_usize(&self) -> usize {
+        match *self {
+            SIMDLevel::Scalar => 1,
+            SIMDLevel::SSE2 => 2,
+            SIMDLevel::SSE41 => 3,
+            SIMDLevel::AVX2 => 4,
+        }
+    }
+}
+
+/// The detected level, offset by one so zero means "not yet detected".
+static DETECTED: AtomicUsize = AtomicUsize::new(0);
+
+/// Return the most capable `SIMDLevel` supported by the running CPU and
+/// operating system. The CPU is only queried on the first call.
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # fn main() {
+/// assert!(detect_simd_level() >= SIMDLevel::Scalar);
+/// # }
+/// ```
+#[inline]
+pub fn detect_simd_level() -> SIMDLevel {
+    let cached = DETECTED.load(Ordering::Relaxed);
+    if cached != 0 {
+        return SIMDLevel::from_usize(cached);
+    }
+
+    let level = query_simd_level();
+    DETECTED.store(level.to_usize(), Ordering::Relaxed);
+    level
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn query_simd_level() -> SIMDLevel {
+    #[cfg(target_arch = "x86")]
+    {
+        if !unsafe { has_cpuid() } {
+            return SIMDLevel::Scalar;
+        }
+    }
+
+    let max_leaf = unsafe { __cpuid(0) }.eax;
+    let leaf1 = unsafe { __cpuid(1) };
+    let sse2 = leaf1.edx & (1 << 26) != 0;
+    let sse41 = leaf1.ecx & (1 << 19) != 0;
+    let osxsave = leaf1.ecx & (1 << 27) != 0;
+    let avx = leaf1.ecx & (1 << 28) != 0;
+    let avx2 = max_leaf >= 7 && unsafe { __cpuid_count(7, 0) }.ebx & (1 << 5) != 0;
+
+    // AVX registers are only usable if the OS saves them on context switches
+    let os_avx = osxsave && unsafe { xgetbv_xcr0() } & 0x6 == 0x6;
+
+    if sse2 && sse41 && avx && avx2 && os_avx {
+        SIMDLevel::AVX2
+    } else if sse2 && sse41 {
+        SIMDLevel::SSE41
+    } else if sse2 {
+        SIMDLevel::SSE2
+    } else {
+        SIMDLevel::Scalar
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "xsave")]
+unsafe fn xgetbv_xcr0() -> u64 {
+    _xgetbv(0)
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn query_simd_level() -> SIMDLevel {
+    SIMDLevel::Scalar
+}
+
+macro_rules! level_aliases {
+    ($(#[$attr:meta])* mod $level:ident use $vecs:ident { $($pvec:ident = $vec:ident ($el:ty)),* }) => {
+        $(#[$attr])*
+        pub mod $level {
+            use $vecs::{$($vec),*};
+
+            $(
+                /// A SIMD vector of this primitive type at this level.
+                #[allow(non_camel_case_types)]
+                pub type $pvec = $vec;
+
+                /// Return a vector of this type with all elements initialized
+                /// to `data`.
+                #[inline(always)]
+                pub fn $pvec(data: $el) -> $pvec {
+                    $vec::splat(data)
+                }
+            )*
+        }
+    }
+}
+
+level_aliases!(
+    /// The vector aliases used by kernels compiled for `SIMDLevel::Scalar`,
+    /// which are emulated with scalar code.
+    #[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
+    mod scalar use shimvecs {
+        u8s = u8x16(u8), i8s = i8x16(i8), u16s = u16x8(u16), i16s = i16x8(i16),
+        u32s = u32x4(u32), i32s = i32x4(i32), f32s = f32x4(f32),
+        u64s = u64x2(u64), i64s = i64x2(i64), f64s = f64x2(f64)
+    });
+
+/// The vector aliases used by kernels compiled for `SIMDLevel::Scalar`.
+///
+/// This crate was compiled with SSE, so it can't run on a CPU without it, and
+/// the emulated vectors have none of its intrinsics. Kernels at this level
+/// use the SSE2 aliases instead.
+#[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
+pub mod scalar {
+    pub use super::sse2::*;
+}
+
+level_aliases!(
+    /// The vector aliases used by kernels compiled for `SIMDLevel::SSE2`.
+    mod sse2 use vecs {
+        u8s = u8x16(u8), i8s = i8x16(i8), u16s = u16x8(u16), i16s = i16x8(i16),
+        u32s = u32x4(u32), i32s = i32x4(i32), f32s = f32x4(f32),
+        u64s = u64x2(u64), i64s = i64x2(i64), f64s = f64x2(f64)
+    });
+
+level_aliases!(
+    /// The vector aliases used by kernels compiled for `SIMDLevel::SSE41`.
+    mod sse41 use vecs {
+        u8s = u8x16(u8), i8s = i8x16(i8), u16s = u16x8(u16), i16s = i16x8(i16),
+        u32s = u32x4(u32), i32s = i32x4(i32), f32s = f32x4(f32),
+        u64s = u64x2(u64), i64s = i64x2(i64), f64s = f64x2(f64)
+    });
+
+level_aliases!(
+    /// The vector aliases used by kernels compiled for `SIMDLevel::AVX2`.
+    mod avx2 use vecs {
+        u8s = u8x32(u8), i8s = i8x32(i8), u16s = u16x16(u16), i16s = i16x16(i16),
+        u32s = u32x8(u32), i32s = i32x8(i32), f32s = f32x8(f32),
+        u64s = u64x4(u64), i64s = i64x4(i64), f64s = f64x4(f64)
+    });
+
+/// A macro which defines a function whose body is compiled once for every
+/// `SIMDLevel`, and which runs the most capable copy supported by the CPU.
+///
+/// Each copy is compiled with the target features of its level enabled, and
+/// with the vector aliases such as `f32s` replaced by those of its level, so
+/// the AVX2 copy works on 256-bit vectors even if this crate was compiled for
+/// a baseline target. The aliases of each level live in the `scalar`, `sse2`,
+/// `sse41` and `avx2` submodules of this module.
+///
+/// Because `Packable::Vector` is still chosen when this crate is compiled,
+/// kernels should build their iterators with `simd_iter_as` rather than
+/// `simd_iter`, and should not assume a particular `WIDTH`. Crates which use
+/// this macro must enable the `target_feature` feature.
+///
+/// Only the vector types follow the dispatched level. The intrinsics of this
+/// crate, such as `abs`, `sqrt` and `upcast`, select their implementation
+/// with the target features this crate was compiled for, so they run the
+/// same instructions in every copy. Operators and code which LLVM vectorizes
+/// itself do use the instructions of the level.
+///
+/// The function may not have generic parameters or named lifetimes, though
+/// elided lifetimes such as those of `&[f32]` are fine. The return type may
+/// be left out for functions which return `()`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(target_feature)]
+/// #[macro_use] extern crate faster;
+/// use faster::*;
+///
+/// simd_dispatch! {
+///     pub fn sum_of_squares(data: &[f32]) -> f32 {
+///         data.simd_iter_as(f32s(0.0))
+///             .simd_reduce(f32s(0.0), |acc, v| acc + v * v)
+///             .sum()
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(sum_of_squares(&[2.0f32; 100]), 400.0);
+/// # }
+/// ```
+#[macro_export] macro_rules! simd_dispatch {
+    ($(#[$attr:meta])* pub fn $name:ident($($arg:ident : $ty:ty),*) -> $ret:ty $body:block) => {
+        $(#[$attr])*
+        pub fn $name($($arg : $ty),*) -> $ret {
+            simd_dispatch!(@dispatch ($($arg : $ty),*) -> $ret $body)
+        }
+    };
+    ($(#[$attr:meta])* fn $name:ident($($arg:ident : $ty:ty),*) -> $ret:ty $body:block) => {
+        $(#[$attr])*
+        fn $name($($arg : $ty),*) -> $ret {
+            simd_dispatch!(@dispatch ($($arg : $ty),*) -> $ret $body)
+        }
+    };
+    ($(#[$attr:meta])* pub fn $name:ident($($arg:ident : $ty:ty),*) $body:block) => {
+        simd_dispatch!($(#[$attr])* pub fn $name($($arg : $ty),*) -> () $body);
+    };
+    ($(#[$attr:meta])* fn $name:ident($($arg:ident : $ty:ty),*) $body:block) => {
+        simd_dispatch!($(#[$attr])* fn $name($($arg : $ty),*) -> () $body);
+    };
+    (@dispatch ($($arg:ident : $ty:ty),*) -> $ret:ty $body:block) => {{
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx2")]
+        unsafe fn avx2($($arg : $ty),*) -> $ret {
+            #[allow(unused_imports)]
+            use $crate::dispatch::avx2::*;
+            $body
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "sse4.1")]
+        unsafe fn sse41($($arg : $ty),*) -> $ret {
+            #[allow(unused_imports)]
+            use $crate::dispatch::sse41::*;
+            $body
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "sse2")]
+        unsafe fn sse2($($arg : $ty),*) -> $ret {
+            #[allow(unused_imports)]
+            use $crate::dispatch::sse2::*;
+            $body
+        }
+
+        #[inline(always)]
+        fn scalar($($arg : $ty),*) -> $ret {
+            #[allow(unused_imports)]
+            use $crate::dispatch::scalar::*;
+            $body
+        }
+
+        match $crate::dispatch::detect_simd_level() {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            $crate::dispatch::SIMDLevel::AVX2 => unsafe { avx2($($arg),*) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            $crate::dispatch::SIMDLevel::SSE41 => unsafe { sse41($($arg),*) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            $crate::dispatch::SIMDLevel::SSE2 => unsafe { sse2($($arg),*) },
+            _ => scalar($($arg),*),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prelude::*;
+
+    #[test]
+    fn detect_is_cached() {
+        let level = detect_simd_level();
+        assert_eq!(detect_simd_level(), level);
+        assert_eq!(SIMDLevel::from_usize(level.to_usize()), level);
+    }
+
+    #[test]
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    fn detect_baseline() {
+        // Every x86_64 CPU supports SSE2
+        assert!(detect_simd_level() >= SIMDLevel::SSE2);
+    }
+
+    simd_dispatch! {
+        fn dispatched_sum(a: u32, b: u32) -> u32 {
+            a + b
+        }
+    }
+
+    #[test]
+    fn dispatch_runs_kernel() {
+        assert_eq!(dispatched_sum(2, 3), 5);
+    }
+
+    simd_dispatch! {
+        fn dispatched_width() -> usize {
+            f32s::WIDTH
+        }
+    }
+
+    simd_dispatch! {
+        fn dispatched_squares(data: &[f32]) -> f32 {
+            data.simd_iter_as(f32s(0.0))
+                .simd_reduce(f32s(0.0), |acc, v| acc + v * v)
+                .sum()
+        }
+    }
+
+    simd_dispatch! {
+        fn dispatched_double(data: &mut [f32]) {
+            data.simd_iter_as_mut(f32s(0.0))
+                .simd_for_each(|v| *v = *v * f32s(2.0));
+        }
+    }
+
+    #[test]
+    fn dispatch_unit_return() {
+        let mut data = [1.5f32; 37];
+        dispatched_double(&mut data);
+        assert_eq!(&data[..], &[3.0f32; 37][..]);
+    }
+
+    #[test]
+    fn dispatch_uses_level_vectors() {
+        match detect_simd_level() {
+            SIMDLevel::AVX2 => assert_eq!(dispatched_width(), 8),
+            _ => assert_eq!(dispatched_width(), 4),
+        }
+        assert_eq!(dispatched_squares(&[3.0f32; 37]), 333.0);
+    }
+}
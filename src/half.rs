@@ -0,0 +1,857 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![allow(unused_imports)]
+
+//! Storage types for 16-bit floats, which are packed into vectors like any
+//! other primitive. Arithmetic isn't performed on these types directly;
+//! upcast them to `f32s` to operate on them, and downcast the results to
+//! store them again.
+//!
+//! ```
+//! extern crate faster;
+//! use faster::*;
+//!
+//! # fn main() {
+//! let data = [f16::from_f32(1.5); 100];
+//! let sum = data.simd_iter(f16s(f16::from_f32(0.0)))
+//!     .simd_reduce(f32s(0.0), |acc, v| {
+//!         let (lo, hi): (f32s, f32s) = v.upcast();
+//!         acc + lo + hi
+//!     }).sum();
+//! assert_eq!(sum, 150.0);
+//! # }
+//! ```
+
+use vecs::*;
+use vec_patterns::Lanes;
+use intrin::*;
+use stdsimd::vendor::*;
+use core_or_std::mem::transmute;
+use core_or_std::slice::{from_raw_parts, from_raw_parts_mut};
+use core_or_std::f32;
+
+/// An IEEE 754 half-precision float, stored as its bits.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub struct f16(u16);
+
+/// A bfloat16 float, which is the upper half of an `f32`, stored as its bits.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub struct bf16(u16);
+
+impl f16 {
+    /// Return the half-precision float nearest to `x`, with ties rounded to
+    /// the float with an even mantissa.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f16::from_f32(1.0).to_bits(), 0x3C00);
+    /// assert_eq!(f16::from_f32(65520.0).to_bits(), 0x7C00);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn from_f32(x: f32) -> Self {
+        let x: u32 = unsafe { transmute(x) };
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xFF) as i32;
+        let man = x & 0x7FFFFF;
+
+        if exp == 0xFF {
+            // Keep NaNs quiet, as the payload may be truncated to zero
+            return f16(sign | 0x7C00 | if man != 0 { 0x0200 | (man >> 13) as u16 } else { 0 });
+        }
+
+        let exp = exp - 127 + 15;
+        if exp >= 0x1F {
+            f16(sign | 0x7C00)
+        } else if exp <= 0 {
+            // Subnormal, so shift the implicit bit into the mantissa
+            if exp < -10 {
+                return f16(sign);
+            }
+            let man = man | 0x800000;
+            let shift = (14 - exp) as u32;
+            let rem = man & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            let mut ret = man >> shift;
+            if rem > half || (rem == half && (ret & 1) == 1) {
+                ret += 1;
+            }
+            f16(sign | ret as u16)
+        } else {
+            // Rounding may carry into the exponent, which is still correct
+            let mut ret = ((exp as u32) << 10) | (man >> 13);
+            let rem = man & 0x1FFF;
+            if rem > 0x1000 || (rem == 0x1000 && (ret & 1) == 1) {
+                ret += 1;
+            }
+            f16(sign | ret as u16)
+        }
+    }
+
+    /// Return this float as an `f32`. This conversion is exact.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f16::from_bits(0x3C00).to_f32(), 1.0);
+    /// assert_eq!(f16::from_bits(0x0001).to_f32(), 5.9604645e-8);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exp = ((self.0 >> 10) & 0x1F) as u32;
+        let man = (self.0 & 0x3FF) as u32;
+
+        let bits = if exp == 0x1F {
+            sign | 0x7F800000 | (man << 13)
+        } else if exp == 0 {
+            if man == 0 {
+                sign
+            } else {
+                // Subnormal, so normalize the mantissa
+                let mut exp: u32 = 127 - 15 + 1;
+                let mut man = man;
+                while man & 0x400 == 0 {
+                    man <<= 1;
+                    exp -= 1;
+                }
+                sign | (exp << 23) | ((man & 0x3FF) << 13)
+            }
+        } else {
+            sign | ((exp + 127 - 15) << 23) | (man << 13)
+        };
+        unsafe { transmute(bits) }
+    }
+}
+
+impl bf16 {
+    /// Return the bfloat16 nearest to `x`, with ties rounded to the float
+    /// with an even mantissa.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(bf16::from_f32(1.0).to_bits(), 0x3F80);
+    /// assert_eq!(bf16::from_f32(1.00390625).to_bits(), 0x3F80);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn from_f32(x: f32) -> Self {
+        let x: u32 = unsafe { transmute(x) };
+        if x & 0x7FFFFFFF > 0x7F800000 {
+            // Keep NaNs quiet, as the payload may be truncated to zero
+            bf16((x >> 16) as u16 | 0x0040)
+        } else {
+            bf16(((x + 0x7FFF + ((x >> 16) & 1)) >> 16) as u16)
+        }
+    }
+
+    /// Return this float as an `f32`. This conversion is exact.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(bf16::from_bits(0x3F80).to_f32(), 1.0);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn to_f32(self) -> f32 {
+        unsafe { transmute((self.0 as u32) << 16) }
+    }
+}
+
+macro_rules! impl_half {
+    ($el:ident) => {
+        impl $el {
+            /// Return a float with the given bit representation.
+            #[inline(always)]
+            pub fn from_bits(bits: u16) -> Self {
+                $el(bits)
+            }
+
+            /// Return the bit representation of this float.
+            #[inline(always)]
+            pub fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            #[inline(always)]
+            fn as_bits(data: &[$el]) -> &[u16] {
+                unsafe { from_raw_parts(data.as_ptr() as *const u16, data.len()) }
+            }
+
+            #[inline(always)]
+            fn as_bits_mut(data: &mut [$el]) -> &mut [u16] {
+                unsafe { from_raw_parts_mut(data.as_mut_ptr() as *mut u16, data.len()) }
+            }
+        }
+
+        // Compare as floats, rather than as bits
+        impl PartialEq for $el {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+    }
+}
+
+impl_half!(f16);
+impl_half!(bf16);
+
+macro_rules! impl_half_packed {
+    ($el:ident, $pvec:ident, $vec:ident, $uvec:ident, $width:expr, [$($feat:expr),*], [$($nfeat:expr),*]) => {
+        /// A SIMD vector of 16-bit floats, stored as their bits.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $vec(pub $uvec);
+
+        /// A SIMD vector of this float type.
+        #[allow(non_camel_case_types)]
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        pub type $pvec = $vec;
+
+        /// Return a vector of this type with all elements initialized to
+        /// `data`.
+        #[inline(always)]
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        pub fn $pvec(data: $el) -> $pvec {
+            $vec::splat(data)
+        }
+
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        impl Packable for $el {
+            type Vector = $vec;
+            const SIZE: usize = 2;
+        }
+
+        impl From<[$el; $width]> for $vec {
+            #[inline(always)]
+            fn from(data: [$el; $width]) -> $vec {
+                $vec::from_array(data)
+            }
+        }
+
+        impl From<$vec> for [$el; $width] {
+            #[inline(always)]
+            fn from(vec: $vec) -> [$el; $width] {
+                vec.to_array()
+            }
+        }
+
+        // The elements are stored as bits, so `Index` has no float to borrow
+        impl IntoIterator for $vec {
+            type Item = $el;
+            type IntoIter = Lanes<$vec>;
+
+            #[inline(always)]
+            fn into_iter(self) -> Lanes<$vec> {
+                Lanes::new(self)
+            }
+        }
+
+        // Compare as floats, rather than as bits
+        impl PartialEq for $vec {
+            fn eq(&self, other: &Self) -> bool {
+                (0..$width).all(|i| self.extract(i) == other.extract(i))
+            }
+        }
+
+        impl Packed for $vec {
+            type Scalar = $el;
+            type Array = [$el; $width];
+
+            const WIDTH: usize = $width;
+
+            #[inline(always)]
+            fn load(data: &[$el], offset: usize) -> $vec {
+                $vec($uvec::load($el::as_bits(data), offset))
+            }
+
+            #[inline(always)]
+            unsafe fn load_unchecked(data: &[$el], offset: usize) -> $vec {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                $vec($uvec::load_unchecked($el::as_bits(data), offset))
+            }
+
+            #[inline(always)]
+            fn store(self, data: &mut [$el], offset: usize) {
+                self.0.store($el::as_bits_mut(data), offset);
+            }
+
+            #[inline(always)]
+            unsafe fn store_unchecked(self, data: &mut [$el], offset: usize) {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                self.0.store_unchecked($el::as_bits_mut(data), offset);
+            }
+
+            #[inline(always)]
+            fn coalesce(self) -> Self::Scalar {
+                for i in 1..Self::WIDTH {
+                    debug_assert!(self.extract(i - 1).to_bits() == self.extract(i).to_bits());
+                }
+                self.extract(0)
+            }
+
+            #[inline(always)]
+            fn extract(&self, idx: usize) -> Self::Scalar {
+                $el(self.0.extract(idx as u32))
+            }
+
+            #[inline(always)]
+            unsafe fn extract_unchecked(&self, idx: usize) -> Self::Scalar {
+                debug_assert!(idx < Self::WIDTH);
+                $el(self.0.extract_unchecked(idx as u32))
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, idx: usize, data: Self::Scalar) -> Self {
+                $vec(self.0.replace(idx as u32, data.0))
+            }
+
+            #[inline(always)]
+            unsafe fn replace_unchecked(&mut self, idx: usize, data: Self::Scalar) -> Self {
+                debug_assert!(idx < Self::WIDTH);
+                $vec(self.0.replace_unchecked(idx as u32, data.0))
+            }
+
+            #[inline(always)]
+            fn from_array(data: Self::Array) -> Self {
+                Self::load(&data, 0)
+            }
+
+            #[inline(always)]
+            fn to_array(self) -> Self::Array {
+                let mut ret = [$el::default(); $width];
+                Packed::store(self, &mut ret, 0);
+                ret
+            }
+
+            #[inline(always)]
+            fn splat(data: $el) -> Self {
+                $vec($uvec::splat(data.0))
+            }
+
+            #[inline(always)]
+            fn default() -> Self {
+                $vec($uvec::splat(0))
+            }
+
+            #[inline(always)]
+            fn product(&self) -> Self::Scalar {
+                $el::from_f32(self.scalar_reduce(1.0, |acc, x| acc * x.to_f32()))
+            }
+
+            #[inline(always)]
+            fn scalar_reduce<T, F>(&self, mut acc: T, mut func: F) -> T
+            where F: FnMut(T, Self::Scalar) -> T {
+                for i in 0..Self::WIDTH {
+                    acc = func(acc, self.extract(i))
+                }
+                acc
+            }
+        }
+
+        impl Merge for $vec {
+            #[inline(always)]
+            fn merge_halves(&self, other: Self) -> Self {
+                $vec(self.0.merge_halves(other.0))
+            }
+
+            #[inline(always)]
+            fn merge_interleaved(&self, other: Self) -> Self {
+                $vec(self.0.merge_interleaved(other.0))
+            }
+
+            #[inline(always)]
+            fn merge_partitioned(&self, other: Self, offset: usize) -> Self {
+                $vec(self.0.merge_partitioned(other.0, offset))
+            }
+        }
+
+        impl Gather for $vec {
+            #[inline(always)]
+            unsafe fn gather_unchecked(data: &[$el], offset: usize, stride: usize) -> Self {
+                $vec($uvec::gather_unchecked($el::as_bits(data), offset, stride))
+            }
+        }
+
+        impl Eq for $vec {
+            type Out = $uvec;
+
+            #[inline(always)]
+            fn eq_mask(&self, other: Self) -> $uvec {
+                let mut ret = $uvec::splat(0);
+                for i in 0..$width {
+                    if self.extract(i) == other.extract(i) {
+                        ret = ret.replace(i as u32, 0xFFFF);
+                    }
+                }
+                ret
+            }
+        }
+    }
+}
+
+impl_half_packed!(f16, f16s, f16x32, u16x32, 32, ["avx512"], ["avx1024"]);
+impl_half_packed!(f16, f16s, f16x16, u16x16, 16, ["avx2"], ["avx512"]);
+impl_half_packed!(f16, f16s, f16x8, u16x8, 8, [], ["avx2"]);
+impl_half_packed!(bf16, bf16s, bf16x32, u16x32, 32, ["avx512"], ["avx1024"]);
+impl_half_packed!(bf16, bf16s, bf16x16, u16x16, 16, ["avx2"], ["avx512"]);
+impl_half_packed!(bf16, bf16s, bf16x8, u16x8, 8, [], ["avx2"]);
+
+macro_rules! half_upcast_fallback {
+    ($out:ident, [$($lo:expr),*], [$($hi:expr),*]) => {
+        #[inline(always)]
+        fn upcast(self) -> ($out, $out) {
+            ($out::new($(self.extract($lo).to_f32()),*),
+             $out::new($(self.extract($hi).to_f32()),*))
+        }
+    }
+}
+
+macro_rules! half_downcast_fallback {
+    ($el:ident, $out:ident, $uvec:ident, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn saturating_downcast(self, other: Self) -> $out {
+            $out($uvec::new($($el::from_f32(self.extract($n)).0,)*
+                            $($el::from_f32(other.extract($n)).0),*))
+        }
+    }
+}
+
+#[inline(always)]
+fn is_infinite(x: f32) -> bool {
+    x == f32::INFINITY || x == f32::NEG_INFINITY
+}
+
+// An element overflowed if it was finite, but became infinite
+macro_rules! checked_half_downcast {
+    ($out:ident, $uvec:ident, $inf:expr, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn checked_downcast(self, other: Self) -> ($out, $uvec) {
+            let ret = self.wrapping_downcast(other);
+            let inf = $uvec::splat($inf);
+            let inf_out = (ret.0 & $uvec::splat(0x7FFF)).eq_mask(inf);
+            let inf_in = $uvec::new($(if is_infinite(self.extract($n)) { 0xFFFF } else { 0 },)*
+                                    $(if is_infinite(other.extract($n)) { 0xFFFF } else { 0 }),*);
+            (ret, inf_out ^ inf_in)
+        }
+    }
+}
+
+impl Upcast<f32x4> for f16x8 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "f16c", not(feature = "force-scalar")))]
+    fn upcast(self) -> (f32x4, f32x4) {
+        unsafe {
+            (transmute(_mm_cvtph_ps(transmute(self.0))),
+             transmute(_mm_cvtph_ps(transmute(_mm_unpackhi_epi64(transmute(self.0), transmute(self.0))))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "f16c"), feature = "force-scalar"))]
+    half_upcast_fallback!(f32x4, [0, 1, 2, 3], [4, 5, 6, 7]);
+}
+
+impl Upcast<f32x8> for f16x16 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "f16c", not(feature = "force-scalar")))]
+    fn upcast(self) -> (f32x8, f32x8) {
+        unsafe {
+            (transmute(_mm256_cvtph_ps(_mm256_castsi256_si128(transmute(self.0)))),
+             transmute(_mm256_cvtph_ps(_mm256_extractf128_si256(transmute(self.0), 1))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "f16c"), feature = "force-scalar"))]
+    half_upcast_fallback!(f32x8, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl Upcast<f32x16> for f16x32 {
+    half_upcast_fallback!(f32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+}
+
+// Rounding to a 16-bit float is integer arithmetic on the bits of an f32, so
+// it can be done in SIMD without F16C. Both functions return the rounded bits
+// sign-extended into 32 bits, which packing with signed saturation keeps as
+// they are.
+macro_rules! half_rounding {
+    ($bf16:ident, $f16:ident, $vec:ident, $uvec:ident, $feat:tt, $srli:ident, $srai:ident, $cmpgt:ident) => {
+        #[inline(always)]
+        #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+        unsafe fn $bf16(x: $vec) -> $uvec {
+            let x: $uvec = transmute(x);
+            let odd: $uvec = transmute($srli(transmute(x), 16));
+            let rounded = x + $uvec::splat(0x7FFF) + (odd & $uvec::splat(1));
+            // Keep NaNs quiet, as the payload may be truncated to zero
+            let nan: $uvec = transmute($cmpgt(transmute(x & $uvec::splat(0x7FFFFFFF)), transmute($uvec::splat(0x7F800000))));
+            let quiet = x | $uvec::splat(0x00400000);
+            let ret = rounded ^ ((rounded ^ quiet) & nan);
+            transmute($srai(transmute(ret), 16))
+        }
+
+        #[inline(always)]
+        #[cfg(all(target_feature = $feat, not(target_feature = "f16c"), not(feature = "force-scalar")))]
+        unsafe fn $f16(x: $vec) -> $uvec {
+            let x: $uvec = transmute(x);
+            let sign = x & $uvec::splat(0x80000000);
+            let x = x ^ sign;
+            let gt = |a: $uvec, b: $uvec| -> $uvec { transmute($cmpgt(transmute(a), transmute(b))) };
+            let select = |mask: $uvec, a: $uvec, b: $uvec| b ^ ((b ^ a) & mask);
+
+            // Rebias the exponent and round the mantissa to nearest even.
+            // Rounding may carry into the exponent, which is still correct.
+            let odd: $uvec = transmute($srli(transmute(x), 13));
+            let normal = x + $uvec::splat(0xFFF) + (odd & $uvec::splat(1)) - $uvec::splat(112 << 23);
+            let normal: $uvec = transmute($srli(transmute(normal), 13));
+
+            // Adding 0.5 shifts a subnormal result's mantissa into place, and
+            // the hardware rounds it to nearest even
+            let magic = $uvec::splat(126 << 23);
+            let sub: $vec = transmute::<_, $vec>(x) + transmute::<_, $vec>(magic);
+            let sub = transmute::<_, $uvec>(sub) - magic;
+
+            let ret = select(gt($uvec::splat(113 << 23), x), sub, normal);
+            let ret = select(gt(x, $uvec::splat((143 << 23) - 1)), $uvec::splat(0x7C00), ret);
+            // Keep NaNs quiet, as the payload may be truncated to zero
+            let nan = $uvec::splat(0x7E00) | (odd & $uvec::splat(0x3FF));
+            let ret = select(gt(x, $uvec::splat(0x7F800000)), nan, ret);
+            ret | transmute($srai(transmute(sign), 16))
+        }
+    }
+}
+
+half_rounding!(round_bf16x4, round_f16x4, f32x4, u32x4, "sse2", _mm_srli_epi32, _mm_srai_epi32, _mm_cmpgt_epi32);
+half_rounding!(round_bf16x8, round_f16x8, f32x8, u32x8, "avx2", _mm256_srli_epi32, _mm256_srai_epi32, _mm256_cmpgt_epi32);
+
+impl Downcast<f16x8> for f32x4 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "f16c", not(feature = "force-scalar")))]
+    fn saturating_downcast(self, other: Self) -> f16x8 {
+        // Rounding mode 0 is round-to-nearest-even
+        unsafe {
+            f16x8(transmute(_mm_unpacklo_epi64(transmute(_mm_cvtps_ph(transmute(self), 0)),
+                                               transmute(_mm_cvtps_ph(transmute(other), 0)))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(target_feature = "f16c"), not(feature = "force-scalar")))]
+    fn saturating_downcast(self, other: Self) -> f16x8 {
+        unsafe {
+            f16x8(transmute(_mm_packs_epi32(transmute(round_f16x4(self)), transmute(round_f16x4(other)))))
+        }
+    }
+
+    #[cfg(any(all(not(target_feature = "f16c"), not(target_feature = "sse2")), feature = "force-scalar"))]
+    half_downcast_fallback!(f16, f16x8, u16x8, [0, 1, 2, 3]);
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> f16x8 {
+        self.saturating_downcast(other)
+    }
+
+    checked_half_downcast!(f16x8, u16x8, 0x7C00, [0, 1, 2, 3]);
+}
+
+impl Downcast<f16x16> for f32x8 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "f16c", not(feature = "force-scalar")))]
+    fn saturating_downcast(self, other: Self) -> f16x16 {
+        // Rounding mode 0 is round-to-nearest-even
+        unsafe {
+            f16x16(transmute(_mm256_insertf128_si256(_mm256_castsi128_si256(_mm256_cvtps_ph(transmute(self), 0)),
+                                                     _mm256_cvtps_ph(transmute(other), 0), 1)))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(target_feature = "f16c"), not(feature = "force-scalar")))]
+    fn saturating_downcast(self, other: Self) -> f16x16 {
+        // Packing works within 128-bit lanes, so put the quarters back in order
+        unsafe {
+            let packed = _mm256_packs_epi32(transmute(round_f16x8(self)), transmute(round_f16x8(other)));
+            f16x16(transmute(_mm256_permute4x64_epi64(transmute(packed), 0xD8)))
+        }
+    }
+
+    #[cfg(any(all(not(target_feature = "f16c"), not(target_feature = "avx2")), feature = "force-scalar"))]
+    half_downcast_fallback!(f16, f16x16, u16x16, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> f16x16 {
+        self.saturating_downcast(other)
+    }
+
+    checked_half_downcast!(f16x16, u16x16, 0x7C00, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl Downcast<f16x32> for f32x16 {
+    half_downcast_fallback!(f16, f16x32, u16x32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> f16x32 {
+        self.saturating_downcast(other)
+    }
+
+    checked_half_downcast!(f16x32, u16x32, 0x7C00, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl Upcast<f32x4> for bf16x8 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn upcast(self) -> (f32x4, f32x4) {
+        // Interleaving zeroes below each element shifts it into the upper half
+        unsafe {
+            let zero = u16x8::splat(0);
+            (transmute(_mm_unpacklo_epi16(transmute(zero), transmute(self.0))),
+             transmute(_mm_unpackhi_epi16(transmute(zero), transmute(self.0))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    half_upcast_fallback!(f32x4, [0, 1, 2, 3], [4, 5, 6, 7]);
+}
+
+impl Upcast<f32x8> for bf16x16 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn upcast(self) -> (f32x8, f32x8) {
+        // Interleaving zeroes below each element shifts it into the upper
+        // half. Unpacking works within each 128-bit lane, so move the second
+        // quarter of the vector into the upper lane first.
+        unsafe {
+            let zero = u16x16::splat(0);
+            let v = _mm256_permute4x64_epi64(transmute(self.0), 0xD8);
+            (transmute(_mm256_unpacklo_epi16(transmute(zero), transmute(v))),
+             transmute(_mm256_unpackhi_epi16(transmute(zero), transmute(v))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    half_upcast_fallback!(f32x8, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl Upcast<f32x16> for bf16x32 {
+    half_upcast_fallback!(f32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+}
+
+impl Downcast<bf16x8> for f32x4 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn saturating_downcast(self, other: Self) -> bf16x8 {
+        unsafe {
+            bf16x8(transmute(_mm_packs_epi32(transmute(round_bf16x4(self)), transmute(round_bf16x4(other)))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    half_downcast_fallback!(bf16, bf16x8, u16x8, [0, 1, 2, 3]);
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> bf16x8 {
+        self.saturating_downcast(other)
+    }
+
+    checked_half_downcast!(bf16x8, u16x8, 0x7F80, [0, 1, 2, 3]);
+}
+
+impl Downcast<bf16x16> for f32x8 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn saturating_downcast(self, other: Self) -> bf16x16 {
+        // Packing works within 128-bit lanes, so put the quarters back in order
+        unsafe {
+            let packed = _mm256_packs_epi32(transmute(round_bf16x8(self)), transmute(round_bf16x8(other)));
+            bf16x16(transmute(_mm256_permute4x64_epi64(transmute(packed), 0xD8)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    half_downcast_fallback!(bf16, bf16x16, u16x16, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> bf16x16 {
+        self.saturating_downcast(other)
+    }
+
+    checked_half_downcast!(bf16x16, u16x16, 0x7F80, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl Downcast<bf16x32> for f32x16 {
+    half_downcast_fallback!(bf16, bf16x32, u16x32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> bf16x32 {
+        self.saturating_downcast(other)
+    }
+
+    checked_half_downcast!(bf16x32, u16x32, 0x7F80, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+    use half::*;
+    use super::is_infinite;
+    use core_or_std::f32;
+    use core_or_std::mem::transmute;
+
+    #[test]
+    fn f16_from_f32() {
+        assert_eq!(f16::from_f32(0.0).to_bits(), 0x0000);
+        assert_eq!(f16::from_f32(-0.0).to_bits(), 0x8000);
+        assert_eq!(f16::from_f32(1.0).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f32(-2.0).to_bits(), 0xC000);
+        assert_eq!(f16::from_f32(65504.0).to_bits(), 0x7BFF);
+        assert_eq!(f16::from_f32(65519.0).to_bits(), 0x7BFF);
+        assert_eq!(f16::from_f32(65520.0).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f32(f32::INFINITY).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f32(f32::NEG_INFINITY).to_bits(), 0xFC00);
+        assert_eq!(f16::from_f32(f32::NAN).to_bits() & 0x7E00, 0x7E00);
+        // Ties round to even
+        assert_eq!(f16::from_f32(1.0 + 1.0 / 2048.0).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f32(1.0 + 3.0 / 2048.0).to_bits(), 0x3C02);
+        // Subnormals
+        assert_eq!(f16::from_f32(5.9604645e-8).to_bits(), 0x0001);
+        assert_eq!(f16::from_f32(2.9802322e-8).to_bits(), 0x0000);
+        assert_eq!(f16::from_f32(4.4703484e-8).to_bits(), 0x0001);
+        assert_eq!(f16::from_f32(6.1035156e-5).to_bits(), 0x0400);
+        assert_eq!(f16::from_f32(6.1033294e-5).to_bits(), 0x0400);
+        assert_eq!(f16::from_f32(1e-10).to_bits(), 0x0000);
+    }
+
+    #[test]
+    fn f16_roundtrip() {
+        for bits in 0..0x10000u32 {
+            let x = f16::from_bits(bits as u16);
+            if x.to_f32() == x.to_f32() {
+                assert_eq!(f16::from_f32(x.to_f32()).to_bits(), bits as u16);
+            } else {
+                assert!(f16::from_f32(x.to_f32()).to_f32().is_nan());
+            }
+        }
+    }
+
+    #[test]
+    fn bf16_from_f32() {
+        assert_eq!(bf16::from_f32(1.0).to_bits(), 0x3F80);
+        assert_eq!(bf16::from_f32(-0.0).to_bits(), 0x8000);
+        assert_eq!(bf16::from_f32(1.00390625).to_bits(), 0x3F80);
+        assert_eq!(bf16::from_f32(1.01171875).to_bits(), 0x3F82);
+        assert_eq!(bf16::from_f32(f32::MAX).to_bits(), 0x7F80);
+        assert_eq!(bf16::from_f32(f32::NEG_INFINITY).to_bits(), 0xFF80);
+        assert!(bf16::from_f32(f32::NAN).to_f32().is_nan());
+        for bits in 0..0x10000u32 {
+            let x = bf16::from_bits(bits as u16);
+            if x.to_f32() == x.to_f32() {
+                assert_eq!(bf16::from_f32(x.to_f32()).to_bits(), bits as u16);
+            }
+        }
+    }
+
+    macro_rules! test_half_vec {
+        ($name:ident, $el:ident, $vec:ident, $fvec:ident) => {
+            #[test]
+            fn $name() {
+                let vals = [0.0, -1.5, 3.0e-6, 65504.0, 1.0e6, 0.333, -1.0e-3, 42.0];
+                let a = $fvec::from_fn(|i| vals[i % vals.len()]);
+                let b = $fvec::from_fn(|i| -vals[(i + 3) % vals.len()]);
+                let h: $vec = a.saturating_downcast(b);
+                for i in 0..$fvec::WIDTH {
+                    assert_eq!(h.extract(i).to_bits(), $el::from_f32(a.extract(i as u32)).to_bits());
+                    assert_eq!(h.extract(i + $fvec::WIDTH).to_bits(), $el::from_f32(b.extract(i as u32)).to_bits());
+                }
+
+                let arr: <$vec as Packed>::Array = h.into();
+                assert_eq!($vec::from(arr), h);
+                for (i, x) in h.into_iter().enumerate() {
+                    assert_eq!(x.to_bits(), arr[i].to_bits());
+                }
+
+                let (lo, hi): ($fvec, $fvec) = h.upcast();
+                for i in 0..$fvec::WIDTH {
+                    assert_eq!(lo.extract(i as u32), h.extract(i).to_f32());
+                    assert_eq!(hi.extract(i as u32), h.extract(i + $fvec::WIDTH).to_f32());
+                }
+
+                let (_, overflow) = Downcast::<$vec>::checked_downcast(a, $fvec::splat(f32::INFINITY));
+                for i in 0..$fvec::WIDTH {
+                    let inf = is_infinite($el::from_f32(a.extract(i as u32)).to_f32());
+                    assert_eq!(overflow.extract(i as u32), if inf { 0xFFFF } else { 0 });
+                    assert_eq!(overflow.extract((i + $fvec::WIDTH) as u32), 0);
+                }
+            }
+        }
+    }
+
+    macro_rules! test_half_bits {
+        ($name:ident, $el:ident, $vec:ident, $fvec:ident) => {
+            #[test]
+            fn $name() {
+                let specials = [0x00000000u32, 0x80000000, 0x7F800000, 0xFF800000, 0x7FC00000, 0x7F800001,
+                                0xFFFFFFFF, 0x477FF000, 0x477FEFFF, 0x33000000, 0x33000001, 0x387FE000,
+                                0x3F808000, 0x3F818000, 0x3F801000, 0x3F803000, 0x00000001, 0x7F7FFFFF];
+                for n in 0..(specials.len() as u32 + 0x4000) {
+                    let bits = |i: usize| {
+                        let j = n as usize * 2 * $fvec::WIDTH + i;
+                        if j < specials.len() { specials[j] } else { (j as u32).wrapping_mul(0x9E3779B1) }
+                    };
+                    let a = $fvec::from_fn(|i| unsafe { transmute::<u32, f32>(bits(i)) });
+                    let b = $fvec::from_fn(|i| unsafe { transmute::<u32, f32>(bits(i + $fvec::WIDTH)) });
+                    let h: $vec = a.saturating_downcast(b);
+                    for i in 0..$fvec::WIDTH {
+                        assert_eq!(h.extract(i).to_bits(), $el::from_f32(a.extract(i as u32)).to_bits(), "{:x}", bits(i));
+                        assert_eq!(h.extract(i + $fvec::WIDTH).to_bits(), $el::from_f32(b.extract(i as u32)).to_bits(), "{:x}", bits(i + $fvec::WIDTH));
+                    }
+                }
+            }
+        }
+    }
+
+    test_half_bits!(half_bits_f16x8, f16, f16x8, f32x4);
+    test_half_bits!(half_bits_f16x16, f16, f16x16, f32x8);
+    test_half_bits!(half_bits_bf16x8, bf16, bf16x8, f32x4);
+    test_half_bits!(half_bits_bf16x16, bf16, bf16x16, f32x8);
+
+    test_half_vec!(half_f16x8, f16, f16x8, f32x4);
+    test_half_vec!(half_f16x16, f16, f16x16, f32x8);
+    test_half_vec!(half_f16x32, f16, f16x32, f32x16);
+    test_half_vec!(half_bf16x8, bf16, bf16x8, f32x4);
+    test_half_vec!(half_bf16x16, bf16, bf16x16, f32x8);
+    test_half_vec!(half_bf16x32, bf16, bf16x32, f32x16);
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn half_iter() {
+        let data = (0..100).map(|i| f16::from_f32(i as f32)).collect::<Vec<f16>>();
+        let doubled = data.simd_iter(f16s(f16::from_f32(0.0)))
+            .simd_map(|v| {
+                let (lo, hi): (f32s, f32s) = v.upcast();
+                Downcast::<f16s>::saturating_downcast(lo * f32s(2.0), hi * f32s(2.0))
+            })
+            .scalar_collect();
+        assert_eq!(doubled, (0..100).map(|i| f16::from_f32(i as f32 * 2.0)).collect::<Vec<f16>>());
+    }
+}
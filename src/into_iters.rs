@@ -5,8 +5,11 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use iters::{SIMDIter, SIMDIterator, SIMDObject};
+use iters::{SIMDIter, SIMDIterator, SIMDObject, AsPacked};
+use intrin::Gather;
 use vecs::*;
+use half::{f16, bf16, f16s, bf16s};
+use wrapped::{usizes, isizes, bools, u128s, i128s};
 
 /// A trait which transforms a contiguous collection into an owned stream of
 /// vectors.
@@ -17,6 +20,13 @@ pub trait IntoSIMDIterator {
     /// values into SIMD vectors. See `SIMDIterator::simd_map` and
     /// `SIMDIterator::simd_reduce` for more information.
     fn into_simd_iter(self, default: <Self::Iter as SIMDObject>::Vector) -> Self::Iter;
+
+    /// Return an iterator over this data which will automatically pack
+    /// values into vectors of type `V`, regardless of the vector size of the
+    /// compilation target.
+    #[cfg(not(feature = "no-std"))]
+    fn into_simd_iter_as<V>(self, default: V) -> SIMDIter<AsPacked<Vec<<Self::Iter as SIMDObject>::Scalar>, V>>
+        where V : Packed<Scalar = <Self::Iter as SIMDObject>::Scalar> + Gather;
 }
 
 /// A trait which transforms a contiguous collection into a slice-backed stream
@@ -28,6 +38,25 @@ pub trait IntoSIMDRefIterator<'a> {
     /// values into SIMD vectors. See `SIMDIterator::simd_map` and
     /// `SIMDIterator::simd_reduce` for more information.
     fn simd_iter(&'a self, default: <Self::Iter as SIMDObject>::Vector) -> Self::Iter;
+
+    /// Return an iterator over this data which will automatically pack
+    /// values into vectors of type `V`, regardless of the vector size of the
+    /// compilation target.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let points = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    /// let sums = points.simd_iter_as(f32x4::splat(0.0))
+    ///     .map(|v| v.sum())
+    ///     .collect::<Vec<f32>>();
+    /// assert_eq!(sums, vec![10.0, 26.0]);
+    /// # }
+    /// ```
+    fn simd_iter_as<V>(&'a self, default: V) -> SIMDIter<AsPacked<&'a [<Self::Iter as SIMDObject>::Scalar], V>>
+        where V : Packed<Scalar = <Self::Iter as SIMDObject>::Scalar> + Gather;
 }
 
 /// A trait which transforms a contiguous collection into a mutable slice-backed
@@ -39,6 +68,12 @@ pub trait IntoSIMDRefMutIterator<'a> {
     /// values into SIMD vectors. See `SIMDIterator::simd_map` and
     /// `SIMDIterator::simd_reduce` for more information.
     fn simd_iter_mut(&'a mut self, default: <Self::Iter as SIMDObject>::Vector) -> Self::Iter;
+
+    /// Return an iterator over this data which will automatically pack
+    /// values into vectors of type `V`, regardless of the vector size of the
+    /// compilation target.
+    fn simd_iter_as_mut<V>(&'a mut self, default: V) -> SIMDIter<AsPacked<&'a mut [<Self::Iter as SIMDObject>::Scalar], V>>
+        where V : Packed<Scalar = <Self::Iter as SIMDObject>::Scalar> + Gather;
 }
 
 macro_rules! impl_array_intos {
@@ -51,11 +86,23 @@ macro_rules! impl_array_intos {
                 #[inline(always)]
                 fn into_simd_iter(self, default: $vec) -> Self::Iter {
                     SIMDIter {
+                        end_position: self.len(),
                         data: self,
                         position: 0,
                         default: default,
                     }
                 }
+
+                #[inline(always)]
+                fn into_simd_iter_as<V>(self, default: V) -> SIMDIter<AsPacked<Self, V>>
+                    where V : Packed<Scalar = $el> + Gather {
+                    SIMDIter {
+                        end_position: self.len(),
+                        data: AsPacked::new(self),
+                        position: 0,
+                        default: default,
+                    }
+                }
             }
 
             impl<'a> IntoSIMDRefIterator<'a> for &'a [$el] {
@@ -64,11 +111,23 @@ macro_rules! impl_array_intos {
                 #[inline(always)]
                 fn simd_iter(&'a self, default: $vec) -> Self::Iter {
                     SIMDIter {
+                        end_position: self.len(),
                         data: self,
                         position: 0,
                         default: default,
                     }
                 }
+
+                #[inline(always)]
+                fn simd_iter_as<V>(&'a self, default: V) -> SIMDIter<AsPacked<&'a [$el], V>>
+                    where V : Packed<Scalar = $el> + Gather {
+                    SIMDIter {
+                        end_position: self.len(),
+                        data: AsPacked::new(*self),
+                        position: 0,
+                        default: default,
+                    }
+                }
             }
 
             impl<'a> IntoSIMDRefMutIterator<'a> for &'a mut [$el] {
@@ -77,11 +136,23 @@ macro_rules! impl_array_intos {
                 #[inline(always)]
                 fn simd_iter_mut(&'a mut self, default: $vec) -> Self::Iter {
                     SIMDIter {
+                        end_position: self.len(),
                         data: self,
                         position: 0,
                         default: default,
                     }
                 }
+
+                #[inline(always)]
+                fn simd_iter_as_mut<V>(&'a mut self, default: V) -> SIMDIter<AsPacked<&'a mut [$el], V>>
+                    where V : Packed<Scalar = $el> + Gather {
+                    SIMDIter {
+                        end_position: self.len(),
+                        data: AsPacked::new(&mut **self),
+                        position: 0,
+                        default: default,
+                    }
+                }
             }
 
             impl<'a> IntoSIMDRefMutIterator<'a> for [$el] {
@@ -90,11 +161,23 @@ macro_rules! impl_array_intos {
                 #[inline(always)]
                 fn simd_iter_mut(&'a mut self, default: $vec) -> Self::Iter {
                     SIMDIter {
+                        end_position: self.len(),
                         data: self,
                         position: 0,
                         default: default,
                     }
                 }
+
+                #[inline(always)]
+                fn simd_iter_as_mut<V>(&'a mut self, default: V) -> SIMDIter<AsPacked<&'a mut [$el], V>>
+                    where V : Packed<Scalar = $el> + Gather {
+                    SIMDIter {
+                        end_position: self.len(),
+                        data: AsPacked::new(self),
+                        position: 0,
+                        default: default,
+                    }
+                }
             }
 
             impl<'a> IntoSIMDRefIterator<'a> for [$el] {
@@ -103,11 +186,23 @@ macro_rules! impl_array_intos {
                 #[inline(always)]
                 fn simd_iter(&'a self, default: $vec) -> Self::Iter {
                     SIMDIter {
+                        end_position: self.len(),
                         data: self,
                         position: 0,
                         default: default,
                     }
                 }
+
+                #[inline(always)]
+                fn simd_iter_as<V>(&'a self, default: V) -> SIMDIter<AsPacked<&'a [$el], V>>
+                    where V : Packed<Scalar = $el> + Gather {
+                    SIMDIter {
+                        end_position: self.len(),
+                        data: AsPacked::new(self),
+                        position: 0,
+                        default: default,
+                    }
+                }
             }
         )*
     }
@@ -122,4 +217,11 @@ impl_array_intos!(u8, u8s,
                   f32, f32s,
                   u64, u64s,
                   i64, i64s,
-                  f64, f64s);
+                  f64, f64s,
+                  f16, f16s,
+                  bf16, bf16s,
+                  usize, usizes,
+                  isize, isizes,
+                  bool, bools,
+                  u128, u128s,
+                  i128, i128s);
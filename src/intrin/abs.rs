@@ -31,13 +31,13 @@ impl Abs for f32x4 {
     type Out = f32x4;
 
     #[inline(always)]
-    #[cfg(target_feature = "sse")]
+    #[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm_and_ps(*self, Self::splat(transmute::<u32, f32>(0x7FFFFFFF))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse"))]
+    #[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(self.extract(0).abs(),
                        self.extract(1).abs(),
@@ -50,13 +50,13 @@ impl Abs for f64x2 {
     type Out = f64x2;
 
     #[inline(always)]
-    #[cfg(target_feature = "sse2")]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm_and_pd(*self, Self::splat(transmute::<u64, f64>(0x7FFFFFFFFFFFFFFF))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse2"))]
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(self.extract(0).abs(),
                        self.extract(1).abs())
@@ -67,13 +67,13 @@ impl Abs for f32x8 {
     type Out = f32x8;
 
     #[inline(always)]
-    #[cfg(target_feature = "avx")]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm256_and_ps(*self, Self::splat(transmute::<u32, f32>(0x7FFFFFFF))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx"))]
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(self.extract(0).abs(),
                        self.extract(1).abs(),
@@ -90,13 +90,13 @@ impl Abs for f64x4 {
     type Out = f64x4;
 
     #[inline(always)]
-    #[cfg(target_feature = "avx")]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm256_and_pd(*self, Self::splat(transmute::<u64, f64>(0x7FFFFFFFFFFFFFFF))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx"))]
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(self.extract(0).abs(),
                        self.extract(1).abs(),
@@ -109,13 +109,13 @@ impl Abs for i8x16 {
     type Out = u8x16;
 
     #[inline(always)]
-    #[cfg(target_feature = "ssse3")]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm_abs_epi8(*self) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "ssse3"))]
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(unsafe { transmute::<i8, u8>(self.extract(0).overflowing_abs().0) },
                        unsafe { transmute::<i8, u8>(self.extract(1).overflowing_abs().0) },
@@ -140,13 +140,13 @@ impl Abs for i16x8 {
     type Out = u16x8;
 
     #[inline(always)]
-    #[cfg(target_feature = "ssse3")]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm_abs_epi16(*self) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "ssse3"))]
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(unsafe { transmute::<i16, u16>(self.extract(0).overflowing_abs().0) },
                        unsafe { transmute::<i16, u16>(self.extract(1).overflowing_abs().0) },
@@ -163,13 +163,13 @@ impl Abs for i32x4 {
     type Out = u32x4;
 
     #[inline(always)]
-    #[cfg(target_feature = "ssse3")]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm_abs_epi32(*self) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "ssse3"))]
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(unsafe { transmute::<i32, u32>(self.extract(0).overflowing_abs().0) },
                        unsafe { transmute::<i32, u32>(self.extract(1).overflowing_abs().0) },
@@ -182,13 +182,13 @@ impl Abs for i8x32 {
     type Out = u8x32;
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm256_abs_epi8(*self) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(unsafe { transmute::<i8, u8>(self.extract(0).overflowing_abs().0) },
                        unsafe { transmute::<i8, u8>(self.extract(1).overflowing_abs().0) },
@@ -229,13 +229,13 @@ impl Abs for i8x32 {
 impl Abs for i16x16 {
     type Out = u16x16;
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm256_abs_epi16(*self) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(unsafe { transmute::<i16, u16>(self.extract(0).overflowing_abs().0) },
                        unsafe { transmute::<i16, u16>(self.extract(1).overflowing_abs().0) },
@@ -259,13 +259,13 @@ impl Abs for i16x16 {
 impl Abs for i32x8 {
     type Out = u32x8;
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn abs(&self) -> Self::Out {
         unsafe { _mm256_abs_epi32(*self) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn abs(&self) -> Self::Out {
         Self::Out::new(unsafe { transmute::<i32, u32>(self.extract(0).overflowing_abs().0) },
                        unsafe { transmute::<i32, u32>(self.extract(1).overflowing_abs().0) },
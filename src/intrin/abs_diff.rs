@@ -0,0 +1,194 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use stdsimd::vendor::*;
+use vecs::*;
+use core_or_std::mem::transmute;
+
+pub trait AbsDiff {
+    type Out;
+
+    /// Return a vector containing the absolute differences of the elements
+    /// of `self` and `other`. The differences of signed elements are
+    /// returned as unsigned elements of the same size, so they never
+    /// overflow.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(3).abs_diff(u8s(10)), u8s(7));
+    /// assert_eq!(i8s(-128).abs_diff(i8s(127)), u8s(255));
+    /// # }
+    /// ```
+    fn abs_diff(&self, other: Self) -> Self::Out;
+}
+
+pub trait Sad {
+    type Out;
+
+    /// Return a vector containing the sums of the absolute differences of
+    /// each group of eight consecutive elements of `self` and `other`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(3).sad(u8s(10)), u64s(56));
+    /// # }
+    /// ```
+    fn sad(&self, other: Self) -> Self::Out;
+}
+
+macro_rules! abs_diff_fallback {
+    ($out:ident, $uel:ty, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn abs_diff(&self, other: Self) -> $out {
+            $out::new($({
+                let (a, b) = (self.extract($n), other.extract($n));
+                if a > b { a.wrapping_sub(b) as $uel } else { b.wrapping_sub(a) as $uel }
+            }),*)
+        }
+    }
+}
+
+macro_rules! sad_fallback {
+    ($out:ident, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn sad(&self, other: Self) -> $out {
+            let d = self.abs_diff(other);
+            $out::new($((0..8).fold(0u64, |acc, i| acc + d.extract($n * 8 + i) as u64)),*)
+        }
+    }
+}
+
+macro_rules! impl_abs_diff {
+    ($vec:ident, $out:ident, $uel:ty, [$($n:expr),*]) => {
+        impl AbsDiff for $vec {
+            type Out = $out;
+            abs_diff_fallback!($out, $uel, [$($n),*]);
+        }
+    };
+    ($vec:ident, $out:ident, $uel:ty, $feat:tt, $outer:ident($a:ident, $b:ident), [$($n:expr),*]) => {
+        impl AbsDiff for $vec {
+            type Out = $out;
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn abs_diff(&self, other: Self) -> $out {
+                unsafe {
+                    transmute($outer(transmute($a(transmute(*self), transmute(other))),
+                                     transmute($b(transmute(other), transmute(*self)))))
+                }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            abs_diff_fallback!($out, $uel, [$($n),*]);
+        }
+    };
+}
+
+impl_abs_diff!(u8x16, u8x16, u8, "sse2", _mm_or_si128(_mm_subs_epu8, _mm_subs_epu8), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_abs_diff!(u8x32, u8x32, u8, "avx2", _mm256_or_si256(_mm256_subs_epu8, _mm256_subs_epu8), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_abs_diff!(u8x64, u8x64, u8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_abs_diff!(i8x16, u8x16, u8, "sse4.1", _mm_sub_epi8(_mm_max_epi8, _mm_min_epi8), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_abs_diff!(i8x32, u8x32, u8, "avx2", _mm256_sub_epi8(_mm256_max_epi8, _mm256_min_epi8), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_abs_diff!(i8x64, u8x64, u8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_abs_diff!(u16x8, u16x8, u16, "sse2", _mm_or_si128(_mm_subs_epu16, _mm_subs_epu16), [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_abs_diff!(u16x16, u16x16, u16, "avx2", _mm256_or_si256(_mm256_subs_epu16, _mm256_subs_epu16), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_abs_diff!(u16x32, u16x32, u16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_abs_diff!(i16x8, u16x8, u16, "sse2", _mm_sub_epi16(_mm_max_epi16, _mm_min_epi16), [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_abs_diff!(i16x16, u16x16, u16, "avx2", _mm256_sub_epi16(_mm256_max_epi16, _mm256_min_epi16), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_abs_diff!(i16x32, u16x32, u16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_abs_diff!(u32x4, u32x4, u32, "sse4.1", _mm_sub_epi32(_mm_max_epu32, _mm_min_epu32), [0, 1, 2, 3]);
+impl_abs_diff!(u32x8, u32x8, u32, "avx2", _mm256_sub_epi32(_mm256_max_epu32, _mm256_min_epu32), [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_abs_diff!(u32x16, u32x16, u32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_abs_diff!(i32x4, u32x4, u32, "sse4.1", _mm_sub_epi32(_mm_max_epi32, _mm_min_epi32), [0, 1, 2, 3]);
+impl_abs_diff!(i32x8, u32x8, u32, "avx2", _mm256_sub_epi32(_mm256_max_epi32, _mm256_min_epi32), [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_abs_diff!(i32x16, u32x16, u32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_abs_diff!(u64x2, u64x2, u64, [0, 1]);
+impl_abs_diff!(u64x4, u64x4, u64, [0, 1, 2, 3]);
+impl_abs_diff!(u64x8, u64x8, u64, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_abs_diff!(i64x2, u64x2, u64, [0, 1]);
+impl_abs_diff!(i64x4, u64x4, u64, [0, 1, 2, 3]);
+impl_abs_diff!(i64x8, u64x8, u64, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+impl Sad for u8x16 {
+    type Out = u64x2;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn sad(&self, other: Self) -> u64x2 {
+        unsafe { transmute(_mm_sad_epu8(transmute(*self), transmute(other))) }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    sad_fallback!(u64x2, [0, 1]);
+}
+
+impl Sad for u8x32 {
+    type Out = u64x4;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn sad(&self, other: Self) -> u64x4 {
+        unsafe { transmute(_mm256_sad_epu8(transmute(*self), transmute(other))) }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    sad_fallback!(u64x4, [0, 1, 2, 3]);
+}
+
+impl Sad for u8x64 {
+    type Out = u64x8;
+    sad_fallback!(u64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+
+    macro_rules! test_abs_diff {
+        ($name:ident, $vec:ident, $el:ident, $wide:ident) => {
+            #[test]
+            fn $name() {
+                for_int_edge_cases!($vec, $el, |a, b| {
+                    let d = a.abs_diff(b);
+                    assert_eq!(d, b.abs_diff(a));
+                    for j in 0..$vec::WIDTH {
+                        let (x, y) = (a.extract(j as u32) as $wide, b.extract(j as u32) as $wide);
+                        assert_eq!(d.extract(j as u32) as $wide, if x > y { x - y } else { y - x });
+                    }
+                });
+            }
+        }
+    }
+
+    test_abs_diff!(abs_diff_u8s, u8s, u8, i16);
+    test_abs_diff!(abs_diff_i8s, i8s, i8, i16);
+    test_abs_diff!(abs_diff_u16s, u16s, u16, i32);
+    test_abs_diff!(abs_diff_i16s, i16s, i16, i32);
+    test_abs_diff!(abs_diff_u32s, u32s, u32, i64);
+    test_abs_diff!(abs_diff_i32s, i32s, i32, i64);
+    test_abs_diff!(abs_diff_u64s, u64s, u64, i128);
+    test_abs_diff!(abs_diff_i64s, i64s, i64, i128);
+
+    #[test]
+    fn sad() {
+        let a = u8s::from_fn(|i| (i * 37) as u8);
+        let b = u8s::from_fn(|i| (i * 91 + 5) as u8);
+        let d = a.abs_diff(b);
+        let s = a.sad(b);
+        for i in 0..u64s::WIDTH {
+            assert_eq!(s.extract(i as u32), (0..8).map(|j| d.extract((i * 8 + j) as u32) as u64).sum());
+        }
+        assert_eq!(u8s(0).sad(u8s(255)), u64s(8 * 255));
+    }
+}
@@ -9,33 +9,90 @@ use stdsimd::vendor::*;
 use vecs::{u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2};
 
 pub trait AddSub {
+    /// Return a vector containing the difference of `self` and `other` at even
+    /// indices, and their sum at odd indices.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(3.0).addsub(f32s(1.0)), f32s::interleave(2.0, 4.0));
+    /// assert_eq!(f64s(3.0).addsub(f64s(1.0)), f64s::interleave(2.0, 4.0));
+    /// # }
+    /// ```
     fn addsub(&self, other: Self) -> Self;
 }
 
-// impl AddSub for f32x4 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm_addsub_ps(*self, other) }
-//     }
-// }
-
-// impl AddSub for f64x2 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm_addsub_pd(*self, other) }
-//     }
-// }
-
-// impl AddSub for f32x8 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm256_addsub_ps(*self, other) }
-//     }
-// }
-
-// impl AddSub for f64x4 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm256_addsub_pd(*self, other) }
-//     }
-// }
+macro_rules! impl_addsub {
+    ($vec:ty, $($a:expr, $b:expr),*) => {
+        impl AddSub for $vec {
+            #[inline(always)]
+            fn addsub(&self, other: Self) -> Self {
+                Self::new($(self.extract($a) - other.extract($a),
+                            self.extract($b) + other.extract($b)),*)
+            }
+        }
+    }
+}
+
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
+impl AddSub for f32x4 {
+    #[inline(always)]
+    fn addsub(&self, other: Self) -> Self {
+        unsafe { _mm_addsub_ps(*self, other) }
+    }
+}
+
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
+impl AddSub for f64x2 {
+    #[inline(always)]
+    fn addsub(&self, other: Self) -> Self {
+        unsafe { _mm_addsub_pd(*self, other) }
+    }
+}
+
+#[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+impl AddSub for f32x8 {
+    #[inline(always)]
+    fn addsub(&self, other: Self) -> Self {
+        unsafe { _mm256_addsub_ps(*self, other) }
+    }
+}
+
+#[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+impl AddSub for f64x4 {
+    #[inline(always)]
+    fn addsub(&self, other: Self) -> Self {
+        unsafe { _mm256_addsub_pd(*self, other) }
+    }
+}
+
+#[cfg(any(not(target_feature = "sse3"), feature = "force-scalar"))]
+impl_addsub!(f32x4, 0, 1, 2, 3);
+#[cfg(any(not(target_feature = "sse3"), feature = "force-scalar"))]
+impl_addsub!(f64x2, 0, 1);
+#[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+impl_addsub!(f32x8, 0, 1, 2, 3, 4, 5, 6, 7);
+#[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+impl_addsub!(f64x4, 0, 1, 2, 3);
+impl_addsub!(f32x16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_addsub!(f64x8, 0, 1, 2, 3, 4, 5, 6, 7);
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+
+    #[test]
+    fn addsub_f32s() {
+        assert_eq!(f32s(1.0).addsub(f32s(2.0)), f32s::interleave(-1.0, 3.0));
+        assert_eq!(f32s::interleave(1.0, 2.0).addsub(f32s::interleave(3.0, 4.0)), f32s::interleave(-2.0, 6.0));
+    }
+
+    #[test]
+    fn addsub_f64s() {
+        assert_eq!(f64s(1.0).addsub(f64s(2.0)), f64s::interleave(-1.0, 3.0));
+        assert_eq!(f64s::interleave(1.0, 2.0).addsub(f64s::interleave(3.0, 4.0)), f64s::interleave(-2.0, 6.0));
+    }
+}
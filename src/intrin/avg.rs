@@ -0,0 +1,110 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use stdsimd::vendor::*;
+use vecs::*;
+use core_or_std::mem::transmute;
+
+pub trait Avg {
+    /// Return a vector containing the averages of the elements of `self` and
+    /// `other`, rounded up. The sum of the elements is computed at double
+    /// width, so it never overflows.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(255).avg(u8s(254)), u8s(255));
+    /// assert_eq!(i16s(-3).avg(i16s(0)), i16s(-1));
+    /// # }
+    /// ```
+    fn avg(&self, other: Self) -> Self;
+}
+
+macro_rules! avg_fallback {
+    ($el:ty, $wide:ty, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn avg(&self, other: Self) -> Self {
+            Self::new($(((self.extract($n) as $wide + other.extract($n) as $wide + 1) >> 1) as $el),*)
+        }
+    }
+}
+
+macro_rules! impl_avg {
+    ($vec:ident, $el:ty, $wide:ty, [$($n:expr),*]) => {
+        impl Avg for $vec {
+            avg_fallback!($el, $wide, [$($n),*]);
+        }
+    };
+    ($vec:ident, $el:ty, $wide:ty, $feat:tt, $mmfn:ident, [$($n:expr),*]) => {
+        impl Avg for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn avg(&self, other: Self) -> Self {
+                unsafe { transmute($mmfn(transmute(*self), transmute(other))) }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            avg_fallback!($el, $wide, [$($n),*]);
+        }
+    };
+}
+
+impl_avg!(u8x16, u8, u16, "sse2", _mm_avg_epu8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_avg!(u8x32, u8, u16, "avx2", _mm256_avg_epu8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_avg!(u8x64, u8, u16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_avg!(i8x16, i8, i16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_avg!(i8x32, i8, i16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_avg!(i8x64, i8, i16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_avg!(u16x8, u16, u32, "sse2", _mm_avg_epu16, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_avg!(u16x16, u16, u32, "avx2", _mm256_avg_epu16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_avg!(u16x32, u16, u32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_avg!(i16x8, i16, i32, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_avg!(i16x16, i16, i32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_avg!(i16x32, i16, i32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_avg!(u32x4, u32, u64, [0, 1, 2, 3]);
+impl_avg!(u32x8, u32, u64, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_avg!(u32x16, u32, u64, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_avg!(i32x4, i32, i64, [0, 1, 2, 3]);
+impl_avg!(i32x8, i32, i64, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_avg!(i32x16, i32, i64, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_avg!(u64x2, u64, u128, [0, 1]);
+impl_avg!(u64x4, u64, u128, [0, 1, 2, 3]);
+impl_avg!(u64x8, u64, u128, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_avg!(i64x2, i64, i128, [0, 1]);
+impl_avg!(i64x4, i64, i128, [0, 1, 2, 3]);
+impl_avg!(i64x8, i64, i128, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+
+    macro_rules! test_avg {
+        ($name:ident, $vec:ident, $el:ident, $wide:ident) => {
+            #[test]
+            fn $name() {
+                for_int_edge_cases!($vec, $el, |a, b| {
+                    assert_eq!(a.avg(b), $vec::from_fn(|j| {
+                        ((a.extract(j as u32) as $wide + b.extract(j as u32) as $wide + 1) >> 1) as $el
+                    }));
+                    assert_eq!(a.avg(a), a);
+                });
+            }
+        }
+    }
+
+    test_avg!(avg_u8s, u8s, u8, u16);
+    test_avg!(avg_i8s, i8s, i8, i16);
+    test_avg!(avg_u16s, u16s, u16, u32);
+    test_avg!(avg_i16s, i16s, i16, i32);
+    test_avg!(avg_u32s, u32s, u32, u64);
+    test_avg!(avg_i32s, i32s, i32, i64);
+    test_avg!(avg_u64s, u64s, u64, u128);
+    test_avg!(avg_i64s, i64s, i64, i128);
+}
@@ -0,0 +1,486 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use stdsimd::vendor::*;
+use vecs::{u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2};
+use core_or_std::mem::transmute;
+
+pub trait Toi32s {
+    type Out;
+
+    /// Return a vector containing the elements of `self` rounded towards zero
+    /// and converted to integers. Elements which are NaN or out of range
+    /// become `i32::MIN`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(-2.7).to_i32s_trunc(), i32s(-2));
+    /// assert_eq!(f32s(3e9).to_i32s_trunc(), i32s(i32::min_value()));
+    /// # }
+    /// ```
+    fn to_i32s_trunc(&self) -> Self::Out;
+
+    /// Return a vector containing the elements of `self` rounded to the
+    /// nearest integer, with ties rounded to the even integer, and converted
+    /// to integers. Elements which are NaN or out of range become
+    /// `i32::MIN`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.5).to_i32s_round(), i32s(2));
+    /// assert_eq!(f32s(-2.7).to_i32s_round(), i32s(-3));
+    /// # }
+    /// ```
+    fn to_i32s_round(&self) -> Self::Out;
+
+    /// Return a vector containing the elements of `self` rounded down and
+    /// converted to integers. Elements which are NaN or out of range become
+    /// `i32::MIN`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.7).to_i32s_floor(), i32s(2));
+    /// assert_eq!(f32s(-2.2).to_i32s_floor(), i32s(-3));
+    /// # }
+    /// ```
+    fn to_i32s_floor(&self) -> Self::Out;
+
+    /// Return a vector containing the elements of `self` rounded towards zero
+    /// and converted to integers. Elements which are out of range are
+    /// clamped to `i32::MIN` or `i32::MAX`, and NaNs become zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(3e9).to_i32s_saturating(), i32s(i32::max_value()));
+    /// assert_eq!(f32s(-3e9).to_i32s_saturating(), i32s(i32::min_value()));
+    /// assert_eq!(f32s(0.0 / 0.0).to_i32s_saturating(), i32s(0));
+    /// # }
+    /// ```
+    fn to_i32s_saturating(&self) -> Self::Out;
+}
+
+pub trait Toi64s {
+    type Out;
+
+    /// Return a vector containing the elements of `self` rounded towards zero
+    /// and converted to integers. Elements which are NaN or out of range
+    /// become `i64::MIN`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(-2.7).to_i64s_trunc(), i64s(-2));
+    /// assert_eq!(f64s(1e19).to_i64s_trunc(), i64s(i64::min_value()));
+    /// # }
+    /// ```
+    fn to_i64s_trunc(&self) -> Self::Out;
+
+    /// Return a vector containing the elements of `self` rounded to the
+    /// nearest integer, with ties rounded to the even integer, and converted
+    /// to integers. Elements which are NaN or out of range become
+    /// `i64::MIN`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(2.5).to_i64s_round(), i64s(2));
+    /// assert_eq!(f64s(-2.7).to_i64s_round(), i64s(-3));
+    /// # }
+    /// ```
+    fn to_i64s_round(&self) -> Self::Out;
+
+    /// Return a vector containing the elements of `self` rounded down and
+    /// converted to integers. Elements which are NaN or out of range become
+    /// `i64::MIN`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(2.7).to_i64s_floor(), i64s(2));
+    /// assert_eq!(f64s(-2.2).to_i64s_floor(), i64s(-3));
+    /// # }
+    /// ```
+    fn to_i64s_floor(&self) -> Self::Out;
+
+    /// Return a vector containing the elements of `self` rounded towards zero
+    /// and converted to integers. Elements which are out of range are
+    /// clamped to `i64::MIN` or `i64::MAX`, and NaNs become zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(1e19).to_i64s_saturating(), i64s(i64::max_value()));
+    /// assert_eq!(f64s(0.0 / 0.0).to_i64s_saturating(), i64s(0));
+    /// # }
+    /// ```
+    fn to_i64s_saturating(&self) -> Self::Out;
+}
+
+pub trait Tof32s {
+    type Out;
+
+    /// Return a vector containing the elements of `self` converted to floats,
+    /// rounded to the nearest float with ties rounded to even.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(3000000000).to_f32s(), f32s(3000000000.0));
+    /// assert_eq!(u32s(0xFFFFFFFF).to_f32s(), f32s(4294967296.0));
+    /// # }
+    /// ```
+    fn to_f32s(&self) -> Self::Out;
+}
+
+// Scalar conversions with the same results as the x86 instructions, which
+// return the "integer indefinite" value (the minimum integer) for NaN and out
+// of range elements. `$bound` is the smallest power of two which is out of
+// range, and is exactly representable as a float.
+macro_rules! scalar_conversions {
+    ($float:ty, $int:ident, $bound:expr, $trunc:ident, $round:ident, $floor:ident, $sat:ident) => {
+        #[inline(always)]
+        fn $trunc(x: $float) -> $int {
+            if x != x || x >= $bound || x < -$bound {
+                $int::min_value()
+            } else {
+                x as $int
+            }
+        }
+
+        #[inline(always)]
+        fn $round(x: $float) -> $int {
+            if x != x || x >= $bound || x < -$bound {
+                $int::min_value()
+            } else {
+                // Floats this large are integers, so `frac` is exact
+                let t = x as $int;
+                let frac = x - t as $float;
+                if frac > 0.5 || (frac == 0.5 && (t & 1) == 1) {
+                    t + 1
+                } else if frac < -0.5 || (frac == -0.5 && (t & 1) == 1) {
+                    t - 1
+                } else {
+                    t
+                }
+            }
+        }
+
+        #[inline(always)]
+        fn $floor(x: $float) -> $int {
+            if x != x || x >= $bound || x < -$bound {
+                $int::min_value()
+            } else {
+                let t = x as $int;
+                if (t as $float) > x { t - 1 } else { t }
+            }
+        }
+
+        #[inline(always)]
+        fn $sat(x: $float) -> $int {
+            if x != x {
+                0
+            } else if x >= $bound {
+                $int::max_value()
+            } else if x < -$bound {
+                $int::min_value()
+            } else {
+                x as $int
+            }
+        }
+    }
+}
+
+scalar_conversions!(f32, i32, 2147483648.0, trunc_f32, round_f32, floor_f32, saturating_f32);
+scalar_conversions!(f64, i64, 9223372036854775808.0, trunc_f64, round_f64, floor_f64, saturating_f64);
+
+macro_rules! conversion_fallback {
+    ($name:ident, $scalar:ident, $out:ident, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn $name(&self) -> $out {
+            $out::new($($scalar(self.extract($n))),*)
+        }
+    }
+}
+
+impl Toi32s for f32x4 {
+    type Out = i32x4;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn to_i32s_trunc(&self) -> i32x4 {
+        unsafe { _mm_cvttps_epi32(*self) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn to_i32s_round(&self) -> i32x4 {
+        // Rust never changes the rounding mode from round-to-nearest-even
+        unsafe { _mm_cvtps_epi32(*self) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
+    fn to_i32s_floor(&self) -> i32x4 {
+        unsafe { _mm_cvttps_epi32(_mm_floor_ps(*self)) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn to_i32s_saturating(&self) -> i32x4 {
+        unsafe {
+            // Overflowing elements become 0x80000000, so flip the bits of the
+            // positive ones to get 0x7FFFFFFF, and clear the NaNs.
+            let overflow = _mm_cmpge_ps(*self, f32x4::splat(2147483648.0));
+            let nan = _mm_cmpunord_ps(*self, *self);
+            let ret: f32x4 = transmute(_mm_cvttps_epi32(*self));
+            transmute(_mm_andnot_ps(nan, _mm_xor_ps(ret, overflow)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_trunc, trunc_f32, i32x4, [0, 1, 2, 3]);
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_round, round_f32, i32x4, [0, 1, 2, 3]);
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_floor, floor_f32, i32x4, [0, 1, 2, 3]);
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_saturating, saturating_f32, i32x4, [0, 1, 2, 3]);
+}
+
+impl Toi32s for f32x8 {
+    type Out = i32x8;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn to_i32s_trunc(&self) -> i32x8 {
+        unsafe { _mm256_cvttps_epi32(*self) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn to_i32s_round(&self) -> i32x8 {
+        // Rust never changes the rounding mode from round-to-nearest-even
+        unsafe { _mm256_cvtps_epi32(*self) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn to_i32s_floor(&self) -> i32x8 {
+        unsafe { _mm256_cvttps_epi32(_mm256_floor_ps(*self)) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn to_i32s_saturating(&self) -> i32x8 {
+        unsafe {
+            // Overflowing elements become 0x80000000, so flip the bits of the
+            // positive ones to get 0x7FFFFFFF, and clear the NaNs.
+            let overflow = _mm256_cmp_ps(*self, f32x8::splat(2147483648.0), 0x1D);
+            let nan = _mm256_cmp_ps(*self, *self, 0x03);
+            let ret: f32x8 = transmute(_mm256_cvttps_epi32(*self));
+            transmute(_mm256_andnot_ps(nan, _mm256_xor_ps(ret, overflow)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_trunc, trunc_f32, i32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_round, round_f32, i32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_floor, floor_f32, i32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+    conversion_fallback!(to_i32s_saturating, saturating_f32, i32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl Toi32s for f32x16 {
+    type Out = i32x16;
+
+    conversion_fallback!(to_i32s_trunc, trunc_f32, i32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    conversion_fallback!(to_i32s_round, round_f32, i32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    conversion_fallback!(to_i32s_floor, floor_f32, i32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    conversion_fallback!(to_i32s_saturating, saturating_f32, i32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+macro_rules! unsigned_conversion_fallback {
+    ($out:ident, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn to_f32s(&self) -> $out {
+            $out::new($(self.extract($n) as f32),*)
+        }
+    }
+}
+
+// x86 can only convert signed integers to floats. Both 16-bit halves of each
+// element convert exactly, and scaling the high half by 2^16 is exact, so the
+// final addition is the only rounding step.
+impl Tof32s for u32x4 {
+    type Out = f32x4;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn to_f32s(&self) -> f32x4 {
+        unsafe {
+            let x: i32x4 = transmute(*self);
+            let lo = _mm_cvtepi32_ps(x & i32x4::splat(0xFFFF));
+            let hi = _mm_cvtepi32_ps(_mm_srli_epi32(x, 16));
+            hi * f32x4::splat(65536.0) + lo
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    unsigned_conversion_fallback!(f32x4, [0, 1, 2, 3]);
+}
+
+impl Tof32s for u32x8 {
+    type Out = f32x8;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn to_f32s(&self) -> f32x8 {
+        unsafe {
+            let x: i32x8 = transmute(*self);
+            let lo = _mm256_cvtepi32_ps(x & i32x8::splat(0xFFFF));
+            let hi = _mm256_cvtepi32_ps(_mm256_srli_epi32(x, 16));
+            hi * f32x8::splat(65536.0) + lo
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    unsigned_conversion_fallback!(f32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl Tof32s for u32x16 {
+    type Out = f32x16;
+
+    unsigned_conversion_fallback!(f32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+// x86 can't convert packed doubles to 64-bit integers before AVX-512
+impl Toi64s for f64x2 {
+    type Out = i64x2;
+
+    conversion_fallback!(to_i64s_trunc, trunc_f64, i64x2, [0, 1]);
+    conversion_fallback!(to_i64s_round, round_f64, i64x2, [0, 1]);
+    conversion_fallback!(to_i64s_floor, floor_f64, i64x2, [0, 1]);
+    conversion_fallback!(to_i64s_saturating, saturating_f64, i64x2, [0, 1]);
+}
+
+impl Toi64s for f64x4 {
+    type Out = i64x4;
+
+    conversion_fallback!(to_i64s_trunc, trunc_f64, i64x4, [0, 1, 2, 3]);
+    conversion_fallback!(to_i64s_round, round_f64, i64x4, [0, 1, 2, 3]);
+    conversion_fallback!(to_i64s_floor, floor_f64, i64x4, [0, 1, 2, 3]);
+    conversion_fallback!(to_i64s_saturating, saturating_f64, i64x4, [0, 1, 2, 3]);
+}
+
+impl Toi64s for f64x8 {
+    type Out = i64x8;
+
+    conversion_fallback!(to_i64s_trunc, trunc_f64, i64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+    conversion_fallback!(to_i64s_round, round_f64, i64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+    conversion_fallback!(to_i64s_floor, floor_f64, i64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+    conversion_fallback!(to_i64s_saturating, saturating_f64, i64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+    use core_or_std::{f32, f64, i32, i64};
+
+    macro_rules! test_conversions {
+        ($name:ident, $vec:ident, $el:ident, $int:ident, ($trunc:ident, $round:ident, $floor:ident, $sat:ident),
+         [$(($x:expr, $t:expr, $r:expr, $f:expr, $s:expr)),*]) => {
+            #[test]
+            fn $name() {
+                let cases: &[($el, $int, $int, $int, $int)] = &[$(($x, $t, $r, $f, $s)),*];
+                for start in 0..cases.len() {
+                    // Place each case in every lane
+                    let at = |i: usize| cases[(start + i) % cases.len()];
+                    let v = $vec::from_fn(|i| at(i).0);
+                    for i in 0..$vec::WIDTH {
+                        let (x, t, r, f, s) = at(i);
+                        assert_eq!(v.$trunc().extract(i as u32), t, "trunc {}", x);
+                        assert_eq!(v.$round().extract(i as u32), r, "round {}", x);
+                        assert_eq!(v.$floor().extract(i as u32), f, "floor {}", x);
+                        assert_eq!(v.$sat().extract(i as u32), s, "saturating {}", x);
+                    }
+                }
+            }
+        }
+    }
+
+    test_conversions!(convert_f32s, f32s, f32, i32, (to_i32s_trunc, to_i32s_round, to_i32s_floor, to_i32s_saturating),
+                      [(f32::NAN, i32::MIN, i32::MIN, i32::MIN, 0),
+                       (f32::INFINITY, i32::MIN, i32::MIN, i32::MIN, i32::MAX),
+                       (f32::NEG_INFINITY, i32::MIN, i32::MIN, i32::MIN, i32::MIN),
+                       (2147483648.0, i32::MIN, i32::MIN, i32::MIN, i32::MAX),
+                       (2147483520.0, 2147483520, 2147483520, 2147483520, 2147483520),
+                       (-2147483648.0, i32::MIN, i32::MIN, i32::MIN, i32::MIN),
+                       (-2147483904.0, i32::MIN, i32::MIN, i32::MIN, i32::MIN),
+                       (-0.0, 0, 0, 0, 0),
+                       (0.7, 0, 1, 0, 0),
+                       (-0.5, 0, 0, -1, 0),
+                       (2.5, 2, 2, 2, 2),
+                       (3.5, 3, 4, 3, 3),
+                       (-2.5, -2, -2, -3, -2),
+                       (-1.7, -1, -2, -2, -1)]);
+
+    #[test]
+    fn convert_u32s() {
+        let cases = [0u32, 1, 0xFFFF, 0x10000, 0x7FFFFFFF, 0x80000000, 0x80000001,
+                     0x80000080, 0x80000081, 0x80000180, 3000000000, 0xFFFFFF7F,
+                     0xFFFFFF80, 0xFFFFFFFF];
+        for start in 0..cases.len() {
+            let v = u32s::from_fn(|i| cases[(start + i) % cases.len()]);
+            let f = v.to_f32s();
+            for i in 0..u32s::WIDTH {
+                let x = v.extract(i as u32);
+                assert_eq!(f.extract(i as u32), x as f32, "{}", x);
+            }
+        }
+    }
+
+    test_conversions!(convert_f64s, f64s, f64, i64, (to_i64s_trunc, to_i64s_round, to_i64s_floor, to_i64s_saturating),
+                      [(f64::NAN, i64::MIN, i64::MIN, i64::MIN, 0),
+                       (f64::INFINITY, i64::MIN, i64::MIN, i64::MIN, i64::MAX),
+                       (f64::NEG_INFINITY, i64::MIN, i64::MIN, i64::MIN, i64::MIN),
+                       (9223372036854775808.0, i64::MIN, i64::MIN, i64::MIN, i64::MAX),
+                       (9223372036854774784.0, 9223372036854774784, 9223372036854774784, 9223372036854774784, 9223372036854774784),
+                       (-9223372036854775808.0, i64::MIN, i64::MIN, i64::MIN, i64::MIN),
+                       (-9223372036854777856.0, i64::MIN, i64::MIN, i64::MIN, i64::MIN),
+                       (-0.0, 0, 0, 0, 0),
+                       (0.7, 0, 1, 0, 0),
+                       (-0.5, 0, 0, -1, 0),
+                       (2.5, 2, 2, 2, 2),
+                       (3.5, 3, 4, 3, 3),
+                       (-2.5, -2, -2, -3, -2),
+                       (-1.7, -1, -2, -2, -1)]);
+}
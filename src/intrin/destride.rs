@@ -4,7 +4,19 @@ use intrin::{Merge, Transmute};
 use core_or_std::mem::transmute;
 
 pub trait Destride : Sized {
+    /// Whether `destride_two` deinterleaves whole vectors, rather than moving
+    /// one lane at a time. Strided loads gather the elements of vectors which
+    /// don't.
+    const SHUFFLES_TWO: bool = true;
+
+    /// Whether `destride_three` deinterleaves whole vectors.
+    const SHUFFLES_THREE: bool = false;
+
+    /// Whether `destride_four` deinterleaves whole vectors.
+    const SHUFFLES_FOUR: bool = true;
+
     fn destride_two(self, other: Self) -> (Self, Self);
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self);
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self);
 }
 
@@ -41,10 +53,32 @@ macro_rules! destride_four_polyfill {
     }
 }
 
+/// Gather every `stride`th element of the concatenation of `src`, beginning
+/// at `channel`, into a single vector.
+#[inline(always)]
+fn destride_polyfill<T : Packed>(src: &[T], stride: usize, channel: usize) -> T {
+    let mut ret = src[0];
+    for i in 0..T::WIDTH {
+        let n = i * stride + channel;
+        ret = ret.replace(i, src[n / T::WIDTH].extract(n % T::WIDTH));
+    }
+    ret
+}
+
+macro_rules! destride_three_polyfill {
+    ($self:expr, $b:expr, $c:expr) => {
+        (destride_polyfill(&[$self, $b, $c], 3, 0),
+         destride_polyfill(&[$self, $b, $c], 3, 1),
+         destride_polyfill(&[$self, $b, $c], 3, 2))
+    }
+}
 
 impl Destride for u8x16 {
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    const SHUFFLES_THREE: bool = true;
+
     #[inline(always)]
-    #[cfg(target_feature = "ssse3")]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         unsafe {
             let a = _mm_shuffle_epi8(self, Self::new(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15));
@@ -56,11 +90,34 @@ impl Destride for u8x16 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "ssse3"))]
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14)
     }
 
+    #[inline(always)]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        unsafe {
+            let x = _mm_shuffle_epi8(self, Self::new(0, 3, 6, 9, 12, 15, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80))
+                | _mm_shuffle_epi8(b, Self::new(0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 2, 5, 8, 11, 14, 0x80, 0x80, 0x80, 0x80, 0x80))
+                | _mm_shuffle_epi8(c, Self::new(0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 1, 4, 7, 10, 13));
+            let y = _mm_shuffle_epi8(self, Self::new(1, 4, 7, 10, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80))
+                | _mm_shuffle_epi8(b, Self::new(0x80, 0x80, 0x80, 0x80, 0x80, 0, 3, 6, 9, 12, 15, 0x80, 0x80, 0x80, 0x80, 0x80))
+                | _mm_shuffle_epi8(c, Self::new(0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 2, 5, 8, 11, 14));
+            let z = _mm_shuffle_epi8(self, Self::new(2, 5, 8, 11, 14, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80))
+                | _mm_shuffle_epi8(b, Self::new(0x80, 0x80, 0x80, 0x80, 0x80, 1, 4, 7, 10, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80))
+                | _mm_shuffle_epi8(c, Self::new(0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0, 3, 6, 9, 12, 15));
+            (x, y, z)
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12)
@@ -69,7 +126,7 @@ impl Destride for u8x16 {
 
 impl Destride for u8x32 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         unsafe {
             // In-lane destrided vectors
@@ -85,11 +142,16 @@ impl Destride for u8x32 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30)
     }
 
+    #[inline(always)]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12, 16, 20, 24, 28)
@@ -97,8 +159,11 @@ impl Destride for u8x32 {
 }
 
 impl Destride for i8x16 {
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    const SHUFFLES_THREE: bool = true;
+
     #[inline(always)]
-    #[cfg(target_feature = "ssse3")]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         unsafe {
             let a = _mm_shuffle_epi8(transmute(self), transmute(Self::new(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15)));
@@ -110,11 +175,24 @@ impl Destride for i8x16 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "ssse3"))]
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14)
     }
 
+    #[inline(always)]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        let (x, y, z) = self.be_u8s().destride_three(b.be_u8s(), c.be_u8s());
+        (x.be_i8s(), y.be_i8s(), z.be_i8s())
+    }
+
+    #[inline(always)]
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12)
@@ -123,7 +201,7 @@ impl Destride for i8x16 {
 
 impl Destride for i8x32 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         unsafe {
             // In-lane destrided vectors
@@ -139,11 +217,16 @@ impl Destride for i8x32 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn destride_two(self, other: Self) -> (Self, Self) {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30)
     }
 
+    #[inline(always)]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12, 16, 20, 24, 28)
@@ -151,6 +234,31 @@ impl Destride for i8x32 {
 }
 
 macro_rules! impl_destride {
+    ($t:ty) => {
+        impl Destride for $t {
+            const SHUFFLES_TWO: bool = false;
+            const SHUFFLES_FOUR: bool = false;
+
+            #[inline(always)]
+            fn destride_two(self, other: Self) -> (Self, Self) {
+                (destride_polyfill(&[self, other], 2, 0),
+                 destride_polyfill(&[self, other], 2, 1))
+            }
+
+            #[inline(always)]
+            fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+                destride_three_polyfill!(self, b, c)
+            }
+
+            #[inline(always)]
+            fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
+                (destride_polyfill(&[self, b, c, d], 4, 0),
+                 destride_polyfill(&[self, b, c, d], 4, 1),
+                 destride_polyfill(&[self, b, c, d], 4, 2),
+                 destride_polyfill(&[self, b, c, d], 4, 3))
+            }
+        }
+    };
     ($t:ty, $($two:expr, $four:expr),*) => {
         impl Destride for $t {
             #[inline(always)]
@@ -158,6 +266,11 @@ macro_rules! impl_destride {
                 destride_two_polyfill!(self, other, $($two, $four),*)
             }
 
+            #[inline(always)]
+            fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+                destride_three_polyfill!(self, b, c)
+            }
+
             #[inline(always)]
             fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
                 destride_four_polyfill!(self, b, c, d, $($two),*)
@@ -175,6 +288,26 @@ impl_destride!(u32x8, 0, 2, 4, 6);
 impl_destride!(u32x4, 0, 2);
 impl_destride!(i32x8, 0, 2, 4, 6);
 impl_destride!(i32x4, 0, 2);
+impl_destride!(f32x8, 0, 2, 4, 6);
+impl_destride!(f32x4, 0, 2);
+
+impl_destride!(u64x4, 0, 2);
+impl_destride!(u64x2);
+impl_destride!(i64x4, 0, 2);
+impl_destride!(i64x2);
+impl_destride!(f64x4, 0, 2);
+impl_destride!(f64x2);
+
+impl_destride!(u8x64, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+impl_destride!(i8x64, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+impl_destride!(u16x32, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+impl_destride!(i16x32, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+impl_destride!(u32x16, 0, 2, 4, 6, 8, 10, 12, 14);
+impl_destride!(i32x16, 0, 2, 4, 6, 8, 10, 12, 14);
+impl_destride!(f32x16, 0, 2, 4, 6, 8, 10, 12, 14);
+impl_destride!(u64x8, 0, 2, 4, 6);
+impl_destride!(i64x8, 0, 2, 4, 6);
+impl_destride!(f64x8, 0, 2, 4, 6);
 
 #[cfg(test)]
 mod tests {
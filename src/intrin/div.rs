@@ -0,0 +1,663 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use stdsimd::vendor::*;
+use vecs::*;
+use intrin::mul::*;
+use core_or_std::mem::transmute;
+use core_or_std::ops::{Div, Rem};
+
+/// Lane-wise integer division. The `/` and `%` operators of the vector types
+/// already divide lane by lane, like the scalar operators, but they give no
+/// defined result when a signed quotient overflows. These methods are their
+/// wrapping counterparts, in the same way that `i32::wrapping_div` relates to
+/// `/`. To divide many vectors by the same number, use a `Divider`, which
+/// replaces the division with multiplications.
+pub trait IntDiv {
+    /// Return a vector containing the quotients of the elements of `self`
+    /// and `other`, rounded towards zero. Dividing the minimum value of a
+    /// signed type by -1 wraps, as with `wrapping_div`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if any element of `other` is zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32s(-7).wrapping_div(i32s(2)), i32s(-3));
+    /// assert_eq!(u8s(200).wrapping_div(u8s(7)), u8s(28));
+    /// # }
+    /// ```
+    fn wrapping_div(&self, other: Self) -> Self;
+
+    /// Return a vector containing the remainders of the elements of `self`
+    /// and `other`, which have the sign of the element of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if any element of `other` is zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32s(-7).wrapping_rem(i32s(2)), i32s(-1));
+    /// assert_eq!(u8s(200).wrapping_rem(u8s(7)), u8s(4));
+    /// # }
+    /// ```
+    fn wrapping_rem(&self, other: Self) -> Self;
+}
+
+// x86 has no integer division instructions for vectors
+macro_rules! impl_int_div {
+    ($vec:ident, [$($n:expr),*]) => {
+        impl IntDiv for $vec {
+            #[inline(always)]
+            fn wrapping_div(&self, other: Self) -> Self {
+                Self::new($(self.extract($n).wrapping_div(other.extract($n))),*)
+            }
+
+            #[inline(always)]
+            fn wrapping_rem(&self, other: Self) -> Self {
+                Self::new($(self.extract($n).wrapping_rem(other.extract($n))),*)
+            }
+        }
+    }
+}
+
+impl_int_div!(u8x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_int_div!(i8x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_int_div!(u16x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_int_div!(i16x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_int_div!(u32x4, [0, 1, 2, 3]);
+impl_int_div!(i32x4, [0, 1, 2, 3]);
+impl_int_div!(u64x2, [0, 1]);
+impl_int_div!(i64x2, [0, 1]);
+impl_int_div!(u8x32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_int_div!(i8x32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_int_div!(u16x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_int_div!(i16x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_int_div!(u32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_int_div!(i32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_int_div!(u64x4, [0, 1, 2, 3]);
+impl_int_div!(i64x4, [0, 1, 2, 3]);
+impl_int_div!(u8x64, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_int_div!(i8x64, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_int_div!(u16x32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_int_div!(i16x32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_int_div!(u32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_int_div!(i32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_int_div!(u64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_int_div!(i64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+/// A divisor with a precomputed reciprocal, which divides the elements of a
+/// vector of type `T` using a high multiplication and shifts, rather than
+/// division. Construct one for divisors which are only known at runtime, but
+/// which divide many vectors.
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # fn main() {
+/// let buckets = Divider::<u32s>::new(7);
+/// assert_eq!(u32s(100) / buckets, u32s(14));
+/// assert_eq!(u32s(100) % buckets, u32s(2));
+///
+/// let scale = Divider::<i16s>::new(-3);
+/// assert_eq!(i16s(-100) / scale, i16s(33));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Divider<T> where T : Packed {
+    divisor: T::Scalar,
+    magic: T::Scalar,
+    // Unsigned dividers shift by `more` before adding the high product, and
+    // signed dividers add `more` times the dividend to the high product.
+    more: i8,
+    shift: u32,
+}
+
+/// Return the magic number and shifts for unsigned division by `d`, using
+/// the "round-up" method of Granlund and Montgomery.
+fn unsigned_magic(d: u64, bits: u32) -> (u64, i8, u32) {
+    assert!(d != 0, "attempt to divide by zero");
+    let l = if d == 1 { 0 } else { 64 - (d - 1).leading_zeros() };
+    let magic = ((((1u128 << l) - d as u128) << bits) / d as u128) + 1;
+    (magic as u64, if l > 0 { 1 } else { 0 }, if l > 1 { l - 1 } else { 0 })
+}
+
+/// Return the magic number, correction and shift for signed division by `d`,
+/// which must not be -1, 0 or 1, using the method in Hacker's Delight.
+fn signed_magic(d: i64, bits: u32) -> (i64, i8, u32) {
+    let two = 1u128 << (bits - 1);
+    let ad = (if d < 0 { -(d as i128) } else { d as i128 }) as u128;
+    let t = two + if d < 0 { 1 } else { 0 };
+    let anc = t - 1 - t % ad;
+    let mut p = bits - 1;
+    let (mut q1, mut r1) = (two / anc, two % anc);
+    let (mut q2, mut r2) = (two / ad, two % ad);
+    loop {
+        p += 1;
+        q1 *= 2;
+        r1 *= 2;
+        if r1 >= anc {
+            q1 += 1;
+            r1 -= anc;
+        }
+        q2 *= 2;
+        r2 *= 2;
+        if r2 >= ad {
+            q2 += 1;
+            r2 -= ad;
+        }
+        let delta = ad - r2;
+        if q1 > delta || (q1 == delta && r1 != 0) {
+            break;
+        }
+    }
+
+    // Sign-extend the magic number from `bits` bits
+    let magic = (q2 + 1) as i128;
+    let magic = if d < 0 { -magic } else { magic };
+    let magic = (magic << (128 - bits)) >> (128 - bits);
+    let more = if d > 0 && magic < 0 { 1 } else if d < 0 && magic > 0 { -1 } else { 0 };
+    (magic as i64, more, p - bits)
+}
+
+macro_rules! unsigned_divide_fallback {
+    ($vec:ident, $el:ident, $wide:ty, $bits:expr, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn divide(&self, n: $vec) -> $vec {
+            $vec::new($({
+                let n = n.extract($n);
+                let t = ((self.magic as $wide * n as $wide) >> $bits) as $el;
+                (t + ((n - t) >> self.more)) >> self.shift
+            }),*)
+        }
+    }
+}
+
+macro_rules! signed_divide_fallback {
+    ($vec:ident, $el:ident, $wide:ty, $bits:expr, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn divide(&self, n: $vec) -> $vec {
+            $vec::new($({
+                let n = n.extract($n);
+                let t = ((self.magic as $wide * n as $wide) >> $bits) as $el;
+                let q = t.wrapping_add(n.wrapping_mul(self.more as $el)) >> self.shift;
+                // Round towards zero, rather than down
+                q - (q >> ($bits - 1))
+            }),*)
+        }
+    }
+}
+
+macro_rules! impl_divider {
+    (unsigned $vec:ident, $el:ident, $bits:expr) => {
+        impl Divider<$vec> {
+            /// Return a divider which divides each element of a vector by
+            /// `divisor`.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `divisor` is zero.
+            #[inline(always)]
+            pub fn new(divisor: $el) -> Self {
+                let (magic, more, shift) = unsigned_magic(divisor as u64, $bits);
+                Divider { divisor: divisor, magic: magic as $el, more: more, shift: shift }
+            }
+
+            /// Return the number which this divider divides by.
+            #[inline(always)]
+            pub fn divisor(&self) -> $el {
+                self.divisor
+            }
+        }
+
+        impl Rem<Divider<$vec>> for $vec {
+            type Output = $vec;
+
+            #[inline(always)]
+            fn rem(self, divider: Divider<$vec>) -> $vec {
+                self - (self / divider) * $vec::splat(divider.divisor)
+            }
+        }
+
+        impl_divider!($vec);
+    };
+    (signed $vec:ident, $el:ident, $bits:expr) => {
+        impl Divider<$vec> {
+            /// Return a divider which divides each element of a vector by
+            /// `divisor`, rounding towards zero.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `divisor` is zero.
+            #[inline(always)]
+            pub fn new(divisor: $el) -> Self {
+                assert!(divisor != 0, "attempt to divide by zero");
+                let (magic, more, shift) = if divisor == 1 || divisor == -1 {
+                    (0, 0, 0)
+                } else {
+                    signed_magic(divisor as i64, $bits)
+                };
+                Divider { divisor: divisor, magic: magic as $el, more: more, shift: shift }
+            }
+
+            /// Return the number which this divider divides by.
+            #[inline(always)]
+            pub fn divisor(&self) -> $el {
+                self.divisor
+            }
+        }
+
+        impl Rem<Divider<$vec>> for $vec {
+            type Output = $vec;
+
+            #[inline(always)]
+            fn rem(self, divider: Divider<$vec>) -> $vec {
+                if divider.divisor == 1 || divider.divisor == -1 {
+                    $vec::splat(0)
+                } else {
+                    self - (self / divider) * $vec::splat(divider.divisor)
+                }
+            }
+        }
+
+        impl Div<Divider<$vec>> for $vec {
+            type Output = $vec;
+
+            #[inline(always)]
+            fn div(self, divider: Divider<$vec>) -> $vec {
+                // The magic number can't represent these divisors
+                match divider.divisor {
+                    1 => self,
+                    -1 => $vec::from_fn(|i| self.extract(i as u32).wrapping_neg()),
+                    _ => divider.divide(self)
+                }
+            }
+        }
+    };
+    ($vec:ident) => {
+        impl Div<Divider<$vec>> for $vec {
+            type Output = $vec;
+
+            #[inline(always)]
+            fn div(self, divider: Divider<$vec>) -> $vec {
+                divider.divide(self)
+            }
+        }
+    }
+}
+
+impl_divider!(unsigned u8x16, u8, 8);
+impl_divider!(unsigned u8x32, u8, 8);
+impl_divider!(unsigned u8x64, u8, 8);
+impl_divider!(unsigned u16x8, u16, 16);
+impl_divider!(unsigned u16x16, u16, 16);
+impl_divider!(unsigned u16x32, u16, 16);
+impl_divider!(unsigned u32x4, u32, 32);
+impl_divider!(unsigned u32x8, u32, 32);
+impl_divider!(unsigned u32x16, u32, 32);
+impl_divider!(unsigned u64x2, u64, 64);
+impl_divider!(unsigned u64x4, u64, 64);
+impl_divider!(unsigned u64x8, u64, 64);
+impl_divider!(signed i8x16, i8, 8);
+impl_divider!(signed i8x32, i8, 8);
+impl_divider!(signed i8x64, i8, 8);
+impl_divider!(signed i16x8, i16, 16);
+impl_divider!(signed i16x16, i16, 16);
+impl_divider!(signed i16x32, i16, 16);
+impl_divider!(signed i32x4, i32, 32);
+impl_divider!(signed i32x8, i32, 32);
+impl_divider!(signed i32x16, i32, 32);
+impl_divider!(signed i64x2, i64, 64);
+impl_divider!(signed i64x4, i64, 64);
+impl_divider!(signed i64x8, i64, 64);
+
+/// Division of a vector by a `Divider`.
+trait Divide<T> {
+    fn divide(&self, n: T) -> T;
+}
+
+impl Divide<u16x8> for Divider<u16x8> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn divide(&self, n: u16x8) -> u16x8 {
+        unsafe {
+            let t: u16x8 = transmute(_mm_mulhi_epu16(transmute(u16x8::splat(self.magic)), transmute(n)));
+            let d: u16x8 = transmute(_mm_sub_epi16(transmute(n), transmute(t)));
+            let d: u16x8 = transmute(_mm_srl_epi16(transmute(d), transmute(_mm_cvtsi32_si128(self.more as i32))));
+            let q: u16x8 = transmute(_mm_add_epi16(transmute(t), transmute(d)));
+            transmute(_mm_srl_epi16(transmute(q), transmute(_mm_cvtsi32_si128(self.shift as i32))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    unsigned_divide_fallback!(u16x8, u16, u32, 16, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl Divide<u16x16> for Divider<u16x16> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn divide(&self, n: u16x16) -> u16x16 {
+        unsafe {
+            let t: u16x16 = transmute(_mm256_mulhi_epu16(transmute(u16x16::splat(self.magic)), transmute(n)));
+            let d: u16x16 = transmute(_mm256_sub_epi16(transmute(n), transmute(t)));
+            let d: u16x16 = transmute(_mm256_srl_epi16(transmute(d), transmute(_mm_cvtsi32_si128(self.more as i32))));
+            let q: u16x16 = transmute(_mm256_add_epi16(transmute(t), transmute(d)));
+            transmute(_mm256_srl_epi16(transmute(q), transmute(_mm_cvtsi32_si128(self.shift as i32))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    unsigned_divide_fallback!(u16x16, u16, u32, 16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl Divide<i16x8> for Divider<i16x8> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn divide(&self, n: i16x8) -> i16x8 {
+        unsafe {
+            let t: i16x8 = transmute(_mm_mulhi_epi16(transmute(i16x8::splat(self.magic)), transmute(n)));
+            let t: i16x8 = match self.more {
+                1 => transmute(_mm_add_epi16(transmute(t), transmute(n))),
+                -1 => transmute(_mm_sub_epi16(transmute(t), transmute(n))),
+                _ => t
+            };
+            let q: i16x8 = transmute(_mm_sra_epi16(transmute(t), transmute(_mm_cvtsi32_si128(self.shift as i32))));
+            // Round towards zero, rather than down
+            let sign: i16x8 = transmute(_mm_srai_epi16(transmute(q), 15));
+            transmute(_mm_sub_epi16(transmute(q), transmute(sign)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    signed_divide_fallback!(i16x8, i16, i32, 16, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl Divide<i16x16> for Divider<i16x16> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn divide(&self, n: i16x16) -> i16x16 {
+        unsafe {
+            let t: i16x16 = transmute(_mm256_mulhi_epi16(transmute(i16x16::splat(self.magic)), transmute(n)));
+            let t: i16x16 = match self.more {
+                1 => transmute(_mm256_add_epi16(transmute(t), transmute(n))),
+                -1 => transmute(_mm256_sub_epi16(transmute(t), transmute(n))),
+                _ => t
+            };
+            let q: i16x16 = transmute(_mm256_sra_epi16(transmute(t), transmute(_mm_cvtsi32_si128(self.shift as i32))));
+            // Round towards zero, rather than down
+            let sign: i16x16 = transmute(_mm256_srai_epi16(transmute(q), 15));
+            transmute(_mm256_sub_epi16(transmute(q), transmute(sign)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    signed_divide_fallback!(i16x16, i16, i32, 16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+// The upper halves of the products of 8-bit elements are the upper halves
+// of the products of the same elements widened to 16 bits and a magic number
+// shifted up by 8 bits, so bytes are divided as 16-bit elements.
+
+impl Divide<u8x16> for Divider<u8x16> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn divide(&self, n: u8x16) -> u8x16 {
+        let wide = Divider::<u16x8> { divisor: self.divisor as u16, magic: (self.magic as u16) << 8, more: self.more, shift: self.shift };
+        unsafe {
+            let lo: u16x8 = transmute(_mm_unpacklo_epi8(transmute(n), transmute(u8x16::splat(0))));
+            let hi: u16x8 = transmute(_mm_unpackhi_epi8(transmute(n), transmute(u8x16::splat(0))));
+            transmute(_mm_packus_epi16(transmute(wide.divide(lo)), transmute(wide.divide(hi))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    unsigned_divide_fallback!(u8x16, u8, u16, 8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl Divide<u8x32> for Divider<u8x32> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn divide(&self, n: u8x32) -> u8x32 {
+        let wide = Divider::<u16x16> { divisor: self.divisor as u16, magic: (self.magic as u16) << 8, more: self.more, shift: self.shift };
+        unsafe {
+            // Unpacking and packing both work within 128-bit lanes, so the
+            // elements end up back in order
+            let lo: u16x16 = transmute(_mm256_unpacklo_epi8(transmute(n), transmute(u8x32::splat(0))));
+            let hi: u16x16 = transmute(_mm256_unpackhi_epi8(transmute(n), transmute(u8x32::splat(0))));
+            transmute(_mm256_packus_epi16(transmute(wide.divide(lo)), transmute(wide.divide(hi))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    unsigned_divide_fallback!(u8x32, u8, u16, 8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+}
+
+impl Divide<i8x16> for Divider<i8x16> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn divide(&self, n: i8x16) -> i8x16 {
+        let wide = Divider::<i16x8> { divisor: self.divisor as i16, magic: (self.magic as i16) << 8, more: self.more, shift: self.shift };
+        unsafe {
+            // Sign-extend each byte by shifting it down from the upper half
+            let lo: i16x8 = transmute(_mm_srai_epi16(transmute(_mm_unpacklo_epi8(transmute(n), transmute(n))), 8));
+            let hi: i16x8 = transmute(_mm_srai_epi16(transmute(_mm_unpackhi_epi8(transmute(n), transmute(n))), 8));
+            transmute(_mm_packs_epi16(transmute(wide.divide(lo)), transmute(wide.divide(hi))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    signed_divide_fallback!(i8x16, i8, i16, 8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl Divide<i8x32> for Divider<i8x32> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn divide(&self, n: i8x32) -> i8x32 {
+        let wide = Divider::<i16x16> { divisor: self.divisor as i16, magic: (self.magic as i16) << 8, more: self.more, shift: self.shift };
+        unsafe {
+            // Sign-extend each byte by shifting it down from the upper half
+            let lo: i16x16 = transmute(_mm256_srai_epi16(transmute(_mm256_unpacklo_epi8(transmute(n), transmute(n))), 8));
+            let hi: i16x16 = transmute(_mm256_srai_epi16(transmute(_mm256_unpackhi_epi8(transmute(n), transmute(n))), 8));
+            transmute(_mm256_packs_epi16(transmute(wide.divide(lo)), transmute(wide.divide(hi))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    signed_divide_fallback!(i8x32, i8, i16, 8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+}
+
+impl Divide<u32x4> for Divider<u32x4> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn divide(&self, n: u32x4) -> u32x4 {
+        unsafe {
+            let t: u32x4 = n.mul_hi(u32x4::splat(self.magic));
+            let d: u32x4 = transmute(_mm_sub_epi32(transmute(n), transmute(t)));
+            let d: u32x4 = transmute(_mm_srl_epi32(transmute(d), transmute(_mm_cvtsi32_si128(self.more as i32))));
+            let q: u32x4 = transmute(_mm_add_epi32(transmute(t), transmute(d)));
+            transmute(_mm_srl_epi32(transmute(q), transmute(_mm_cvtsi32_si128(self.shift as i32))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    unsigned_divide_fallback!(u32x4, u32, u64, 32, [0, 1, 2, 3]);
+}
+
+impl Divide<u32x8> for Divider<u32x8> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn divide(&self, n: u32x8) -> u32x8 {
+        unsafe {
+            let t: u32x8 = n.mul_hi(u32x8::splat(self.magic));
+            let d: u32x8 = transmute(_mm256_sub_epi32(transmute(n), transmute(t)));
+            let d: u32x8 = transmute(_mm256_srl_epi32(transmute(d), transmute(_mm_cvtsi32_si128(self.more as i32))));
+            let q: u32x8 = transmute(_mm256_add_epi32(transmute(t), transmute(d)));
+            transmute(_mm256_srl_epi32(transmute(q), transmute(_mm_cvtsi32_si128(self.shift as i32))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    unsigned_divide_fallback!(u32x8, u32, u64, 32, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl Divide<i32x4> for Divider<i32x4> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
+    fn divide(&self, n: i32x4) -> i32x4 {
+        unsafe {
+            let t: i32x4 = n.mul_hi(i32x4::splat(self.magic));
+            let t: i32x4 = match self.more {
+                1 => transmute(_mm_add_epi32(transmute(t), transmute(n))),
+                -1 => transmute(_mm_sub_epi32(transmute(t), transmute(n))),
+                _ => t
+            };
+            let q: i32x4 = transmute(_mm_sra_epi32(transmute(t), transmute(_mm_cvtsi32_si128(self.shift as i32))));
+            // Round towards zero, rather than down
+            let sign: i32x4 = transmute(_mm_srai_epi32(transmute(q), 31));
+            transmute(_mm_sub_epi32(transmute(q), transmute(sign)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
+    signed_divide_fallback!(i32x4, i32, i64, 32, [0, 1, 2, 3]);
+}
+
+impl Divide<i32x8> for Divider<i32x8> {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn divide(&self, n: i32x8) -> i32x8 {
+        unsafe {
+            let t: i32x8 = n.mul_hi(i32x8::splat(self.magic));
+            let t: i32x8 = match self.more {
+                1 => transmute(_mm256_add_epi32(transmute(t), transmute(n))),
+                -1 => transmute(_mm256_sub_epi32(transmute(t), transmute(n))),
+                _ => t
+            };
+            let q: i32x8 = transmute(_mm256_sra_epi32(transmute(t), transmute(_mm_cvtsi32_si128(self.shift as i32))));
+            // Round towards zero, rather than down
+            let sign: i32x8 = transmute(_mm256_srai_epi32(transmute(q), 31));
+            transmute(_mm256_sub_epi32(transmute(q), transmute(sign)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    signed_divide_fallback!(i32x8, i32, i64, 32, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+// x86 has no 64-bit high multiplication, and AVX-512 isn't supported yet, so
+// the remaining dividers are scalar
+macro_rules! impl_divide_fallback {
+    ($kind:ident, $vec:ident, $el:ident, $wide:ty, $bits:expr, [$($n:expr),*]) => {
+        impl Divide<$vec> for Divider<$vec> {
+            $kind!($vec, $el, $wide, $bits, [$($n),*]);
+        }
+    }
+}
+
+impl_divide_fallback!(unsigned_divide_fallback, u8x64, u8, u16, 8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_divide_fallback!(unsigned_divide_fallback, u16x32, u16, u32, 16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_divide_fallback!(unsigned_divide_fallback, u32x16, u32, u64, 32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_divide_fallback!(unsigned_divide_fallback, u64x2, u64, u128, 64, [0, 1]);
+impl_divide_fallback!(unsigned_divide_fallback, u64x4, u64, u128, 64, [0, 1, 2, 3]);
+impl_divide_fallback!(unsigned_divide_fallback, u64x8, u64, u128, 64, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_divide_fallback!(signed_divide_fallback, i8x64, i8, i16, 8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_divide_fallback!(signed_divide_fallback, i16x32, i16, i32, 16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_divide_fallback!(signed_divide_fallback, i32x16, i32, i64, 32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_divide_fallback!(signed_divide_fallback, i64x2, i64, i128, 64, [0, 1]);
+impl_divide_fallback!(signed_divide_fallback, i64x4, i64, i128, 64, [0, 1, 2, 3]);
+impl_divide_fallback!(signed_divide_fallback, i64x8, i64, i128, 64, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+
+    macro_rules! test_divider {
+        ($name:ident, $vec:ident, $el:ident, [$($d:expr),*]) => {
+            #[test]
+            fn $name() {
+                let min = $el::min_value();
+                let max = $el::max_value();
+                for &d in [1, 2, 3, 5, 6, 7, 10, 16, 25, 100, 127, max, max - 1, max / 2, max / 2 + 1, min, min + 1, min / 2 $(, $d)*].iter() {
+                    if d == 0 {
+                        continue;
+                    }
+                    let divider = Divider::<$vec>::new(d);
+                    for_int_edge_cases!($vec, $el, |v, w| {
+                        let (q, r) = (v / divider, w % divider);
+                        for i in 0..$vec::WIDTH {
+                            assert_eq!(q.extract(i as u32), v.extract(i as u32).wrapping_div(d), "{} / {}", v.extract(i as u32), d);
+                            assert_eq!(r.extract(i as u32), w.extract(i as u32).wrapping_rem(d), "{} % {}", w.extract(i as u32), d);
+                        }
+                        assert_eq!(v.wrapping_div($vec::splat(d)), q);
+                        assert_eq!(w.wrapping_rem($vec::splat(d)), r);
+                    });
+                }
+            }
+        }
+    }
+
+    test_divider!(divide_u8s, u8s, u8, []);
+    test_divider!(divide_i8s, i8s, i8, [-1, -2, -3, -7, -100]);
+    test_divider!(divide_u16s, u16s, u16, [1000, 4096]);
+    test_divider!(divide_i16s, i16s, i16, [-1, -2, -3, -7, -100, 1000, -4096]);
+    test_divider!(divide_u32s, u32s, u32, [1000, 641, 0x10000]);
+    test_divider!(divide_i32s, i32s, i32, [-1, -2, -3, -7, -100, 641, -0x10000]);
+    test_divider!(divide_u64s, u64s, u64, [1000, 641, 0x100000000, 274177]);
+    test_divider!(divide_i64s, i64s, i64, [-1, -2, -3, -7, -100, 641, -0x100000000]);
+
+    #[test]
+    fn divide_exhaustive_u8() {
+        for d in 1..256 {
+            let divider = Divider::<u8x16>::new(d as u8);
+            for n in 0..16 {
+                let v = u8x16::from_fn(|i| (n * 16 + i) as u8);
+                assert_eq!(v / divider, u8x16::from_fn(|i| (n * 16 + i) as u8 / d as u8));
+            }
+        }
+    }
+
+    #[test]
+    fn divide_exhaustive_i8() {
+        for d in -128..128 {
+            if d == 0 {
+                continue;
+            }
+            let divider = Divider::<i8x16>::new(d as i8);
+            for n in 0..16 {
+                let v = i8x16::from_fn(|i| (n * 16 + i) as i8);
+                assert_eq!(v / divider, i8x16::from_fn(|i| ((n * 16 + i) as i8).wrapping_div(d as i8)));
+            }
+        }
+    }
+
+    #[test]
+    fn divide_exhaustive_i16() {
+        for &d in [-32768i32, -32767, -1000, -9, -2, -1, 1, 2, 9, 1000, 32767].iter() {
+            let divider = Divider::<i16x8>::new(d as i16);
+            for n in 0..8192 {
+                let v = i16x8::from_fn(|i| (n * 8 + i as i32) as i16);
+                assert_eq!(v / divider, i16x8::from_fn(|i| ((n * 8 + i as i32) as i16).wrapping_div(d as i16)));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn divide_by_zero() {
+        Divider::<u32s>::new(0);
+    }
+}
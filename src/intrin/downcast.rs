@@ -8,7 +8,13 @@
 use vecs::*;
 use stdsimd::vendor::*;
 use intrin::transmute::*;
+use intrin::abs::Abs;
+use intrin::cmp::Cmp;
+use intrin::eq::Eq;
+use intrin::saturating_sub::SaturatingSub;
+use intrin::upcast::Upcast;
 use core_or_std::mem::transmute;
+use core_or_std::{f32, f64};
 
 pub trait Downcast<T> {
     /// Return a vector containing elements of the same value as `self` and
@@ -30,17 +36,138 @@ pub trait Downcast<T> {
     /// # }
     /// ```
     fn saturating_downcast(self, other: Self) -> T;
+
+    /// Return a vector containing elements of the same value as `self` and
+    /// `other`, but different type. The first half of the returned vector
+    /// contains the downcast values of `self`, whereas the second half of the
+    /// returned vector contains the downcast values of `other`. If an element
+    /// exceeds the maximum or minimum value of the downcast type, only its
+    /// low bits are kept, as with an `as` cast. Floats are rounded to the
+    /// nearest representable value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(0x12345678).wrapping_downcast(u32s(3)), u16s::halfs(0x5678, 3));
+    /// assert_eq!(i16s(128).wrapping_downcast(i16s(-129)), i8s::halfs(-128, 127));
+    /// # }
+    /// ```
+    fn wrapping_downcast(self, other: Self) -> T;
+
+    /// Return the result of `wrapping_downcast`, and a mask in the format of
+    /// `Eq::eq_mask` whose elements are filled with 1s where the downcast
+    /// value differs from the original value. Float elements are only
+    /// considered to have overflowed if they were finite and became infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let (ret, overflow) = i32s(70000).checked_downcast(i32s(-2));
+    /// assert_eq!(ret, i16s::halfs(70000i32 as i16, -2));
+    /// assert_eq!(overflow, u16s::halfs(0xFFFF, 0));
+    /// # }
+    /// ```
+    fn checked_downcast(self, other: Self) -> (T, T::Out) where T : Eq;
+}
+
+/// A trait which downcasts signed vectors to unsigned vectors of half the
+/// element size.
+pub trait UnsignedDowncast<T> {
+    /// Return a vector containing elements of the same value as `self` and
+    /// `other`, but of an unsigned type of half the size. The first half of
+    /// the returned vector contains the downcast values of `self`, whereas
+    /// the second half contains the downcast values of `other`. Negative
+    /// elements become zero, and elements which exceed the maximum value of
+    /// the downcast type are saturated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32s(-2).saturating_downcast_unsigned(i32s(70000)), u16s::halfs(0, 0xFFFF));
+    /// assert_eq!(i16s(200).saturating_downcast_unsigned(i16s(300)), u8s::halfs(200, 255));
+    /// # }
+    /// ```
+    fn saturating_downcast_unsigned(self, other: Self) -> T;
+}
+
+// Keep the low bits of each element, as with an `as` cast
+macro_rules! wrapping_fallback {
+    ($out:ident, $el:ty, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn wrapping_downcast(self, other: Self) -> $out {
+            $out::new($(self.extract($n) as $el,)* $(other.extract($n) as $el),*)
+        }
+    }
+}
+
+// An element overflowed if upcasting its downcast value doesn't restore it
+macro_rules! checked_downcast {
+    ($out:ident) => {
+        #[inline(always)]
+        fn checked_downcast(self, other: Self) -> ($out, <$out as Eq>::Out) {
+            let ret = self.wrapping_downcast(other);
+            let (lo, hi): (Self, Self) = ret.upcast();
+            (ret, lo.ne_mask(self).saturating_downcast(hi.ne_mask(other)))
+        }
+    }
+}
+
+// Finite floats which don't fit in the smaller type become infinite
+macro_rules! checked_float_downcast {
+    ($vec:ident, $el:ident, $out:ident, $outel:ident) => {
+        #[inline(always)]
+        fn checked_downcast(self, other: Self) -> ($out, <$out as Eq>::Out) {
+            let ret = self.wrapping_downcast(other);
+            let inf = $vec::splat($el::INFINITY);
+            let inf_in = Abs::abs(&self).eq_mask(inf).saturating_downcast(Abs::abs(&other).eq_mask(inf));
+            let inf_out = Abs::abs(&ret).eq_mask($out::splat($outel::INFINITY));
+            (ret, inf_out ^ inf_in)
+        }
+    }
+}
+
+/// Move the low halves of each element of `a` and `b` into the returned
+/// vector, where `mask` selects the low half of each element of a 64-bit
+/// lane.
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+#[inline(always)]
+unsafe fn pack_low_halves(a: u8x16, b: u8x16, mask: u8x16) -> u8x16 {
+    let (a, b) = (_mm_shuffle_epi8(a, mask), _mm_shuffle_epi8(b, mask));
+    transmute(_mm_unpacklo_epi64(transmute(a), transmute(b)))
+}
+
+/// Move the low halves of each element of `a` and `b` into the returned
+/// vector, where `mask` selects the low half of each element of a 128-bit
+/// lane.
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+#[inline(always)]
+unsafe fn pack_low_halves_256(a: u8x32, b: u8x32, mask: u8x32) -> u8x32 {
+    let (a, b) = (_mm256_shuffle_epi8(a, mask), _mm256_shuffle_epi8(b, mask));
+    // The shuffle works on each 128-bit lane, so restore the order of the halves
+    transmute(_mm256_permute4x64_epi64(_mm256_unpacklo_epi64(transmute(a), transmute(b)), 0xD8))
 }
 
 impl Downcast<i16x8> for i32x4 {
     #[inline(always)]
-    #[cfg(target_feature = "sse2")]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> i16x8 {
         unsafe { _mm_packs_epi32(self, other) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse2"))]
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> i16x8 {
         i16x8::new(self.extract(0).min(0x00007FFF).max(-0x00008000) as i16,
                    self.extract(1).min(0x00007FFF).max(-0x00008000) as i16,
@@ -51,6 +178,18 @@ impl Downcast<i16x8> for i32x4 {
                    other.extract(2).min(0x00007FFF).max(-0x00008000) as i16,
                    other.extract(3).min(0x00007FFF).max(-0x00008000) as i16)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> i16x8 {
+        let mask = u8x16::new(0, 1, 4, 5, 8, 9, 12, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
+    wrapping_fallback!(i16x8, i16, [0, 1, 2, 3]);
+
+    checked_downcast!(i16x8);
 }
 
 impl Downcast<i32x4> for i64x2 {
@@ -61,16 +200,40 @@ impl Downcast<i32x4> for i64x2 {
                    other.extract(0).min(0x7FFFFFFF).max(-0x80000000) as i32,
                    other.extract(1).min(0x7FFFFFFF).max(-0x80000000) as i32)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> i32x4 {
+        // Select the low half of each element, which is first on x86
+        unsafe { transmute(_mm_shuffle_ps(transmute(self), transmute(other), 0b10001000)) }
+    }
+
+    #[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
+    wrapping_fallback!(i32x4, i32, [0, 1]);
+
+    checked_downcast!(i32x4);
 }
 
 impl Downcast<u32x4> for u64x2 {
     #[inline(always)]
     fn saturating_downcast(self, other: Self) -> u32x4 {
         u32x4::new(self.extract(0).min(0xFFFFFFFF) as u32,
-                   self.extract(1).min(0x7FFFFFFF) as u32,
-                   other.extract(0).min(0x7FFFFFFF) as u32,
-                   other.extract(1).min(0x7FFFFFFF) as u32)
+                   self.extract(1).min(0xFFFFFFFF) as u32,
+                   other.extract(0).min(0xFFFFFFFF) as u32,
+                   other.extract(1).min(0xFFFFFFFF) as u32)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> u32x4 {
+        // Select the low half of each element, which is first on x86
+        unsafe { transmute(_mm_shuffle_ps(transmute(self), transmute(other), 0b10001000)) }
+    }
+
+    #[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
+    wrapping_fallback!(u32x4, u32, [0, 1]);
+
+    checked_downcast!(u32x4);
 }
 
 impl Downcast<f32x4> for f64x2 {
@@ -81,17 +244,24 @@ impl Downcast<f32x4> for f64x2 {
                    other.extract(0) as f32,
                    other.extract(1) as f32)
     }
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> f32x4 {
+        self.saturating_downcast(other)
+    }
+
+    checked_float_downcast!(f64x2, f64, f32x4, f32);
 }
 
 impl Downcast<i8x16> for i16x8 {
     #[inline(always)]
-    #[cfg(target_feature = "sse2")]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> i8x16 {
         unsafe { _mm_packs_epi16(self, other) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse2"))]
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> i8x16 {
         i8x16::new(self.extract(0).min(0x007F).max(-0x0080) as i8,
                    self.extract(1).min(0x007F).max(-0x0080) as i8,
@@ -110,17 +280,31 @@ impl Downcast<i8x16> for i16x8 {
                    other.extract(6).min(0x007F).max(-0x0080) as i8,
                    other.extract(7).min(0x007F).max(-0x0080) as i8)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> i8x16 {
+        let mask = u8x16::new(0, 2, 4, 6, 8, 10, 12, 14, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
+    wrapping_fallback!(i8x16, i8, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    checked_downcast!(i8x16);
 }
 
 impl Downcast<u16x8> for u32x4 {
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> u16x8 {
-        unsafe { transmute(_mm_packus_epi32(transmute(self), transmute(other))) }
+        // packus treats its inputs as signed, so clamp them first
+        let max = u32x4::splat(0x0000FFFF);
+        unsafe { transmute(_mm_packus_epi32(transmute(Cmp::min(&self, max)), transmute(Cmp::min(&other, max)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> u16x8 {
         u16x8::new(self.extract(0).min(0x0000FFFF) as u16,
                    self.extract(1).min(0x0000FFFF) as u16,
@@ -131,17 +315,32 @@ impl Downcast<u16x8> for u32x4 {
                    other.extract(2).min(0x0000FFFF) as u16,
                    other.extract(3).min(0x0000FFFF) as u16)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> u16x8 {
+        let mask = u8x16::new(0, 1, 4, 5, 8, 9, 12, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
+    wrapping_fallback!(u16x8, u16, [0, 1, 2, 3]);
+
+    checked_downcast!(u16x8);
 }
 
 impl Downcast<u8x16> for u16x8 {
     #[inline(always)]
-    #[cfg(target_feature = "sse2")]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> u8x16 {
-        unsafe { _mm_packus_epi16(self.be_i16s(), other.be_i16s()) }
+        // packus treats its inputs as signed, so clamp them first
+        let max = u16x8::splat(0x00FF);
+        let (a, b) = (self - self.saturating_sub(max), other - other.saturating_sub(max));
+        unsafe { _mm_packus_epi16(a.be_i16s(), b.be_i16s()) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse2"))]
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> u8x16 {
         u8x16::new(self.extract(0).min(0x00FF) as u8,
                    self.extract(1).min(0x00FF) as u8,
@@ -160,17 +359,30 @@ impl Downcast<u8x16> for u16x8 {
                    other.extract(6).min(0x00FF) as u8,
                    other.extract(7).min(0x00FF) as u8)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> u8x16 {
+        let mask = u8x16::new(0, 2, 4, 6, 8, 10, 12, 14, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
+    wrapping_fallback!(u8x16, u8, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    checked_downcast!(u8x16);
 }
 
 impl Downcast<i16x16> for i32x8 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> i16x16 {
-        unsafe { _mm256_packs_epi32(self, other) }
+        // pack works on each 128-bit lane, so restore the order of the halves
+        unsafe { transmute(_mm256_permute4x64_epi64(_mm256_packs_epi32(self, other).be_i64s(), 0xD8)) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> i16x16 {
         i16x16::new(self.extract(0).min(0x00007FFF).max(-0x00008000) as i16,
                     self.extract(1).min(0x00007FFF).max(-0x00008000) as i16,
@@ -189,17 +401,31 @@ impl Downcast<i16x16> for i32x8 {
                     other.extract(6).min(0x00007FFF).max(-0x00008000) as i16,
                     other.extract(7).min(0x00007FFF).max(-0x00008000) as i16)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> i16x16 {
+        let mask = u8x32::new(0, 1, 4, 5, 8, 9, 12, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                              0, 1, 4, 5, 8, 9, 12, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves_256(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    wrapping_fallback!(i16x16, i16, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    checked_downcast!(i16x16);
 }
 
 impl Downcast<i8x32> for i16x16 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> i8x32 {
-        unsafe { _mm256_packs_epi16(self, other) }
+        // pack works on each 128-bit lane, so restore the order of the halves
+        unsafe { transmute(_mm256_permute4x64_epi64(_mm256_packs_epi16(self, other).be_i64s(), 0xD8)) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> i8x32 {
         i8x32::new(self.extract(0).min(0x007F).max(-0x0080) as i8,
                    self.extract(1).min(0x007F).max(-0x0080) as i8,
@@ -234,17 +460,34 @@ impl Downcast<i8x32> for i16x16 {
                    other.extract(14).min(0x007F).max(-0x0080) as i8,
                    other.extract(15).min(0x007F).max(-0x0080) as i8)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> i8x32 {
+        let mask = u8x32::new(0, 2, 4, 6, 8, 10, 12, 14, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                              0, 2, 4, 6, 8, 10, 12, 14, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves_256(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    wrapping_fallback!(i8x32, i8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+    checked_downcast!(i8x32);
 }
 
 impl Downcast<u16x16> for u32x8 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> u16x16 {
-        unsafe { transmute(_mm256_packus_epi32(transmute(self), transmute(other))) }
+        // packus treats its inputs as signed and works on each 128-bit lane,
+        // so clamp the inputs and restore the order of the halves
+        let max = u32x8::splat(0x0000FFFF);
+        let packed: u16x16 = unsafe { transmute(_mm256_packus_epi32(transmute(Cmp::min(&self, max)), transmute(Cmp::min(&other, max)))) };
+        unsafe { transmute(_mm256_permute4x64_epi64(packed.be_i64s(), 0xD8)) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> u16x16 {
         u16x16::new(self.extract(0).min(0x0000FFFF) as u16,
                     self.extract(1).min(0x0000FFFF) as u16,
@@ -263,17 +506,34 @@ impl Downcast<u16x16> for u32x8 {
                     other.extract(6).min(0x0000FFFF) as u16,
                     other.extract(7).min(0x0000FFFF) as u16)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> u16x16 {
+        let mask = u8x32::new(0, 1, 4, 5, 8, 9, 12, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                              0, 1, 4, 5, 8, 9, 12, 13, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves_256(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    wrapping_fallback!(u16x16, u16, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    checked_downcast!(u16x16);
 }
 
 impl Downcast<u8x32> for u16x16 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn saturating_downcast(self, other: Self) -> u8x32 {
-        unsafe { _mm256_packus_epi16(self.be_i16s(), other.be_i16s()) }
+        // packus treats its inputs as signed and works on each 128-bit lane,
+        // so clamp the inputs and restore the order of the halves
+        let max = u16x16::splat(0x00FF);
+        let (a, b) = (Cmp::min(&self, max), Cmp::min(&other, max));
+        unsafe { transmute(_mm256_permute4x64_epi64(_mm256_packus_epi16(a.be_i16s(), b.be_i16s()).be_i64s(), 0xD8)) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn saturating_downcast(self, other: Self) -> u8x32 {
         u8x32::new(self.extract(0).min(0x00FF) as u8,
                    self.extract(1).min(0x00FF) as u8,
@@ -308,6 +568,19 @@ impl Downcast<u8x32> for u16x16 {
                    other.extract(14).min(0x00FF) as u8,
                    other.extract(15).min(0x00FF) as u8)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> u8x32 {
+        let mask = u8x32::new(0, 2, 4, 6, 8, 10, 12, 14, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                              0, 2, 4, 6, 8, 10, 12, 14, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+        unsafe { transmute(pack_low_halves_256(self.be_u8s(), other.be_u8s(), mask)) }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    wrapping_fallback!(u8x32, u8, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+    checked_downcast!(u8x32);
 }
 
 impl Downcast<i32x8> for i64x4 {
@@ -322,20 +595,54 @@ impl Downcast<i32x8> for i64x4 {
                    other.extract(2).min(0x7FFFFFFF).max(-0x80000000) as i32,
                    other.extract(3).min(0x7FFFFFFF).max(-0x80000000) as i32)
     }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> i32x8 {
+        // Gather the low half of each element into the lower 128-bit lane
+        let idx = i32x8::new(0, 2, 4, 6, 0, 2, 4, 6);
+        unsafe {
+            let (a, b) = (_mm256_permutevar8x32_epi32(transmute(self), transmute(idx)),
+                          _mm256_permutevar8x32_epi32(transmute(other), transmute(idx)));
+            transmute(_mm256_permute2x128_si256(transmute(a), transmute(b), 0x20))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    wrapping_fallback!(i32x8, i32, [0, 1, 2, 3]);
+
+    checked_downcast!(i32x8);
 }
 
 impl Downcast<u32x8> for u64x4 {
     #[inline(always)]
     fn saturating_downcast(self, other: Self) -> u32x8 {
         u32x8::new(self.extract(0).min(0xFFFFFFFF) as u32,
-                   self.extract(1).min(0x7FFFFFFF) as u32,
+                   self.extract(1).min(0xFFFFFFFF) as u32,
                    self.extract(2).min(0xFFFFFFFF) as u32,
-                   self.extract(3).min(0x7FFFFFFF) as u32,
-                   other.extract(0).min(0x7FFFFFFF) as u32,
-                   other.extract(1).min(0x7FFFFFFF) as u32,
-                   other.extract(2).min(0x7FFFFFFF) as u32,
-                   other.extract(3).min(0x7FFFFFFF) as u32)
+                   self.extract(3).min(0xFFFFFFFF) as u32,
+                   other.extract(0).min(0xFFFFFFFF) as u32,
+                   other.extract(1).min(0xFFFFFFFF) as u32,
+                   other.extract(2).min(0xFFFFFFFF) as u32,
+                   other.extract(3).min(0xFFFFFFFF) as u32)
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn wrapping_downcast(self, other: Self) -> u32x8 {
+        // Gather the low half of each element into the lower 128-bit lane
+        let idx = i32x8::new(0, 2, 4, 6, 0, 2, 4, 6);
+        unsafe {
+            let (a, b) = (_mm256_permutevar8x32_epi32(transmute(self), transmute(idx)),
+                          _mm256_permutevar8x32_epi32(transmute(other), transmute(idx)));
+            transmute(_mm256_permute2x128_si256(transmute(a), transmute(b), 0x20))
+        }
     }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    wrapping_fallback!(u32x8, u32, [0, 1, 2, 3]);
+
+    checked_downcast!(u32x8);
 }
 
 impl Downcast<f32x8> for f64x4 {
@@ -350,4 +657,126 @@ impl Downcast<f32x8> for f64x4 {
                    other.extract(2) as f32,
                    other.extract(3) as f32)
     }
+
+    #[inline(always)]
+    fn wrapping_downcast(self, other: Self) -> f32x8 {
+        self.saturating_downcast(other)
+    }
+
+    checked_float_downcast!(f64x4, f64, f32x8, f32);
+}
+
+// Clamp each element to the range of the unsigned downcast type
+macro_rules! unsigned_fallback {
+    ($out:ident, $el:ty, $max:expr, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn saturating_downcast_unsigned(self, other: Self) -> $out {
+            $out::new($(self.extract($n).max(0).min($max) as $el,)*
+                      $(other.extract($n).max(0).min($max) as $el),*)
+        }
+    }
+}
+
+impl UnsignedDowncast<u16x8> for i32x4 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
+    fn saturating_downcast_unsigned(self, other: Self) -> u16x8 {
+        unsafe { transmute(_mm_packus_epi32(transmute(self), transmute(other))) }
+    }
+
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
+    unsigned_fallback!(u16x8, u16, 0x0000FFFF, [0, 1, 2, 3]);
+}
+
+impl UnsignedDowncast<u8x16> for i16x8 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn saturating_downcast_unsigned(self, other: Self) -> u8x16 {
+        unsafe { _mm_packus_epi16(self, other) }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    unsigned_fallback!(u8x16, u8, 0x00FF, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl UnsignedDowncast<u32x4> for i64x2 {
+    unsigned_fallback!(u32x4, u32, 0xFFFFFFFF, [0, 1]);
+}
+
+impl UnsignedDowncast<u16x16> for i32x8 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn saturating_downcast_unsigned(self, other: Self) -> u16x16 {
+        // packus works on each 128-bit lane, so restore the order of the halves
+        unsafe { transmute(_mm256_permute4x64_epi64(transmute(_mm256_packus_epi32(transmute(self), transmute(other))), 0xD8)) }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    unsigned_fallback!(u16x16, u16, 0x0000FFFF, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl UnsignedDowncast<u8x32> for i16x16 {
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn saturating_downcast_unsigned(self, other: Self) -> u8x32 {
+        // packus works on each 128-bit lane, so restore the order of the halves
+        unsafe { transmute(_mm256_permute4x64_epi64(transmute(_mm256_packus_epi16(transmute(self), transmute(other))), 0xD8)) }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    unsigned_fallback!(u8x32, u8, 0x00FF, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl UnsignedDowncast<u32x8> for i64x4 {
+    unsigned_fallback!(u32x8, u32, 0xFFFFFFFF, [0, 1, 2, 3]);
+}
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+    use core_or_std::f64;
+
+    #[test]
+    fn wrapping_downcast() {
+        assert_eq!(i32s::interleave(0x12345, -1).wrapping_downcast(i32s(0x8000)),
+                   i16s::from_fn(|i| if i >= i32s::WIDTH { -0x8000 } else if i % 2 == 0 { 0x2345 } else { -1 }));
+        assert_eq!(i16s(0x1FF).wrapping_downcast(i16s(-0x100)), i8s::halfs(-1, 0));
+        assert_eq!(i64s(0x1_0000_0002).wrapping_downcast(i64s(-3)), i32s::halfs(2, -3));
+        assert_eq!(u64s(0x1_0000_0002).wrapping_downcast(u64s(3)), u32s::halfs(2, 3));
+        assert_eq!(u32s(0x10002).wrapping_downcast(u32s(0xFFFF)), u16s::halfs(2, 0xFFFF));
+        assert_eq!(u16s(0x102).wrapping_downcast(u16s(0xFF)), u8s::halfs(2, 0xFF));
+        assert_eq!(f64s(1.5).wrapping_downcast(f64s(-2.0)), f32s::halfs(1.5, -2.0));
+    }
+
+    #[test]
+    fn wrapping_downcast_order() {
+        let a = u32s::from_fn(|i| 0x10000 + i as u32);
+        let b = u32s::from_fn(|i| 0x20000 + (i + u32s::WIDTH) as u32);
+        assert_eq!(a.wrapping_downcast(b), u16s::from_fn(|i| i as u16));
+        let a = i64s::from_fn(|i| -(i as i64) - 0x1_0000_0000);
+        let b = i64s::from_fn(|i| -((i + i64s::WIDTH) as i64));
+        assert_eq!(a.wrapping_downcast(b), i32s::from_fn(|i| -(i as i32)));
+    }
+
+    #[test]
+    fn checked_downcast() {
+        assert_eq!(i32s(0x7FFF).checked_downcast(i32s(-0x8001)), (i16s::halfs(0x7FFF, 0x7FFF), u16s::halfs(0, 0xFFFF)));
+        assert_eq!(u16s(0xFF).checked_downcast(u16s(0x100)), (u8s::halfs(0xFF, 0), u8s::halfs(0, 0xFF)));
+        assert_eq!(u64s(0xFFFFFFFF).checked_downcast(u64s(0x1_0000_0000)), (u32s::halfs(0xFFFFFFFF, 0), u32s::halfs(0, 0xFFFFFFFF)));
+        assert_eq!(i16s(-0x80).checked_downcast(i16s(0x80)), (i8s::halfs(-0x80, -0x80), u8s::halfs(0, 0xFF)));
+        let (ret, overflow) = f64s(1e300).checked_downcast(f64s(f64::INFINITY));
+        assert_eq!(ret, f32s(::core_or_std::f32::INFINITY));
+        assert_eq!(overflow, u32s::halfs(0xFFFFFFFF, 0));
+    }
+
+    #[test]
+    fn saturating_downcast_unsigned() {
+        assert_eq!(i32s(-1).saturating_downcast_unsigned(i32s(0x10000)), u16s::halfs(0, 0xFFFF));
+        assert_eq!(i16s(0xFF).saturating_downcast_unsigned(i16s(0x100)), u8s::halfs(0xFF, 0xFF));
+        assert_eq!(i64s(-0x1_0000_0000).saturating_downcast_unsigned(i64s(0x8000_0000)), u32s::halfs(0, 0x8000_0000));
+        let a = i32s::from_fn(|i| i as i32);
+        let b = i32s::from_fn(|i| (i + i32s::WIDTH) as i32);
+        assert_eq!(a.saturating_downcast_unsigned(b), u16s::from_fn(|i| i as u16));
+    }
 }
@@ -68,14 +68,14 @@ pub trait Reendianize : Sized + Copy {
 macro_rules! impl_packed_swap_bytes {
     ($vec:tt, $uvec:tt, $feat:expr, $mmfn:tt, ($($c:expr),*), ($($a:expr, $b:expr),*)) => {
         impl Reendianize for $vec {
-            #[cfg(not(target_feature = $feat))]
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
             #[inline(always)]
             fn swap_bytes(&self) -> Self {
                 $vec::new($(self.extract($a).swap_bytes(),
                             self.extract($b).swap_bytes()),*)
             }
 
-            #[cfg(target_feature = $feat)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
             #[inline(always)]
             fn swap_bytes(&self) -> Self {
                 unsafe {
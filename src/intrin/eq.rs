@@ -53,14 +53,14 @@ macro_rules! rust_fallback_eq {
                 type Out = $mask;
 
                 #[inline(always)]
-                #[cfg(target_feature = $feat)]
+                #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
                 fn $newfn(&self, other: Self) -> $mask {
                     use core_or_std::mem::transmute;
                     unsafe { transmute($mmfn(transmute(*self), transmute(other), $($mmfnargs),*)) }
                 }
 
                 #[inline(always)]
-                #[cfg(not(target_feature = $feat))]
+                #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
                 fn $newfn(&self, other: Self) -> Self::Out {
                     use core_or_std::mem::transmute;
                     unsafe {
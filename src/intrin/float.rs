@@ -0,0 +1,590 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use stdsimd::vendor::*;
+use vecs::*;
+use core_or_std::mem::transmute;
+
+/// Classification of the elements of a float vector. Each function returns a
+/// mask of the same type as [`Eq::Out`], where each element is filled with 1s
+/// if the element of `self` at the same index is in the class, and filled
+/// with zeroes otherwise.
+///
+/// [`Eq::Out`]: trait.Eq.html#associatedtype.Out
+///
+/// # Examples
+///
+/// Masks may be used to replace elements of a vector. Here, all NaNs are
+/// replaced with zero:
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # #[cfg(feature = "no-std")]
+/// # fn main() { }
+///
+/// # #[cfg(not(feature = "no-std"))]
+/// # fn main() {
+/// let nan = ::std::f32::NAN;
+/// let cleaned = [1.0f32, nan, 3.0, nan, 5.0].simd_iter(f32s(0.0))
+///     .simd_map(|v| {
+///         let bits = v.be_u32s();
+///         unsafe { (bits ^ (bits & v.is_nan())).be_f32s_unchecked() }
+///     })
+///     .scalar_collect();
+/// assert_eq!(cleaned, vec![1.0, 0.0, 3.0, 0.0, 5.0]);
+/// # }
+/// ```
+pub trait FloatClass {
+    type Out;
+
+    /// Return a mask of the elements of `self` which are NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(0.0, ::std::f32::NAN).is_nan(), u32s::interleave(0, 0xFFFFFFFF));
+    /// # }
+    /// ```
+    fn is_nan(&self) -> Self::Out;
+
+    /// Return a mask of the elements of `self` which are positive or
+    /// negative infinity.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s::interleave(::std::f64::NEG_INFINITY, 1.0).is_infinite(), u64s::interleave(0xFFFFFFFFFFFFFFFF, 0));
+    /// # }
+    /// ```
+    fn is_infinite(&self) -> Self::Out;
+
+    /// Return a mask of the elements of `self` which are neither infinite nor
+    /// NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-2.0, ::std::f32::INFINITY).is_finite(), u32s::interleave(0xFFFFFFFF, 0));
+    /// # }
+    /// ```
+    fn is_finite(&self) -> Self::Out;
+
+    /// Return a mask of the elements of `self` which have their sign bit set,
+    /// including `-0.0` and NaNs with a negative sign.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-0.0, 0.0).is_sign_negative(), u32s::interleave(0xFFFFFFFF, 0));
+    /// # }
+    /// ```
+    fn is_sign_negative(&self) -> Self::Out;
+}
+
+pub trait FloatSign {
+    /// Return a vector containing the magnitudes of the elements of `self`
+    /// with the signs of the elements of `sign`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(3.0).copysign(f32s::interleave(-0.0, 1.0)), f32s::interleave(-3.0, 3.0));
+    /// # }
+    /// ```
+    fn copysign(&self, sign: Self) -> Self;
+
+    /// Return a vector containing `1.0` where the element of `self` has a
+    /// positive sign, `-1.0` where it has a negative sign, and NaN where it
+    /// is NaN.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s::interleave(-7.5, 0.0).signum(), f64s::interleave(-1.0, 1.0));
+    /// # }
+    /// ```
+    fn signum(&self) -> Self;
+
+    /// Return a vector containing the negated absolute values of the elements
+    /// of `self`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(-2.0, 2.0).neg_abs(), f32s(-2.0));
+    /// # }
+    /// ```
+    fn neg_abs(&self) -> Self;
+}
+
+pub trait Fract {
+    /// Return a vector containing the fractional parts of the elements of
+    /// `self`, which have the same sign as the element of `self`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s::interleave(2.25, -3.5).fract(), f32s::interleave(0.25, -0.5));
+    /// # }
+    /// ```
+    fn fract(&self) -> Self;
+}
+
+/// Bit twiddling on the elements of float vectors which can't be classified
+/// or manipulated in SIMD.
+trait FloatBits : Copy {
+    type Bits;
+    fn nan_mask(self) -> Self::Bits;
+    fn infinite_mask(self) -> Self::Bits;
+    fn finite_mask(self) -> Self::Bits;
+    fn sign_negative_mask(self) -> Self::Bits;
+    fn copysign_bits(self, sign: Self) -> Self;
+    fn signum_bits(self) -> Self;
+    fn neg_abs_bits(self) -> Self;
+    fn fract_bits(self) -> Self;
+}
+
+macro_rules! impl_float_bits {
+    ($el:ident, $bits:ident, $sign:expr, $exp:expr, $mant:expr) => {
+        impl FloatBits for $el {
+            type Bits = $bits;
+
+            #[inline(always)]
+            fn nan_mask(self) -> $bits {
+                let x = unsafe { transmute::<$el, $bits>(self) };
+                ((x & !$sign > $exp) as $bits).wrapping_neg()
+            }
+
+            #[inline(always)]
+            fn infinite_mask(self) -> $bits {
+                let x = unsafe { transmute::<$el, $bits>(self) };
+                ((x & !$sign == $exp) as $bits).wrapping_neg()
+            }
+
+            #[inline(always)]
+            fn finite_mask(self) -> $bits {
+                let x = unsafe { transmute::<$el, $bits>(self) };
+                ((x & !$sign < $exp) as $bits).wrapping_neg()
+            }
+
+            #[inline(always)]
+            fn sign_negative_mask(self) -> $bits {
+                let x = unsafe { transmute::<$el, $bits>(self) };
+                ((x & $sign != 0) as $bits).wrapping_neg()
+            }
+
+            #[inline(always)]
+            fn copysign_bits(self, sign: Self) -> Self {
+                unsafe {
+                    transmute::<$bits, $el>(transmute::<$el, $bits>(self) & !$sign |
+                                            transmute::<$el, $bits>(sign) & $sign)
+                }
+            }
+
+            #[inline(always)]
+            fn signum_bits(self) -> Self {
+                if self != self {
+                    self
+                } else {
+                    (1.0 as $el).copysign_bits(self)
+                }
+            }
+
+            #[inline(always)]
+            fn neg_abs_bits(self) -> Self {
+                unsafe { transmute::<$bits, $el>(transmute::<$el, $bits>(self) | $sign) }
+            }
+
+            #[inline(always)]
+            fn fract_bits(self) -> Self {
+                let x = unsafe { transmute::<$el, $bits>(self) };
+                let e = ((x & $exp) >> $mant) as i32 - (((($exp as $bits) >> $mant) >> 1) as i32);
+                // Clear the bits of the mantissa which are below the binary
+                // point to truncate; infinities and NaNs are left alone, so
+                // their fractional part is NaN
+                let trunc = if e < 0 {
+                    x & $sign
+                } else if e >= $mant {
+                    x
+                } else {
+                    x & !((1 << ($mant - e)) - 1)
+                };
+                self - unsafe { transmute::<$bits, $el>(trunc) }
+            }
+        }
+    }
+}
+
+impl_float_bits!(f32, u32, 0x80000000, 0x7F800000, 23);
+impl_float_bits!(f64, u64, 0x8000000000000000, 0x7FF0000000000000, 52);
+
+macro_rules! float_class_fallback {
+    ($mask:ident, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn is_nan(&self) -> $mask {
+            $mask::new($(self.extract($n).nan_mask()),*)
+        }
+
+        #[inline(always)]
+        fn is_infinite(&self) -> $mask {
+            $mask::new($(self.extract($n).infinite_mask()),*)
+        }
+
+        #[inline(always)]
+        fn is_finite(&self) -> $mask {
+            $mask::new($(self.extract($n).finite_mask()),*)
+        }
+
+        #[inline(always)]
+        fn is_sign_negative(&self) -> $mask {
+            $mask::new($(self.extract($n).sign_negative_mask()),*)
+        }
+    }
+}
+
+macro_rules! float_sign_fallback {
+    ([$($n:expr),*]) => {
+        #[inline(always)]
+        fn copysign(&self, sign: Self) -> Self {
+            Self::new($(self.extract($n).copysign_bits(sign.extract($n))),*)
+        }
+
+        #[inline(always)]
+        fn signum(&self) -> Self {
+            Self::new($(self.extract($n).signum_bits()),*)
+        }
+
+        #[inline(always)]
+        fn neg_abs(&self) -> Self {
+            Self::new($(self.extract($n).neg_abs_bits()),*)
+        }
+    }
+}
+
+macro_rules! fract_fallback {
+    ([$($n:expr),*]) => {
+        #[inline(always)]
+        fn fract(&self) -> Self {
+            Self::new($(self.extract($n).fract_bits()),*)
+        }
+    }
+}
+
+// The sign of a float is classified by setting its magnitude to 1.0 and
+// comparing it to zero, so -0.0 and NaNs with a negative sign are included.
+
+impl FloatClass for f32x4 {
+    type Out = u32x4;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
+    fn is_nan(&self) -> u32x4 {
+        unsafe { transmute(_mm_cmpunord_ps(*self, *self)) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
+    fn is_infinite(&self) -> u32x4 {
+        unsafe {
+            let abs = _mm_and_ps(*self, Self::splat(transmute::<u32, f32>(0x7FFFFFFF)));
+            transmute(_mm_cmpeq_ps(abs, Self::splat(transmute::<u32, f32>(0x7F800000))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
+    fn is_finite(&self) -> u32x4 {
+        unsafe {
+            let abs = _mm_and_ps(*self, Self::splat(transmute::<u32, f32>(0x7FFFFFFF)));
+            transmute(_mm_cmplt_ps(abs, Self::splat(transmute::<u32, f32>(0x7F800000))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
+    fn is_sign_negative(&self) -> u32x4 {
+        unsafe {
+            let sign = _mm_and_ps(*self, Self::splat(transmute::<u32, f32>(0x80000000)));
+            transmute(_mm_cmplt_ps(_mm_or_ps(sign, Self::splat(1.0)), Self::splat(0.0)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
+    float_class_fallback!(u32x4, [0, 1, 2, 3]);
+}
+
+impl FloatClass for f64x2 {
+    type Out = u64x2;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn is_nan(&self) -> u64x2 {
+        unsafe { transmute(_mm_cmpunord_pd(*self, *self)) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn is_infinite(&self) -> u64x2 {
+        unsafe {
+            let abs = _mm_and_pd(*self, Self::splat(transmute::<u64, f64>(0x7FFFFFFFFFFFFFFF)));
+            transmute(_mm_cmpeq_pd(abs, Self::splat(transmute::<u64, f64>(0x7FF0000000000000))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn is_finite(&self) -> u64x2 {
+        unsafe {
+            let abs = _mm_and_pd(*self, Self::splat(transmute::<u64, f64>(0x7FFFFFFFFFFFFFFF)));
+            transmute(_mm_cmplt_pd(abs, Self::splat(transmute::<u64, f64>(0x7FF0000000000000))))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn is_sign_negative(&self) -> u64x2 {
+        unsafe {
+            let sign = _mm_and_pd(*self, Self::splat(transmute::<u64, f64>(0x8000000000000000)));
+            transmute(_mm_cmplt_pd(_mm_or_pd(sign, Self::splat(1.0)), Self::splat(0.0)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    float_class_fallback!(u64x2, [0, 1]);
+}
+
+impl FloatClass for f32x8 {
+    type Out = u32x8;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_nan(&self) -> u32x8 {
+        unsafe { transmute(_mm256_cmp_ps(*self, *self, 0x03)) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_infinite(&self) -> u32x8 {
+        unsafe {
+            let abs = _mm256_and_ps(*self, Self::splat(transmute::<u32, f32>(0x7FFFFFFF)));
+            transmute(_mm256_cmp_ps(abs, Self::splat(transmute::<u32, f32>(0x7F800000)), 0x00))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_finite(&self) -> u32x8 {
+        unsafe {
+            let abs = _mm256_and_ps(*self, Self::splat(transmute::<u32, f32>(0x7FFFFFFF)));
+            transmute(_mm256_cmp_ps(abs, Self::splat(transmute::<u32, f32>(0x7F800000)), 0x11))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_sign_negative(&self) -> u32x8 {
+        unsafe {
+            let sign = _mm256_and_ps(*self, Self::splat(transmute::<u32, f32>(0x80000000)));
+            transmute(_mm256_cmp_ps(_mm256_or_ps(sign, Self::splat(1.0)), Self::splat(0.0), 0x11))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+    float_class_fallback!(u32x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+impl FloatClass for f64x4 {
+    type Out = u64x4;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_nan(&self) -> u64x4 {
+        unsafe { transmute(_mm256_cmp_pd(*self, *self, 0x03)) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_infinite(&self) -> u64x4 {
+        unsafe {
+            let abs = _mm256_and_pd(*self, Self::splat(transmute::<u64, f64>(0x7FFFFFFFFFFFFFFF)));
+            transmute(_mm256_cmp_pd(abs, Self::splat(transmute::<u64, f64>(0x7FF0000000000000)), 0x00))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_finite(&self) -> u64x4 {
+        unsafe {
+            let abs = _mm256_and_pd(*self, Self::splat(transmute::<u64, f64>(0x7FFFFFFFFFFFFFFF)));
+            transmute(_mm256_cmp_pd(abs, Self::splat(transmute::<u64, f64>(0x7FF0000000000000)), 0x11))
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
+    fn is_sign_negative(&self) -> u64x4 {
+        unsafe {
+            let sign = _mm256_and_pd(*self, Self::splat(transmute::<u64, f64>(0x8000000000000000)));
+            transmute(_mm256_cmp_pd(_mm256_or_pd(sign, Self::splat(1.0)), Self::splat(0.0), 0x11))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
+    float_class_fallback!(u64x4, [0, 1, 2, 3]);
+}
+
+impl FloatClass for f32x16 {
+    type Out = u32x16;
+    float_class_fallback!(u32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl FloatClass for f64x8 {
+    type Out = u64x8;
+    float_class_fallback!(u64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+macro_rules! impl_float_sign {
+    ($vec:ident, $el:ident, $bits:ident, $sign:expr, $feat:tt, $and:ident, $andnot:ident, $or:ident, $unord:ident($($imm:expr),*), [$($n:expr),*]) => {
+        impl FloatSign for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn copysign(&self, sign: Self) -> Self {
+                unsafe {
+                    let mask = Self::splat(transmute::<$bits, $el>($sign));
+                    $or($andnot(mask, *self), $and(mask, sign))
+                }
+            }
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn signum(&self) -> Self {
+                unsafe {
+                    let mask = Self::splat(transmute::<$bits, $el>($sign));
+                    let signum = $or($and(mask, *self), Self::splat(1.0));
+                    // Pass NaNs through untouched
+                    let nan = $unord(*self, *self, $($imm),*);
+                    $or($andnot(nan, signum), $and(nan, *self))
+                }
+            }
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn neg_abs(&self) -> Self {
+                unsafe { $or(*self, Self::splat(transmute::<$bits, $el>($sign))) }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            float_sign_fallback!([$($n),*]);
+        }
+    }
+}
+
+impl_float_sign!(f32x4, f32, u32, 0x80000000, "sse", _mm_and_ps, _mm_andnot_ps, _mm_or_ps,
+                 _mm_cmpunord_ps(), [0, 1, 2, 3]);
+impl_float_sign!(f64x2, f64, u64, 0x8000000000000000, "sse2", _mm_and_pd, _mm_andnot_pd, _mm_or_pd,
+                 _mm_cmpunord_pd(), [0, 1]);
+impl_float_sign!(f32x8, f32, u32, 0x80000000, "avx", _mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps,
+                 _mm256_cmp_ps(0x03), [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_float_sign!(f64x4, f64, u64, 0x8000000000000000, "avx", _mm256_and_pd, _mm256_andnot_pd, _mm256_or_pd,
+                 _mm256_cmp_pd(0x03), [0, 1, 2, 3]);
+
+impl FloatSign for f32x16 {
+    float_sign_fallback!([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl FloatSign for f64x8 {
+    float_sign_fallback!([0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+macro_rules! impl_fract {
+    ($vec:ident, $feat:tt, $round:ident, [$($n:expr),*]) => {
+        impl Fract for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn fract(&self) -> Self {
+                *self - unsafe { $round(*self, _MM_FROUND_TRUNC) }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            fract_fallback!([$($n),*]);
+        }
+    };
+    ($vec:ident, [$($n:expr),*]) => {
+        impl Fract for $vec {
+            fract_fallback!([$($n),*]);
+        }
+    };
+}
+
+impl_fract!(f32x4, "sse4.1", _mm_round_ps, [0, 1, 2, 3]);
+impl_fract!(f64x2, "sse4.1", _mm_round_pd, [0, 1]);
+impl_fract!(f32x8, "avx", _mm256_round_ps, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_fract!(f64x4, "avx", _mm256_round_pd, [0, 1, 2, 3]);
+impl_fract!(f32x16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_fract!(f64x8, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+
+    macro_rules! test_float {
+        ($name:ident, $vec:ident, $el:ident, $bits:ident) => {
+            #[test]
+            fn $name() {
+                use core_or_std::$el::*;
+                let xs = [0.0, -0.0, 1.0, -1.0, 0.5, -0.5, 2.25, -3.75, 1e10, -1e10, 12345.678, -0.001,
+                          MIN_POSITIVE, -MIN_POSITIVE, MIN_POSITIVE / 4.0, -MIN_POSITIVE / 4.0,
+                          MAX, MIN, EPSILON, INFINITY, NEG_INFINITY, NAN, -NAN, 8388607.5, 4503599627370495.5];
+                for i in 0..xs.len() {
+                    let v = $vec::from_fn(|j| xs[(i + j) % xs.len()]);
+                    let s = $vec::from_fn(|j| xs[(i * 7 + j * 3) % xs.len()]);
+                    let mask = |b: bool| if b { $bits::max_value() } else { 0 };
+                    let same = |a: $el, b: $el| a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan());
+                    let (nan, inf, fin, neg) = (v.is_nan(), v.is_infinite(), v.is_finite(), v.is_sign_negative());
+                    let (copysign, signum, neg_abs, fract) = (v.copysign(s), v.signum(), v.neg_abs(), v.fract());
+                    for j in 0..$vec::WIDTH {
+                        let (x, y) = (v.extract(j as u32), s.extract(j as u32));
+                        assert_eq!(nan.extract(j as u32), mask(x.is_nan()), "{:?}.is_nan()", x);
+                        assert_eq!(inf.extract(j as u32), mask(x.is_infinite()), "{:?}.is_infinite()", x);
+                        assert_eq!(fin.extract(j as u32), mask(x.is_finite()), "{:?}.is_finite()", x);
+                        assert_eq!(neg.extract(j as u32), mask(x.is_sign_negative()), "{:?}.is_sign_negative()", x);
+                        assert!(same(copysign.extract(j as u32), $el::from_bits(x.abs().to_bits() | (y.to_bits() & (-0.0 as $el).to_bits()))), "{:?}.copysign({:?})", x, y);
+                        assert!(same(signum.extract(j as u32), x.signum()), "{:?}.signum()", x);
+                        assert!(same(neg_abs.extract(j as u32), -x.abs()), "{:?}.neg_abs()", x);
+                        assert!(same(fract.extract(j as u32), x.fract()), "{:?}.fract()", x);
+                    }
+                }
+            }
+        }
+    }
+
+    test_float!(float_f32x4, f32x4, f32, u32);
+    test_float!(float_f32x8, f32x8, f32, u32);
+    test_float!(float_f32x16, f32x16, f32, u32);
+    test_float!(float_f64x2, f64x2, f64, u64);
+    test_float!(float_f64x4, f64x4, f64, u64);
+    test_float!(float_f64x8, f64x8, f64, u64);
+}
@@ -0,0 +1,133 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use vecs::*;
+use stdsimd::vendor::*;
+use core_or_std::mem::transmute;
+
+pub trait Gather : Packed {
+    /// Return a vector whose nth element is `data[offset + stride * n]`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [0u32, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    /// assert_eq!(u32x4::gather(&data, 1, 4), u32x4::new(1, 5, 9, 13));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn gather(data: &[Self::Scalar], offset: usize, stride: usize) -> Self {
+        assert!(offset + stride * (Self::WIDTH - 1) < data.len());
+        unsafe { Self::gather_unchecked(data, offset, stride) }
+    }
+
+    /// Return a vector whose nth element is `data[offset + stride * n]`,
+    /// without asserting that every element is within `data`.
+    unsafe fn gather_unchecked(data: &[Self::Scalar], offset: usize, stride: usize) -> Self;
+}
+
+macro_rules! impl_gather {
+    ($vec:tt, $el:tt, $feat:expr, $mmfn:ident, $idx:tt, $idxel:tt, $ptr:ty, $scale:expr, ($($n:expr),*)) => {
+        impl Gather for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            unsafe fn gather_unchecked(data: &[$el], offset: usize, stride: usize) -> Self {
+                debug_assert!(offset + stride * (Self::WIDTH - 1) < data.len());
+                let stride = stride as $idxel;
+                transmute($mmfn(data.as_ptr().offset(offset as isize) as *const $ptr,
+                                transmute($idx::new($($n * stride),*)), $scale))
+            }
+
+            #[inline(always)]
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            unsafe fn gather_unchecked(data: &[$el], offset: usize, stride: usize) -> Self {
+                debug_assert!(offset + stride * (Self::WIDTH - 1) < data.len());
+                Self::new($(*data.get_unchecked(offset + stride * $n)),*)
+            }
+        }
+    };
+    ($vec:tt, $el:tt, ($($n:expr),*)) => {
+        impl Gather for $vec {
+            #[inline(always)]
+            unsafe fn gather_unchecked(data: &[$el], offset: usize, stride: usize) -> Self {
+                debug_assert!(offset + stride * (Self::WIDTH - 1) < data.len());
+                Self::new($(*data.get_unchecked(offset + stride * $n)),*)
+            }
+        }
+    }
+}
+
+impl_gather!(u8x64, u8, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63));
+impl_gather!(u8x32, u8, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_gather!(u8x16, u8, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+
+impl_gather!(i8x64, i8, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63));
+impl_gather!(i8x32, i8, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_gather!(i8x16, i8, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+
+impl_gather!(u16x32, u16, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_gather!(u16x16, u16, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_gather!(u16x8, u16, (0, 1, 2, 3, 4, 5, 6, 7));
+
+impl_gather!(i16x32, i16, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_gather!(i16x16, i16, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_gather!(i16x8, i16, (0, 1, 2, 3, 4, 5, 6, 7));
+
+impl_gather!(u32x16, u32, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_gather!(u32x8, u32, "avx2", _mm256_i32gather_epi32, i32x8, i32, i32, 4, (0, 1, 2, 3, 4, 5, 6, 7));
+impl_gather!(u32x4, u32, "avx2", _mm_i32gather_epi32, i32x4, i32, i32, 4, (0, 1, 2, 3));
+
+impl_gather!(i32x16, i32, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_gather!(i32x8, i32, "avx2", _mm256_i32gather_epi32, i32x8, i32, i32, 4, (0, 1, 2, 3, 4, 5, 6, 7));
+impl_gather!(i32x4, i32, "avx2", _mm_i32gather_epi32, i32x4, i32, i32, 4, (0, 1, 2, 3));
+
+impl_gather!(f32x16, f32, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_gather!(f32x8, f32, "avx2", _mm256_i32gather_ps, i32x8, i32, f32, 4, (0, 1, 2, 3, 4, 5, 6, 7));
+impl_gather!(f32x4, f32, "avx2", _mm_i32gather_ps, i32x4, i32, f32, 4, (0, 1, 2, 3));
+
+impl_gather!(u64x8, u64, (0, 1, 2, 3, 4, 5, 6, 7));
+impl_gather!(u64x4, u64, "avx2", _mm256_i64gather_epi64, i64x4, i64, i64, 8, (0, 1, 2, 3));
+impl_gather!(u64x2, u64, "avx2", _mm_i64gather_epi64, i64x2, i64, i64, 8, (0, 1));
+
+impl_gather!(i64x8, i64, (0, 1, 2, 3, 4, 5, 6, 7));
+impl_gather!(i64x4, i64, "avx2", _mm256_i64gather_epi64, i64x4, i64, i64, 8, (0, 1, 2, 3));
+impl_gather!(i64x2, i64, "avx2", _mm_i64gather_epi64, i64x2, i64, i64, 8, (0, 1));
+
+impl_gather!(f64x8, f64, (0, 1, 2, 3, 4, 5, 6, 7));
+impl_gather!(f64x4, f64, "avx2", _mm256_i64gather_pd, i64x4, i64, f64, 8, (0, 1, 2, 3));
+impl_gather!(f64x2, f64, "avx2", _mm_i64gather_pd, i64x2, i64, f64, 8, (0, 1));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vecs::*;
+
+    macro_rules! test_gather {
+        (($($vec:tt),*), ($($el:tt),*), ($($fn:ident),*)) => {
+            $(
+                #[test]
+                fn $fn() {
+                    let data = (0..128).map(|i| i as $el).collect::<Vec<$el>>();
+                    for stride in 1..6 {
+                        for offset in 0..3 {
+                            let v = $vec::gather(&data, offset, stride);
+                            for i in 0..$vec::WIDTH {
+                                assert_eq!(v.extract(i as u32), data[offset + stride * i]);
+                            }
+                        }
+                    }
+                }
+            )*
+        }
+    }
+
+    test_gather!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                 (u8, i8, u16, i16, u32, i32, f32, u64, i64, f64, u32, i32, f32, u64, i64, f64),
+                 (gather_u8x16, gather_i8x16, gather_u16x8, gather_i16x8, gather_u32x4, gather_i32x4, gather_f32x4, gather_u64x2, gather_i64x2, gather_f64x2, gather_u32x8, gather_i32x8, gather_f32x8, gather_u64x4, gather_i64x4, gather_f64x4));
+}
@@ -18,7 +18,7 @@ pub trait HAdd {
     fn hadd(&self, other: Self) -> Self;
 }
 
-#[cfg(target_feature = "sse3")]
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
 impl HAdd for f32x4 {
     #[inline(always)]
     fn hadd(&self, other: Self) -> Self {
@@ -27,16 +27,16 @@ impl HAdd for f32x4 {
     }
 }
 
-#[cfg(target_feature = "sse3")]
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
 impl HAdd for f64x2 {
     #[inline(always)]
-    #[cfg(target_feature = "sse3")]
+    #[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
     fn hadd(&self, other: Self) -> Self {
         unsafe { _mm_hadd_pd(*self, other) }
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl HAdd for f32x8 {
     #[inline(always)]
     fn hadd(&self, other: Self) -> Self {
@@ -45,7 +45,7 @@ impl HAdd for f32x8 {
     }
 }
 
-#[cfg(target_feature = "avx")]
+#[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
 impl HAdd for f64x4 {
     #[inline(always)]
     fn hadd(&self, other: Self) -> Self {
@@ -53,7 +53,7 @@ impl HAdd for f64x4 {
     }
 }
 
-#[cfg(target_feature = "ssse3")]
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
 impl HAdd for i16x8 {
     #[inline(always)]
     fn hadd(&self, other: Self) -> Self {
@@ -62,7 +62,7 @@ impl HAdd for i16x8 {
     }
 }
 
-#[cfg(target_feature = "ssse3")]
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
 impl HAdd for i32x4 {
     #[inline(always)]
     fn hadd(&self, other: Self) -> Self {
@@ -71,7 +71,7 @@ impl HAdd for i32x4 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl HAdd for i16x16 {
     #[inline(always)]
     fn hadd(&self, other: Self) -> Self {
@@ -81,7 +81,7 @@ impl HAdd for i16x16 {
 }
 
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl HAdd for i32x8 {
     #[inline(always)]
     fn hadd(&self, other: Self) -> Self {
@@ -105,27 +105,27 @@ impl HAdd for u8x64 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11,
 impl HAdd for i64x2 { hop!(hadd, Add::add, 0, 1); }
 impl HAdd for i64x4 { hop!(hadd, Add::add, 0, 1, 2, 3); }
 impl HAdd for i64x8 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7); }
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 impl HAdd for i32x4 { hop!(hadd, Add::add, 0, 1, 2, 3); }
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl HAdd for i32x8 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7); }
 impl HAdd for i32x16 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 impl HAdd for i16x8 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7); }
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl HAdd for i16x16 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
 impl HAdd for i16x32 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31); }
 impl HAdd for i8x16 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
 impl HAdd for i8x32 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31); }
 impl HAdd for i8x64 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63); }
-#[cfg(not(target_feature = "sse3"))]
+#[cfg(any(not(target_feature = "sse3"), feature = "force-scalar"))]
 impl HAdd for f64x2 { hop!(hadd, Add::add, 0, 1); }
-#[cfg(not(target_feature = "avx"))]
+#[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
 impl HAdd for f64x4 { hop!(hadd, Add::add, 0, 1, 2, 3); }
 impl HAdd for f64x8 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7); }
-#[cfg(not(target_feature = "sse3"))]
+#[cfg(any(not(target_feature = "sse3"), feature = "force-scalar"))]
 impl HAdd for f32x4 { hop!(hadd, Add::add, 0, 1, 2, 3); }
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl HAdd for f32x8 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7); }
 impl HAdd for f32x16 { hop!(hadd, Add::add, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
 
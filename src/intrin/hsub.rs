@@ -18,7 +18,7 @@ pub trait HSub {
     fn hsub(&self, other: Self) -> Self;
 }
 
-#[cfg(target_feature = "sse3")]
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
 impl HSub for f32x4 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -27,7 +27,7 @@ impl HSub for f32x4 {
     }
 }
 
-#[cfg(target_feature = "sse3")]
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
 impl HSub for f64x2 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -35,7 +35,7 @@ impl HSub for f64x2 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl HSub for f32x8 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -44,7 +44,7 @@ impl HSub for f32x8 {
     }
 }
 
-#[cfg(target_feature = "avx")]
+#[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
 impl HSub for f64x4 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -52,7 +52,7 @@ impl HSub for f64x4 {
     }
 }
 
-#[cfg(target_feature = "ssse3")]
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
 impl HSub for i16x8 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -61,7 +61,7 @@ impl HSub for i16x8 {
     }
 }
 
-#[cfg(target_feature = "ssse3")]
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
 impl HSub for i32x4 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -70,7 +70,7 @@ impl HSub for i32x4 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl HSub for i16x16 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -79,7 +79,7 @@ impl HSub for i16x16 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl HSub for i32x8 {
     #[inline(always)]
     fn hsub(&self, other: Self) -> Self {
@@ -103,27 +103,27 @@ impl HSub for u8x64 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11,
 impl HSub for i64x2 { hop!(hsub, Sub::sub, 0, 1); }
 impl HSub for i64x4 { hop!(hsub, Sub::sub, 0, 1, 2, 3); }
 impl HSub for i64x8 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7); }
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 impl HSub for i32x4 { hop!(hsub, Sub::sub, 0, 1, 2, 3); }
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl HSub for i32x8 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7); }
 impl HSub for i32x16 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 impl HSub for i16x8 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7); }
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl HSub for i16x16 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
 impl HSub for i16x32 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31); }
 impl HSub for i8x16 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
 impl HSub for i8x32 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31); }
 impl HSub for i8x64 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63); }
-#[cfg(not(target_feature = "sse3"))]
+#[cfg(any(not(target_feature = "sse3"), feature = "force-scalar"))]
 impl HSub for f64x2 { hop!(hsub, Sub::sub, 0, 1); }
-#[cfg(not(target_feature = "avx"))]
+#[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
 impl HSub for f64x4 { hop!(hsub, Sub::sub, 0, 1, 2, 3); }
 impl HSub for f64x8 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7); }
-#[cfg(not(target_feature = "sse3"))]
+#[cfg(any(not(target_feature = "sse3"), feature = "force-scalar"))]
 impl HSub for f32x4 { hop!(hsub, Sub::sub, 0, 1, 2, 3); }
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl HSub for f32x8 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7); }
 impl HSub for f32x16 { hop!(hsub, Sub::sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
 
@@ -11,13 +11,13 @@ macro_rules! rust_fallback_impl {
         impl $trait for $type {
             $(
                 #[inline(always)]
-                #[cfg(target_feature = $feat)]
+                #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
                 fn $rustfn(&self) -> Self {
                     unsafe { $mmfn(*self, $($mmfnargs),*) }
                 }
 
                 #[inline(always)]
-                #[cfg(not(target_feature = $feat))]
+                #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
                 fn $rustfn(&self) -> Self {
                     Self::new($(self.extract($n).$rustfn(),)*)
                 }
@@ -32,14 +32,14 @@ macro_rules! rust_fallback_impl_binary {
         impl $trait for $type {
             $(
                 #[inline(always)]
-                #[cfg(target_feature = $feat)]
+                #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
                 fn $rustfn(&self, other: Self) -> Self {
                     use core_or_std::mem::transmute;
                     unsafe { transmute($mmfn(transmute(*self), transmute(other), $($mmfnargs),*)) }
                 }
 
                 #[inline(always)]
-                #[cfg(not(target_feature = $feat))]
+                #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
                 fn $rustfn(&self, other: Self) -> Self {
                     Self::new($(self.extract($n).$rustfn(other.extract($n)),)*)
                 }
@@ -57,3 +57,29 @@ macro_rules! hop {
         }
     }
 }
+
+/// Return an array of integers of type `$el` which are likely to expose
+/// overflow and rounding bugs in the integer intrinsics.
+#[cfg(test)]
+macro_rules! int_edge_cases {
+    ($el:ident) => {{
+        let min = $el::min_value();
+        let max = $el::max_value();
+        [0, 1, 2, 3, 7, 100, max, max - 1, max / 3, min, min + 1, min / 3, min / 2 + 1, 42, 0x55, 0x7F]
+    }}
+}
+
+/// Run `$body` on pairs of vectors of type `$vec` filled with different
+/// arrangements of `int_edge_cases!($el)`, so that every edge case meets
+/// several others in the same lane.
+#[cfg(test)]
+macro_rules! for_int_edge_cases {
+    ($vec:ident, $el:ident, |$a:ident, $b:ident| $body:block) => {{
+        let ns = int_edge_cases!($el);
+        for i in 0..ns.len() {
+            let $a = $vec::from_fn(|j| ns[(i + j) % ns.len()]);
+            let $b = $vec::from_fn(|j| ns[(i * 3 + j * 7) % ns.len()]);
+            $body
+        }
+    }}
+}
@@ -47,7 +47,7 @@ pub trait Merge {
 
 macro_rules! impl_packed_merge {
     ($vec:ty, $uvec:tt, $uscl:tt, $mmfn:expr, $feat:expr, ($($a:expr),*), ($($b:expr),*), $($na:expr, $nb:expr),*) => {
-        #[cfg(not(target_feature = $feat))]
+        #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
         impl Merge for $vec {
 
             #[inline(always)]
@@ -78,7 +78,7 @@ macro_rules! impl_packed_merge {
             }
         }
 
-        #[cfg(target_feature = $feat)]
+        #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
         impl Merge for $vec {
 
             #[inline(always)]
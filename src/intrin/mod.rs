@@ -12,14 +12,28 @@ mod macros;
 
 mod abs;
 pub use self::abs::*;
+mod float;
+pub use self::float::*;
 mod sqrt;
 pub use self::sqrt::*;
 mod transmute;
 pub use self::transmute::*;
 mod cast;
 pub use self::cast::*;
+mod convert;
+pub use self::convert::*;
+mod div;
+pub use self::div::*;
+mod mul;
+pub use self::mul::*;
+mod avg;
+pub use self::avg::*;
+mod abs_diff;
+pub use self::abs_diff::*;
 mod upcast;
 pub use self::upcast::*;
+mod widen;
+pub use self::widen::*;
 mod downcast;
 pub use self::downcast::*;
 mod round;
@@ -50,6 +64,8 @@ mod merge;
 pub use self::merge::*;
 mod swizzle;
 pub use self::swizzle::*;
+mod reverse;
+pub use self::reverse::*;
 mod endian;
 pub use self::endian::*;
 mod sum;
@@ -58,3 +74,5 @@ mod popcnt;
 pub use self::popcnt::*;
 mod destride;
 pub use self::destride::*;
+mod gather;
+pub use self::gather::*;
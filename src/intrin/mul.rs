@@ -0,0 +1,408 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use stdsimd::vendor::*;
+use vecs::*;
+use intrin::upcast::*;
+use core_or_std::mem::transmute;
+
+pub trait MulHi {
+    /// Return a vector containing the upper halves of the double-width
+    /// products of the elements of `self` and `other`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s(0x8000).mul_hi(u16s(6)), u16s(3));
+    /// assert_eq!(i16s(-0x4000).mul_hi(i16s(8)), i16s(-2));
+    /// # }
+    /// ```
+    fn mul_hi(&self, other: Self) -> Self;
+}
+
+pub trait MulWide {
+    type Out;
+
+    /// Return two vectors containing the full, double-width products of the
+    /// elements of `self` and `other`. The first vector contains the products
+    /// of the first half of `self`, and the second vector contains the
+    /// products of the second half.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s(1000).mul_wide(u16s(1000)), (u32s(1000000), u32s(1000000)));
+    /// assert_eq!(i8s(-128).mul_wide(i8s(-128)), (i16s(16384), i16s(16384)));
+    /// # }
+    /// ```
+    fn mul_wide(&self, other: Self) -> (Self::Out, Self::Out);
+}
+
+pub trait MulAddPairs<T> {
+    type Out;
+
+    /// Return a vector containing the sums of the double-width products of
+    /// each adjacent pair of elements in `self` and `other`. The sums of
+    /// `i16s` products wrap, and the sums of `u8s` and `i8s` products
+    /// saturate.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i16s(300).mul_add_pairs(i16s(-200)), i32s(-120000));
+    /// assert_eq!(u8s(255).mul_add_pairs(i8s(127)), i16s(32767));
+    /// # }
+    /// ```
+    fn mul_add_pairs(&self, other: T) -> Self::Out;
+}
+
+pub trait MulHrs {
+    /// Return a vector containing the products of the elements of `self`
+    /// and `other` as Q15 fixed-point numbers, rounded to nearest.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i16s(0x4000).mulhrs(i16s(-0x2000)), i16s(-0x1000));
+    /// # }
+    /// ```
+    fn mulhrs(&self, other: Self) -> Self;
+}
+
+macro_rules! mul_hi_fallback {
+    ($el:ty, $wide:ty, $bits:expr, [$($n:expr),*]) => {
+        #[inline(always)]
+        fn mul_hi(&self, other: Self) -> Self {
+            Self::new($(((self.extract($n) as $wide * other.extract($n) as $wide) >> $bits) as $el),*)
+        }
+    }
+}
+
+macro_rules! mul_wide_fallback {
+    ($out:ident, $wide:ty, [$($lo:expr),*], [$($hi:expr),*]) => {
+        #[inline(always)]
+        fn mul_wide(&self, other: Self) -> ($out, $out) {
+            ($out::new($(self.extract($lo) as $wide * other.extract($lo) as $wide),*),
+             $out::new($(self.extract($hi) as $wide * other.extract($hi) as $wide),*))
+        }
+    }
+}
+
+macro_rules! mul_add_pairs_fallback {
+    ($rhs:ident, $out:ident, $wide:ty, $add:ident, [$($a:expr, $b:expr),*]) => {
+        #[inline(always)]
+        fn mul_add_pairs(&self, other: $rhs) -> $out {
+            $out::new($((self.extract($a) as $wide * other.extract($a) as $wide)
+                        .$add(self.extract($b) as $wide * other.extract($b) as $wide)),*)
+        }
+    }
+}
+
+macro_rules! mulhrs_fallback {
+    ([$($n:expr),*]) => {
+        #[inline(always)]
+        fn mulhrs(&self, other: Self) -> Self {
+            Self::new($((((self.extract($n) as i32 * other.extract($n) as i32) + 0x4000) >> 15) as i16),*)
+        }
+    }
+}
+
+macro_rules! impl_mulhrs {
+    ($vec:ident, [$($n:expr),*]) => {
+        impl MulHrs for $vec {
+            mulhrs_fallback!([$($n),*]);
+        }
+    };
+    ($vec:ident, $feat:tt, $mmfn:ident, [$($n:expr),*]) => {
+        impl MulHrs for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn mulhrs(&self, other: Self) -> Self {
+                unsafe { transmute($mmfn(transmute(*self), transmute(other))) }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            mulhrs_fallback!([$($n),*]);
+        }
+    };
+}
+
+impl_mulhrs!(i16x8, "ssse3", _mm_mulhrs_epi16, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_mulhrs!(i16x16, "avx2", _mm256_mulhrs_epi16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mulhrs!(i16x32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+
+macro_rules! impl_mul_hi {
+    ($vec:ident, $el:ty, $wide:ty, $bits:expr, [$($n:expr),*]) => {
+        impl MulHi for $vec {
+            mul_hi_fallback!($el, $wide, $bits, [$($n),*]);
+        }
+    };
+    ($vec:ident, $el:ty, $wide:ty, $bits:expr, $feat:tt, $mmfn:ident, [$($n:expr),*]) => {
+        impl MulHi for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn mul_hi(&self, other: Self) -> Self {
+                unsafe { transmute($mmfn(transmute(*self), transmute(other))) }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            mul_hi_fallback!($el, $wide, $bits, [$($n),*]);
+        }
+    };
+}
+
+impl_mul_hi!(u16x8, u16, u32, 16, "sse2", _mm_mulhi_epu16, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_mul_hi!(i16x8, i16, i32, 16, "sse2", _mm_mulhi_epi16, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_mul_hi!(u16x16, u16, u32, 16, "avx2", _mm256_mulhi_epu16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_hi!(i16x16, i16, i32, 16, "avx2", _mm256_mulhi_epi16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_hi!(u16x32, u16, u32, 16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_mul_hi!(i16x32, i16, i32, 16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+// x86 only multiplies the even 32-bit elements of two vectors into 64-bit
+// products, so the odd elements are shifted down and multiplied separately,
+// and the upper halves of both sets of products are shuffled back together.
+macro_rules! impl_mul_hi_32 {
+    ($vec:ident, $el:ty, $wide:ty, $feat:tt, $mul:ident, $srli:ident, $shuffle:ident, $unpack:ident, [$($n:expr),*]) => {
+        impl MulHi for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn mul_hi(&self, other: Self) -> Self {
+                unsafe {
+                    let even = $mul(transmute(*self), transmute(other));
+                    let odd = $mul(transmute($srli(transmute(*self), 32)), transmute($srli(transmute(other), 32)));
+                    transmute($unpack(transmute($shuffle(transmute(even), 0x0D)), transmute($shuffle(transmute(odd), 0x0D))))
+                }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            mul_hi_fallback!($el, $wide, 32, [$($n),*]);
+        }
+    }
+}
+
+impl_mul_hi_32!(u32x4, u32, u64, "sse2", _mm_mul_epu32, _mm_srli_epi64, _mm_shuffle_epi32, _mm_unpacklo_epi32, [0, 1, 2, 3]);
+impl_mul_hi_32!(i32x4, i32, i64, "sse4.1", _mm_mul_epi32, _mm_srli_epi64, _mm_shuffle_epi32, _mm_unpacklo_epi32, [0, 1, 2, 3]);
+impl_mul_hi_32!(u32x8, u32, u64, "avx2", _mm256_mul_epu32, _mm256_srli_epi64, _mm256_shuffle_epi32, _mm256_unpacklo_epi32, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_mul_hi_32!(i32x8, i32, i64, "avx2", _mm256_mul_epi32, _mm256_srli_epi64, _mm256_shuffle_epi32, _mm256_unpacklo_epi32, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_mul_hi!(u32x16, u32, u64, 32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_hi!(i32x16, i32, i64, 32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+impl MulWide for u16x8 {
+    type Out = u32x4;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn mul_wide(&self, other: Self) -> (u32x4, u32x4) {
+        unsafe {
+            let lo: u16x8 = transmute(_mm_mullo_epi16(transmute(*self), transmute(other)));
+            let hi: u16x8 = transmute(_mm_mulhi_epu16(transmute(*self), transmute(other)));
+            (transmute(_mm_unpacklo_epi16(transmute(lo), transmute(hi))),
+             transmute(_mm_unpackhi_epi16(transmute(lo), transmute(hi))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    mul_wide_fallback!(u32x4, u32, [0, 1, 2, 3], [4, 5, 6, 7]);
+}
+
+impl MulWide for i16x8 {
+    type Out = i32x4;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
+    fn mul_wide(&self, other: Self) -> (i32x4, i32x4) {
+        unsafe {
+            let lo: i16x8 = transmute(_mm_mullo_epi16(transmute(*self), transmute(other)));
+            let hi: i16x8 = transmute(_mm_mulhi_epi16(transmute(*self), transmute(other)));
+            (transmute(_mm_unpacklo_epi16(transmute(lo), transmute(hi))),
+             transmute(_mm_unpackhi_epi16(transmute(lo), transmute(hi))))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
+    mul_wide_fallback!(i32x4, i32, [0, 1, 2, 3], [4, 5, 6, 7]);
+}
+
+impl MulWide for u16x16 {
+    type Out = u32x8;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn mul_wide(&self, other: Self) -> (u32x8, u32x8) {
+        unsafe {
+            let lo: u16x16 = transmute(_mm256_mullo_epi16(transmute(*self), transmute(other)));
+            let hi: u16x16 = transmute(_mm256_mulhi_epu16(transmute(*self), transmute(other)));
+            // Unpacking works within 128-bit lanes, so put the halves back in order
+            let a: u32x8 = transmute(_mm256_unpacklo_epi16(transmute(lo), transmute(hi)));
+            let b: u32x8 = transmute(_mm256_unpackhi_epi16(transmute(lo), transmute(hi)));
+            (transmute(_mm256_permute2x128_si256(transmute(a), transmute(b), 0x20)),
+             transmute(_mm256_permute2x128_si256(transmute(a), transmute(b), 0x31)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    mul_wide_fallback!(u32x8, u32, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+impl MulWide for i16x16 {
+    type Out = i32x8;
+
+    #[inline(always)]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
+    fn mul_wide(&self, other: Self) -> (i32x8, i32x8) {
+        unsafe {
+            let lo: i16x16 = transmute(_mm256_mullo_epi16(transmute(*self), transmute(other)));
+            let hi: i16x16 = transmute(_mm256_mulhi_epi16(transmute(*self), transmute(other)));
+            // Unpacking works within 128-bit lanes, so put the halves back in order
+            let a: i32x8 = transmute(_mm256_unpacklo_epi16(transmute(lo), transmute(hi)));
+            let b: i32x8 = transmute(_mm256_unpackhi_epi16(transmute(lo), transmute(hi)));
+            (transmute(_mm256_permute2x128_si256(transmute(a), transmute(b), 0x20)),
+             transmute(_mm256_permute2x128_si256(transmute(a), transmute(b), 0x31)))
+        }
+    }
+
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
+    mul_wide_fallback!(i32x8, i32, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+macro_rules! impl_mul_wide {
+    ($vec:ident, $out:ident, $wide:ty, [$($lo:expr),*], [$($hi:expr),*]) => {
+        impl MulWide for $vec {
+            type Out = $out;
+            mul_wide_fallback!($out, $wide, [$($lo),*], [$($hi),*]);
+        }
+    }
+}
+
+// Widen both operands, whose products then fit in the wider elements
+macro_rules! impl_mul_wide_upcast {
+    ($vec:ident, $out:ident, $wide:ty, $feat:tt, $mul:ident, [$($lo:expr),*], [$($hi:expr),*]) => {
+        impl MulWide for $vec {
+            type Out = $out;
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn mul_wide(&self, other: Self) -> ($out, $out) {
+                let (a, b): ($out, $out) = self.upcast();
+                let (c, d): ($out, $out) = other.upcast();
+                unsafe {
+                    (transmute($mul(transmute(a), transmute(c))),
+                     transmute($mul(transmute(b), transmute(d))))
+                }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            mul_wide_fallback!($out, $wide, [$($lo),*], [$($hi),*]);
+        }
+    }
+}
+
+impl_mul_wide_upcast!(u8x16, u16x8, u16, "sse2", _mm_mullo_epi16, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_wide_upcast!(i8x16, i16x8, i16, "sse2", _mm_mullo_epi16, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_wide_upcast!(u8x32, u16x16, u16, "avx2", _mm256_mullo_epi16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_mul_wide_upcast!(i8x32, i16x16, i16, "avx2", _mm256_mullo_epi16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_mul_wide_upcast!(u32x4, u64x2, u64, "sse2", _mm_mul_epu32, [0, 1], [2, 3]);
+impl_mul_wide_upcast!(i32x4, i64x2, i64, "sse4.1", _mm_mul_epi32, [0, 1], [2, 3]);
+impl_mul_wide_upcast!(u32x8, u64x4, u64, "avx2", _mm256_mul_epu32, [0, 1, 2, 3], [4, 5, 6, 7]);
+impl_mul_wide_upcast!(i32x8, i64x4, i64, "avx2", _mm256_mul_epi32, [0, 1, 2, 3], [4, 5, 6, 7]);
+impl_mul_wide!(u8x64, u16x32, u16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], [32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_mul_wide!(i8x64, i16x32, i16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31], [32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+impl_mul_wide!(u16x32, u32x16, u32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_mul_wide!(i16x32, i32x16, i32, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_mul_wide!(u32x16, u64x8, u64, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_wide!(i32x16, i64x8, i64, [0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]);
+
+macro_rules! impl_mul_add_pairs {
+    ($vec:ident, $rhs:ident, $out:ident, $wide:ty, $add:ident, [$($a:expr, $b:expr),*]) => {
+        impl MulAddPairs<$rhs> for $vec {
+            type Out = $out;
+            mul_add_pairs_fallback!($rhs, $out, $wide, $add, [$($a, $b),*]);
+        }
+    };
+    ($vec:ident, $rhs:ident, $out:ident, $wide:ty, $add:ident, $feat:tt, $mmfn:ident, [$($a:expr, $b:expr),*]) => {
+        impl MulAddPairs<$rhs> for $vec {
+            type Out = $out;
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn mul_add_pairs(&self, other: $rhs) -> $out {
+                unsafe { transmute($mmfn(transmute(*self), transmute(other))) }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            mul_add_pairs_fallback!($rhs, $out, $wide, $add, [$($a, $b),*]);
+        }
+    };
+}
+
+impl_mul_add_pairs!(i16x8, i16x8, i32x4, i32, wrapping_add, "sse2", _mm_madd_epi16, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_mul_add_pairs!(i16x16, i16x16, i32x8, i32, wrapping_add, "avx2", _mm256_madd_epi16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_add_pairs!(i16x32, i16x32, i32x16, i32, wrapping_add, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_mul_add_pairs!(u8x16, i8x16, i16x8, i16, saturating_add, "ssse3", _mm_maddubs_epi16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_mul_add_pairs!(u8x32, i8x32, i16x16, i16, saturating_add, "avx2", _mm256_maddubs_epi16, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]);
+impl_mul_add_pairs!(u8x64, i8x64, i16x32, i16, saturating_add, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]);
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+
+    macro_rules! test_mul {
+        ($name:ident, $vec:ident, $el:ident, $wide:ident, $bits:expr) => {
+            #[test]
+            fn $name() {
+                for_int_edge_cases!($vec, $el, |a, b| {
+                    let wide = |i: usize| a.extract(i as u32) as $wide * b.extract(i as u32) as $wide;
+                    let (lo, hi) = a.mul_wide(b);
+                    assert_eq!(a.mul_hi(b), $vec::from_fn(|i| (wide(i) >> $bits) as $el));
+                    for i in 0..$vec::WIDTH / 2 {
+                        assert_eq!(lo.extract(i as u32), wide(i));
+                        assert_eq!(hi.extract(i as u32), wide(i + $vec::WIDTH / 2));
+                    }
+                });
+            }
+        }
+    }
+
+    test_mul!(mul_u16s, u16s, u16, u32, 16);
+    test_mul!(mul_i16s, i16s, i16, i32, 16);
+    test_mul!(mul_u32s, u32s, u32, u64, 32);
+    test_mul!(mul_i32s, i32s, i32, i64, 32);
+
+    #[test]
+    fn mul_wide_bytes() {
+        let a = u8s::interleave(255, 3);
+        let b = i8s::interleave(-128, 127);
+        assert_eq!(a.mul_wide(a), (u16s::interleave(65025, 9), u16s::interleave(65025, 9)));
+        assert_eq!(b.mul_wide(b), (i16s::interleave(16384, 16129), i16s::interleave(16384, 16129)));
+    }
+
+    #[test]
+    fn mul_add_pairs() {
+        assert_eq!(i16s::interleave(-32768, -32768).mul_add_pairs(i16s(-32768)), i32s(i32::min_value()));
+        assert_eq!(i16s::interleave(1000, -3).mul_add_pairs(i16s::interleave(2000, 5)), i32s(1999985));
+        assert_eq!(u8s(255).mul_add_pairs(i8s(-128)), i16s(-32768));
+        assert_eq!(u8s::interleave(200, 10).mul_add_pairs(i8s::interleave(-3, 50)), i16s(-100));
+        assert_eq!(u8s::interleave(255, 255).mul_add_pairs(i8s::interleave(127, -128)), i16s(-255));
+    }
+
+    #[test]
+    fn mulhrs() {
+        for &(a, b) in [(0x4000, -0x2000), (-32768, -32768), (32767, 32767), (1, 1), (-1, 1), (3, 0x2AAB), (-32768, 1)].iter() {
+            let expected = (((a as i32 * b as i32) + 0x4000) >> 15) as i16;
+            assert_eq!(i16s(a).mulhrs(i16s(b)), i16s(expected));
+        }
+        assert_eq!(i16s(-32768).mulhrs(i16s(-32768)), i16s(-32768));
+    }
+}
@@ -18,7 +18,7 @@ pub trait Popcnt : Packed {
 }
 
 #[inline(always)]
-#[cfg(target_feature = "ssse3")]
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
 unsafe fn popcnt128(v: u8x16) -> usize {
     // SSE3 popcnt algorithm by Wojciech Muła
     // http://wm.ite.pl/articles/sse-popcount.html
@@ -30,14 +30,14 @@ unsafe fn popcnt128(v: u8x16) -> usize {
 }
 
 #[inline(always)]
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 #[allow(unused_unsafe)]
 unsafe fn popcnt128(v: u8x16) -> usize {
     v.be_u64s(). scalar_reduce(0, |acc, s| acc + (s.count_ones() as usize))
 }
 
 #[inline(always)]
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 unsafe fn popcnt256(v: u8x32) -> usize {
     // AVX2 popcnt algorithm by Wojciech Muła, Nathan Kurz, and Daniel Lemire
     // https://arxiv.org/abs/1611.07612
@@ -49,7 +49,7 @@ unsafe fn popcnt256(v: u8x32) -> usize {
 }
 
 #[inline(always)]
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 #[allow(unused_unsafe)]
 unsafe fn popcnt256(v: u8x32) -> usize {
     v.be_u64s().scalar_reduce(0, |acc, s| acc + (s.count_ones() as usize))
@@ -0,0 +1,156 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use vecs::*;
+use stdsimd::vendor::*;
+use intrin::transmute::*;
+use core_or_std::mem::transmute;
+
+pub trait Reverse : Packed {
+    /// Return a vector containing the elements of `self` in reverse order.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::new(1, 2, 3, 4).reverse(), u32x4::new(4, 3, 2, 1));
+    /// assert_eq!(u8s::interleave(1, 2).reverse(), u8s::interleave(2, 1));
+    /// # }
+    /// ```
+    fn reverse(&self) -> Self;
+}
+
+#[inline(always)]
+fn reverse_polyfill<T : Packed>(v: &T) -> T {
+    let mut ret = *v;
+    for i in 0..T::WIDTH {
+        ret = ret.replace(i, v.extract(T::WIDTH - 1 - i));
+    }
+    ret
+}
+
+macro_rules! impl_packed_reverse {
+    ($vec:tt, $feat:expr, ($($c:expr),*)) => {
+        impl Reverse for $vec {
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            #[inline(always)]
+            fn reverse(&self) -> Self {
+                reverse_polyfill(self)
+            }
+
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            #[inline(always)]
+            fn reverse(&self) -> Self {
+                unsafe {
+                    transmute(_mm_shuffle_epi8(self.be_u8s(), u8x16::new($($c),*)))
+                }
+            }
+        }
+    };
+    ($vec:tt, $feat:expr, ($($c:expr),*), lanes) => {
+        impl Reverse for $vec {
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            #[inline(always)]
+            fn reverse(&self) -> Self {
+                reverse_polyfill(self)
+            }
+
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            #[inline(always)]
+            fn reverse(&self) -> Self {
+                unsafe {
+                    // Reverse each 128-bit lane, then swap the lanes
+                    let lanes = _mm256_shuffle_epi8(self.be_u8s(), u8x32::new($($c),*)).be_i64s();
+                    transmute(_mm256_permute4x64_epi64(lanes, 0x4E))
+                }
+            }
+        }
+    };
+    ($($vec:tt),*) => {
+        $(
+            impl Reverse for $vec {
+                #[inline(always)]
+                fn reverse(&self) -> Self {
+                    reverse_polyfill(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_reverse!(u8x16, "ssse3", (15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0));
+impl_packed_reverse!(i8x16, "ssse3", (15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0));
+impl_packed_reverse!(u16x8, "ssse3", (14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1));
+impl_packed_reverse!(i16x8, "ssse3", (14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1));
+impl_packed_reverse!(u32x4, "ssse3", (12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3));
+impl_packed_reverse!(i32x4, "ssse3", (12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3));
+impl_packed_reverse!(f32x4, "ssse3", (12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3));
+impl_packed_reverse!(u64x2, "ssse3", (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_reverse!(i64x2, "ssse3", (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_reverse!(f64x2, "ssse3", (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7));
+
+impl_packed_reverse!(u8x32, "avx2",
+                     (15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+                      15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0), lanes);
+impl_packed_reverse!(i8x32, "avx2",
+                     (15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+                      15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0), lanes);
+impl_packed_reverse!(u16x16, "avx2",
+                     (14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1,
+                      14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1), lanes);
+impl_packed_reverse!(i16x16, "avx2",
+                     (14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1,
+                      14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1), lanes);
+impl_packed_reverse!(u32x8, "avx2",
+                     (12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3,
+                      12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3), lanes);
+impl_packed_reverse!(i32x8, "avx2",
+                     (12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3,
+                      12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3), lanes);
+impl_packed_reverse!(f32x8, "avx2",
+                     (12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3,
+                      12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3), lanes);
+impl_packed_reverse!(u64x4, "avx2",
+                     (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7,
+                      8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7), lanes);
+impl_packed_reverse!(i64x4, "avx2",
+                     (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7,
+                      8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7), lanes);
+impl_packed_reverse!(f64x4, "avx2",
+                     (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7,
+                      8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7), lanes);
+
+impl_packed_reverse!(u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_reverse {
+        (($($vec:tt),*), ($($fn:ident),*)) => {
+            $(
+                #[test]
+                fn $fn() {
+                    let mut v = $vec::splat(0 as <$vec as Packed>::Scalar);
+                    for i in 0..$vec::WIDTH {
+                        v = v.replace(i as u32, i as <$vec as Packed>::Scalar);
+                    }
+                    let r = v.reverse();
+                    for i in 0..$vec::WIDTH {
+                        assert_eq!(r.extract(i as u32), ($vec::WIDTH - 1 - i) as <$vec as Packed>::Scalar);
+                    }
+                }
+            )*
+        }
+    }
+
+    test_reverse!((u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2,
+                   u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4),
+                  (reverse_u8x16, reverse_i8x16, reverse_u16x8, reverse_i16x8, reverse_u32x4, reverse_i32x4, reverse_f32x4, reverse_u64x2, reverse_i64x2, reverse_f64x2,
+                   reverse_u8x32, reverse_i8x32, reverse_u16x16, reverse_i16x16, reverse_u32x8, reverse_i32x8, reverse_f32x8, reverse_u64x4, reverse_i64x4, reverse_f64x4));
+}
@@ -10,7 +10,7 @@ use vecs::{u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x
 
 pub trait Round {
     /// Return a vector with all elements of `self` rounded to the nearest
-    /// integer.
+    /// integer, with ties rounded to the even integer.
     ///
     /// ```
     /// extern crate faster;
@@ -21,6 +21,7 @@ pub trait Round {
     /// assert_eq!(f32s(2.4).round(), f32s(2.0));
     /// assert_eq!(f32s(-2.7).round(), f32s(-3.0));
     /// assert_eq!(f32s(-2.4).round(), f32s(-2.0));
+    /// assert_eq!(f32s(2.5).round(), f32s(2.0));
     /// # }
     /// ```
     fn round(&self) -> Self;
@@ -74,41 +75,85 @@ pub trait Round {
     fn trunc(&self) -> Self;
 }
 
-rust_fallback_impl! {
-    impl Round for f32x4 where "sse4.1" {
-        round => _mm_round_ps(_MM_FROUND_TO_NEAREST_INT), [0, 1, 2, 3];
-        ceil => _mm_ceil_ps(), [0, 1, 2, 3];
-        floor => _mm_floor_ps(), [0, 1, 2, 3];
-        trunc => _mm_round_ps(_MM_FROUND_TRUNC), [0, 1, 2, 3];
-    }
-}
-
-rust_fallback_impl! {
-    impl Round for f64x2 where "sse4.1" {
-        round => _mm_round_pd(_MM_FROUND_TO_NEAREST_INT), [0, 1];
-        ceil => _mm_ceil_pd(), [0, 1];
-        floor => _mm_floor_pd(), [0, 1];
-        trunc => _mm_round_pd(_MM_FROUND_TRUNC), [0, 1];
+// The scalar `round` rounds ties away from zero, while the hardware rounds
+// them to even, so the fallbacks round ties to even as well.
+macro_rules! round_even {
+    ($name:ident, $el:ty) => {
+        #[inline(always)]
+        #[allow(dead_code)]
+        fn $name(x: $el) -> $el {
+            let r = x.round();
+            if (r - x).abs() == 0.5 { (x / 2.0).round() * 2.0 } else { r }
+        }
     }
 }
 
-rust_fallback_impl! {
-    impl Round for f32x8 where "avx" {
-        round => _mm256_round_ps(_MM_FROUND_TO_NEAREST_INT), [0, 1, 2, 3, 4, 5, 6, 7];
-        ceil => _mm256_ceil_ps(), [0, 1, 2, 3, 4, 5, 6, 7];
-        floor => _mm256_floor_ps(), [0, 1, 2, 3, 4, 5, 6, 7];
-        trunc => _mm256_round_ps(_MM_FROUND_TRUNC), [0, 1, 2, 3, 4, 5, 6, 7];
+round_even!(round_even_f32, f32);
+round_even!(round_even_f64, f64);
+
+macro_rules! impl_round {
+    ($vec:tt, $feat:tt, $round_even:ident, [$($n:expr),+],
+     $round:ident($($rarg:expr),*), $ceil:ident, $floor:ident, $trunc:ident($($targ:expr),*)) => {
+        impl Round for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn round(&self) -> Self {
+                unsafe { $round(*self, $($rarg),*) }
+            }
+
+            #[inline(always)]
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            fn round(&self) -> Self {
+                Self::new($($round_even(self.extract($n)),)*)
+            }
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn ceil(&self) -> Self {
+                unsafe { $ceil(*self) }
+            }
+
+            #[inline(always)]
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            fn ceil(&self) -> Self {
+                Self::new($(self.extract($n).ceil(),)*)
+            }
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn floor(&self) -> Self {
+                unsafe { $floor(*self) }
+            }
+
+            #[inline(always)]
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            fn floor(&self) -> Self {
+                Self::new($(self.extract($n).floor(),)*)
+            }
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn trunc(&self) -> Self {
+                unsafe { $trunc(*self, $($targ),*) }
+            }
+
+            #[inline(always)]
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            fn trunc(&self) -> Self {
+                Self::new($(self.extract($n).trunc(),)*)
+            }
+        }
     }
 }
 
-rust_fallback_impl! {
-    impl Round for f64x4 where "sse4.1" {
-        round => _mm256_round_pd(_MM_FROUND_TO_NEAREST_INT), [0, 1, 2, 3];
-        ceil => _mm256_ceil_pd(), [0, 1, 2, 3];
-        floor => _mm256_floor_pd(), [0, 1, 2, 3];
-        trunc => _mm256_round_pd(_MM_FROUND_TRUNC), [0, 1, 2, 3];
-    }
-}
+impl_round!(f32x4, "sse4.1", round_even_f32, [0, 1, 2, 3],
+            _mm_round_ps(_MM_FROUND_TO_NEAREST_INT), _mm_ceil_ps, _mm_floor_ps, _mm_round_ps(_MM_FROUND_TRUNC));
+impl_round!(f64x2, "sse4.1", round_even_f64, [0, 1],
+            _mm_round_pd(_MM_FROUND_TO_NEAREST_INT), _mm_ceil_pd, _mm_floor_pd, _mm_round_pd(_MM_FROUND_TRUNC));
+impl_round!(f32x8, "avx", round_even_f32, [0, 1, 2, 3, 4, 5, 6, 7],
+            _mm256_round_ps(_MM_FROUND_TO_NEAREST_INT), _mm256_ceil_ps, _mm256_floor_ps, _mm256_round_ps(_MM_FROUND_TRUNC));
+impl_round!(f64x4, "avx", round_even_f64, [0, 1, 2, 3],
+            _mm256_round_pd(_MM_FROUND_TO_NEAREST_INT), _mm256_ceil_pd, _mm256_floor_pd, _mm256_round_pd(_MM_FROUND_TRUNC));
 
 #[cfg(test)]
 mod tests {
@@ -136,6 +181,13 @@ mod tests {
         assert_eq!(f32s(9.8).round(), f32s(10.0));
         assert_eq!(f32s(9.1).round(), f32s(9.0));
         assert_eq!(f32s(9.0).round(), f32s(9.0));
+
+        // Ties round to even
+        assert_eq!(f32s(2.5).round(), f32s(2.0));
+        assert_eq!(f32s(3.5).round(), f32s(4.0));
+        assert_eq!(f32s(-2.5).round(), f32s(-2.0));
+        assert_eq!(f64s(0.5).round(), f64s(0.0));
+        assert_eq!(f64s(-1.5).round(), f64s(-2.0));
     }
 
     #[test]
@@ -17,7 +17,7 @@ pub trait SaturatingHSub {
     fn saturating_hsub(&self, other: Self) -> Self;
 }
 
-#[cfg(target_feature = "ssse3")]
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
 impl SaturatingHSub for i16x8 {
     #[inline(always)]
     fn saturating_hsub(&self, other: Self) -> Self {
@@ -26,7 +26,7 @@ impl SaturatingHSub for i16x8 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl SaturatingHSub for i16x16 {
     #[inline(always)]
     fn saturating_hsub(&self, other: Self) -> Self {
@@ -53,9 +53,9 @@ impl SaturatingHSub for i64x8 { hop!(saturating_hsub, i64::saturating_sub, 0, 1,
 impl SaturatingHSub for i32x4 { hop!(saturating_hsub, i32::saturating_sub, 0, 1, 2, 3); }
 impl SaturatingHSub for i32x8 { hop!(saturating_hsub, i32::saturating_sub, 0, 1, 2, 3, 4, 5, 6, 7); }
 impl SaturatingHSub for i32x16 { hop!(saturating_hsub, i32::saturating_sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 impl SaturatingHSub for i16x8 { hop!(saturating_hsub, i16::saturating_sub, 0, 1, 2, 3, 4, 5, 6, 7); }
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl SaturatingHSub for i16x16 { hop!(saturating_hsub, i16::saturating_sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
 impl SaturatingHSub for i16x32 { hop!(saturating_hsub, i16::saturating_sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31); }
 impl SaturatingHSub for i8x16 { hop!(saturating_hsub, i8::saturating_sub, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15); }
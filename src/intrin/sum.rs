@@ -33,7 +33,7 @@ pub trait UpcastSum :  {
 //     }
 // }
 
-#[cfg(target_feature = "sse2")]
+#[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
 impl Sum for i8x16 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -44,7 +44,7 @@ impl Sum for i8x16 {
     }
 }
 
-#[cfg(target_feature = "sse2")]
+#[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
 impl UpcastSum for i8x16 {
     #[inline(always)]
     fn sum_upcast(&self) -> i64 {
@@ -55,7 +55,7 @@ impl UpcastSum for i8x16 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl Sum for i8x32 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -68,7 +68,7 @@ impl Sum for i8x32 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl UpcastSum for i8x32 {
     #[inline(always)]
     fn sum_upcast(&self) -> i64 {
@@ -81,7 +81,7 @@ impl UpcastSum for i8x32 {
     }
 }
 
-#[cfg(target_feature = "sse2")]
+#[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
 impl Sum for u8x16 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -90,7 +90,7 @@ impl Sum for u8x16 {
     }
 }
 
-#[cfg(target_feature = "sse2")]
+#[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
 impl UpcastSum for u8x16 {
     #[inline(always)]
     fn sum_upcast(&self) -> i64 {
@@ -99,7 +99,7 @@ impl UpcastSum for u8x16 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl Sum for u8x32 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -108,7 +108,7 @@ impl Sum for u8x32 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl UpcastSum for u8x32 {
     #[inline(always)]
     fn sum_upcast(&self) -> i64 {
@@ -117,7 +117,7 @@ impl UpcastSum for u8x32 {
     }
 }
 
-#[cfg(target_feature = "ssse3")]
+#[cfg(all(target_feature = "ssse3", not(feature = "force-scalar")))]
 impl Sum for i16x8 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -130,7 +130,7 @@ impl Sum for i16x8 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl Sum for i16x16 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -143,7 +143,7 @@ impl Sum for i16x16 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl UpcastSum for i16x16 {
     #[inline(always)]
     fn sum_upcast(&self) -> i64 {
@@ -158,7 +158,7 @@ impl UpcastSum for i16x16 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl Sum for u16x16 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -173,7 +173,7 @@ impl Sum for u16x16 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl UpcastSum for u16x16 {
     #[inline(always)]
     fn sum_upcast(&self) -> i64 {
@@ -188,7 +188,7 @@ impl UpcastSum for u16x16 {
     }
 }
 
-#[cfg(target_feature = "avx2")]
+#[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
 impl Sum for i32x8 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -200,7 +200,7 @@ impl Sum for i32x8 {
     }
 }
 
-#[cfg(target_feature = "sse3")]
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
 impl Sum for f32x4 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -212,7 +212,7 @@ impl Sum for f32x4 {
     }
 }
 
-#[cfg(target_feature = "sse3")]
+#[cfg(all(target_feature = "sse3", not(feature = "force-scalar")))]
 impl Sum for f64x2 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -223,7 +223,7 @@ impl Sum for f64x2 {
     }
 }
 
-#[cfg(target_feature = "avx")]
+#[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
 impl Sum for f32x8 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -235,7 +235,7 @@ impl Sum for f32x8 {
     }
 }
 
-#[cfg(target_feature = "avx")]
+#[cfg(all(target_feature = "avx", not(feature = "force-scalar")))]
 impl Sum for f64x4 {
     #[inline(always)]
     fn sum(&self) -> Self::Scalar {
@@ -275,25 +275,25 @@ macro_rules! impl_packed_upcast_sum {
 impl_packed_sum!(u8x64, i8x64, u16x32, u16x8, i16x32, u32x16, u32x8, u32x4, i32x16, i32x4, f32x16, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8);
 impl_packed_upcast_sum!(u8x64, i8x64, u16x32, u16x8, i16x32, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2);
 
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl_packed_sum!(i8x32, u8x32, i16x16, u16x16, i32x8);
-#[cfg(not(target_feature = "avx2"))]
+#[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
 impl_packed_upcast_sum!(i8x32, u8x32, i16x16, u16x16);
 
-#[cfg(not(target_feature = "avx"))]
+#[cfg(any(not(target_feature = "avx"), feature = "force-scalar"))]
 impl_packed_sum!(f32x8, f64x4);
 
-#[cfg(not(target_feature = "sse3"))]
+#[cfg(any(not(target_feature = "sse3"), feature = "force-scalar"))]
 impl_packed_sum!(f32x4, f64x2);
 
-#[cfg(not(target_feature = "sse2"))]
+#[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
 impl_packed_sum!(i8x16, u8x16);
-#[cfg(not(target_feature = "sse2"))]
+#[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
 impl_packed_upcast_sum!(i8x16, u8x16);
 
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 impl_packed_sum!(i16x8);
-#[cfg(not(target_feature = "ssse3"))]
+#[cfg(any(not(target_feature = "ssse3"), feature = "force-scalar"))]
 impl_packed_upcast_sum!();
 
 mod tests {
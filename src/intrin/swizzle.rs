@@ -23,13 +23,13 @@ pub trait Swizzle {
 macro_rules! impl_packed_swizzle {
     ($vec:tt, $uvec:tt, $feat:expr, $mmfn:tt, ($($c:expr),*), ($($a:expr, $b:expr),*)) => {
         impl Swizzle for $vec {
-            #[cfg(not(target_feature = $feat))]
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
             #[inline(always)]
             fn flip(&self) -> Self {
                 $vec::new($(self.extract($b), self.extract($a)),*)
             }
 
-            #[cfg(target_feature = $feat)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
             #[inline(always)]
             fn flip(&self) -> Self {
                 unsafe {
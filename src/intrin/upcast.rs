@@ -11,8 +11,6 @@ use stdsimd::vendor::*;
 use stdsimd::simd::{__m256i, __m128i};
 use core_or_std::mem::transmute;
 
-// TODO: Upcast<i..> for u..
-
 pub trait Upcast<T> {
     /// Return two vectors containing elements of the same value, but different
     /// type. The first vector contains the first half of `self`, and the second
@@ -32,9 +30,51 @@ pub trait Upcast<T> {
     fn upcast(self) -> (T, T);
 }
 
+pub trait Upcast4<T> {
+    /// Return four vectors containing elements of the same value, but of a
+    /// type four times as large. The returned vectors contain the first,
+    /// second, third and fourth quarter of `self`, in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let (a, b, c, d): (u32s, u32s, u32s, u32s) = u8s(200).upcast4();
+    /// assert_eq!((a, b, c, d), (u32s(200), u32s(200), u32s(200), u32s(200)));
+    /// # }
+    /// ```
+    fn upcast4(self) -> (T, T, T, T);
+}
+
+pub trait UpcastSigned<T> {
+    /// Return two vectors containing the elements of this unsigned vector,
+    /// converted to a signed integer or float type twice as large. The first
+    /// vector contains the first half of `self`, and the second vector
+    /// contains the second half.
+    ///
+    /// This is separate from `Upcast` so that `upcast` on unsigned vectors
+    /// keeps inferring an unsigned result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let (a, b): (i16s, i16s) = u8s::halfs(1, 255).upcast_signed();
+    /// assert_eq!((a, b), (i16s(1), i16s(255)));
+    /// # }
+    /// ```
+    fn upcast_signed(self) -> (T, T);
+}
+
 impl Upcast<u16x8> for u8x16 {
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn upcast(self) -> (u16x8, u16x8) {
         // Shuffle the vector as i32s for better perf
         unsafe {
@@ -44,7 +84,7 @@ impl Upcast<u16x8> for u8x16 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn upcast(self) -> (u16x8, u16x8) {
         (u16x8::new(self.extract(0) as u16,
                     self.extract(1) as u16,
@@ -67,7 +107,7 @@ impl Upcast<u16x8> for u8x16 {
 
 impl Upcast<i16x8> for i8x16 {
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn upcast(self) -> (i16x8, i16x8) {
         // Shuffle the vector as i32s for better perf
         unsafe {
@@ -77,7 +117,7 @@ impl Upcast<i16x8> for i8x16 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn upcast(self) -> (i16x8, i16x8) {
         (i16x8::new(self.extract(0) as i16,
                     self.extract(1) as i16,
@@ -100,7 +140,7 @@ impl Upcast<i16x8> for i8x16 {
 
 impl Upcast<u32x4> for u16x8 {
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn upcast(self) -> (u32x4, u32x4) {
         unsafe {
             (_mm_cvtepu16_epi32(self).be_u32s(),
@@ -109,7 +149,7 @@ impl Upcast<u32x4> for u16x8 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn upcast(self) -> (u32x4, u32x4) {
         (u32x4::new(self.extract(0) as u32,
                     self.extract(1) as u32,
@@ -124,7 +164,7 @@ impl Upcast<u32x4> for u16x8 {
 
 impl Upcast<i32x4> for i16x8 {
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn upcast(self) -> (i32x4, i32x4) {
         unsafe {
             (_mm_cvtepi16_epi32(self),
@@ -133,7 +173,7 @@ impl Upcast<i32x4> for i16x8 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn upcast(self) -> (i32x4, i32x4) {
         (i32x4::new(self.extract(0) as i32,
                     self.extract(1) as i32,
@@ -148,7 +188,7 @@ impl Upcast<i32x4> for i16x8 {
 
 impl Upcast<u16x16> for u8x32 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (u16x16, u16x16) {
         unsafe {
             (_mm256_cvtepu8_epi16(transmute(_mm256_castsi256_si128(transmute(self)))).be_u16s(),
@@ -157,7 +197,7 @@ impl Upcast<u16x16> for u8x32 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (u16x16, u16x16) {
         (u16x16::new(self.extract(0) as u16,
                      self.extract(1) as u16,
@@ -196,7 +236,7 @@ impl Upcast<u16x16> for u8x32 {
 
 impl Upcast<i16x16> for i8x32 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (i16x16, i16x16) {
         unsafe {
             (_mm256_cvtepi8_epi16(
@@ -212,7 +252,7 @@ impl Upcast<i16x16> for i8x32 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (i16x16, i16x16) {
         (i16x16::new(self.extract(0) as i16,
                      self.extract(1) as i16,
@@ -251,7 +291,7 @@ impl Upcast<i16x16> for i8x32 {
 
 impl Upcast<u32x8> for u16x16 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (u32x8, u32x8) {
         unsafe {
             (_mm256_cvtepu16_epi32(transmute(_mm256_castsi256_si128(transmute(self)))).be_u32s(),
@@ -260,7 +300,7 @@ impl Upcast<u32x8> for u16x16 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (u32x8, u32x8) {
         (u32x8::new(self.extract(0) as u32,
                     self.extract(1) as u32,
@@ -284,7 +324,7 @@ impl Upcast<u32x8> for u16x16 {
 
 impl Upcast<i32x8> for i16x16 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (i32x8, i32x8) {
         unsafe {
             (_mm256_cvtepi16_epi32(
@@ -300,7 +340,7 @@ impl Upcast<i32x8> for i16x16 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (i32x8, i32x8) {
         (i32x8::new(self.extract(0) as i32,
                     self.extract(1) as i32,
@@ -323,14 +363,14 @@ impl Upcast<i32x8> for i16x16 {
 
 impl Upcast<f64x2> for f32x4 {
     #[inline(always)]
-    #[cfg(target_feature = "sse2")]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
     fn upcast(self) -> (f64x2, f64x2) {
         // Shuffle the vector as i32s for better perf
         unsafe { (_mm_cvtps_pd(self), _mm_cvtps_pd(_mm_shuffle_epi32(self.be_i32s(), 0x0E).be_f32s_unchecked())) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse2"))]
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
     fn upcast(self) -> (f64x2, f64x2) {
         (f64x2::new(self.extract(0) as f64,
                     self.extract(1) as f64),
@@ -341,13 +381,13 @@ impl Upcast<f64x2> for f32x4 {
 
 impl Upcast<f64x2> for i32x4 {
     #[inline(always)]
-    #[cfg(target_feature = "sse2")]
+    #[cfg(all(target_feature = "sse2", not(feature = "force-scalar")))]
     fn upcast(self) -> (f64x2, f64x2) {
         unsafe { (_mm_cvtepi32_pd(self), _mm_cvtepi32_pd(_mm_shuffle_epi32(self, 0x0E))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse2"))]
+    #[cfg(any(not(target_feature = "sse2"), feature = "force-scalar"))]
     fn upcast(self) -> (f64x2, f64x2) {
         (f64x2::new(self.extract(0) as f64,
                     self.extract(1) as f64),
@@ -358,7 +398,7 @@ impl Upcast<f64x2> for i32x4 {
 
 impl Upcast<i64x2> for i32x4 {
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn upcast(self) -> (i64x2, i64x2) {
         unsafe {
             (_mm_cvtepi32_epi64(self),
@@ -367,7 +407,7 @@ impl Upcast<i64x2> for i32x4 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn upcast(self) -> (i64x2, i64x2) {
         (i64x2::new(self.extract(0) as i64,
                     self.extract(1) as i64),
@@ -378,7 +418,7 @@ impl Upcast<i64x2> for i32x4 {
 
 impl Upcast<u64x2> for u32x4 {
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn upcast(self) -> (u64x2, u64x2) {
         unsafe {
             (_mm_cvtepu32_epi64(self).be_u64s(),
@@ -386,7 +426,7 @@ impl Upcast<u64x2> for u32x4 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn upcast(self) -> (u64x2, u64x2) {
         (u64x2::new(self.extract(0) as u64,
                     self.extract(1) as u64),
@@ -397,7 +437,7 @@ impl Upcast<u64x2> for u32x4 {
 
 impl Upcast<f64x4> for f32x8 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (f64x4, f64x4) {
         // Shuffle the vector as i32s for better perf
         unsafe {
@@ -414,7 +454,7 @@ impl Upcast<f64x4> for f32x8 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (f64x4, f64x4) {
         (f64x4::new(self.extract(0) as f64,
                     self.extract(1) as f64,
@@ -429,7 +469,7 @@ impl Upcast<f64x4> for f32x8 {
 
 impl Upcast<f64x4> for i32x8 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (f64x4, f64x4) {
         unsafe {
             (_mm256_cvtepi32_pd(
@@ -445,7 +485,7 @@ impl Upcast<f64x4> for i32x8 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (f64x4, f64x4) {
         (f64x4::new(self.extract(0) as f64,
                     self.extract(1) as f64,
@@ -460,7 +500,7 @@ impl Upcast<f64x4> for i32x8 {
 
 impl Upcast<i64x4> for i32x8 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (i64x4, i64x4) {
         unsafe {
             (_mm256_cvtepi32_epi64(
@@ -476,7 +516,7 @@ impl Upcast<i64x4> for i32x8 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (i64x4, i64x4) {
         (i64x4::new(self.extract(0) as i64,
                     self.extract(1) as i64,
@@ -491,7 +531,7 @@ impl Upcast<i64x4> for i32x8 {
 
 impl Upcast<u64x4> for u32x8 {
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn upcast(self) -> (u64x4, u64x4) {
         unsafe {
             (_mm256_cvtepu32_epi64(transmute(_mm256_castsi256_si128(transmute(self)))).be_u64s(),
@@ -499,7 +539,7 @@ impl Upcast<u64x4> for u32x8 {
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn upcast(self) -> (u64x4, u64x4) {
         (u64x4::new(self.extract(0) as u64,
                     self.extract(1) as u64,
@@ -600,6 +640,76 @@ impl Upcast<u64x8> for u32x16 {
     }
 }
 
+// Zero-extending an unsigned element gives the same bits whether the wide
+// element is signed or unsigned
+macro_rules! impl_unsigned_to_signed_upcast {
+    ($from:ty, $via:ty, $to:ty, $be:ident) => {
+        impl UpcastSigned<$to> for $from {
+            #[inline(always)]
+            fn upcast_signed(self) -> ($to, $to) {
+                let (a, b): ($via, $via) = self.upcast();
+                (a.$be(), b.$be())
+            }
+        }
+    }
+}
+
+impl_unsigned_to_signed_upcast!(u8x16, u16x8, i16x8, be_i16s);
+impl_unsigned_to_signed_upcast!(u8x32, u16x16, i16x16, be_i16s);
+impl_unsigned_to_signed_upcast!(u16x8, u32x4, i32x4, be_i32s);
+impl_unsigned_to_signed_upcast!(u16x16, u32x8, i32x8, be_i32s);
+impl_unsigned_to_signed_upcast!(u32x4, u64x2, i64x2, be_i64s);
+impl_unsigned_to_signed_upcast!(u32x8, u64x4, i64x4, be_i64s);
+impl_unsigned_to_signed_upcast!(u32x16, u64x8, i64x8, be_i64s);
+
+// x86 can only convert signed integers to floats. Placing a u32 in the low
+// bits of the mantissa of 2^52 gives 2^52 + n exactly, so subtracting 2^52
+// leaves n. These are never NaN, so the transmute is safe.
+macro_rules! impl_unsigned_to_float_upcast {
+    ($from:ty, $via:ty, $to:ty) => {
+        impl UpcastSigned<$to> for $from {
+            #[inline(always)]
+            fn upcast_signed(self) -> ($to, $to) {
+                let (a, b): ($via, $via) = self.upcast();
+                let magic = <$via>::splat(0x4330000000000000);
+                let bias = <$to>::splat(4503599627370496.0);
+                unsafe {
+                    ((a | magic).be_f64s_unchecked() - bias, (b | magic).be_f64s_unchecked() - bias)
+                }
+            }
+        }
+    }
+}
+
+impl_unsigned_to_float_upcast!(u32x4, u64x2, f64x2);
+impl_unsigned_to_float_upcast!(u32x8, u64x4, f64x4);
+impl_unsigned_to_float_upcast!(u32x16, u64x8, f64x8);
+
+macro_rules! impl_upcast4 {
+    ($from:ty => $mid:ty => $to:ty) => {
+        impl Upcast4<$to> for $from {
+            #[inline(always)]
+            fn upcast4(self) -> ($to, $to, $to, $to) {
+                let (a, b): ($mid, $mid) = self.upcast();
+                let (c, d): ($to, $to) = a.upcast();
+                let (e, f): ($to, $to) = b.upcast();
+                (c, d, e, f)
+            }
+        }
+    }
+}
+
+impl_upcast4!(u8x16 => u16x8 => u32x4);
+impl_upcast4!(i8x16 => i16x8 => i32x4);
+impl_upcast4!(u16x8 => u32x4 => u64x2);
+impl_upcast4!(i16x8 => i32x4 => i64x2);
+impl_upcast4!(i16x8 => i32x4 => f64x2);
+impl_upcast4!(u8x32 => u16x16 => u32x8);
+impl_upcast4!(i8x32 => i16x16 => i32x8);
+impl_upcast4!(u16x16 => u32x8 => u64x4);
+impl_upcast4!(i16x16 => i32x8 => i64x4);
+impl_upcast4!(i16x16 => i32x8 => f64x4);
+
 #[cfg(test)]
 mod tests {
     use vecs::*;
@@ -617,6 +727,11 @@ mod tests {
         assert_eq!(u8s::interleave(1, 2).upcast().1, u16s::interleave(1, 2));
     }
 
+    #[test]
+    fn upcast_u8s_i16s() {
+        assert_eq!(u8s::halfs(1, 255).upcast_signed(), (i16s(1), i16s(255)));
+    }
+
     #[test]
     fn upcast_i16s() {
         assert_eq!(i16s::interleave(1, 2).upcast().0, i32s::interleave(1, 2));
@@ -629,6 +744,11 @@ mod tests {
         assert_eq!(u16s::interleave(1, 2).upcast().1, u32s::interleave(1, 2));
     }
 
+    #[test]
+    fn upcast_u16s_i32s() {
+        assert_eq!(u16s::halfs(1, 0xFFFF).upcast_signed(), (i32s(1), i32s(0xFFFF)));
+    }
+
     #[test]
     fn upcast_i32s_i64s() {
         // TODO: Fix ugliness
@@ -649,6 +769,29 @@ mod tests {
         assert_eq!(u32s::interleave(1, 2).upcast().1, u64s::interleave(1, 2));
     }
 
+    #[test]
+    fn upcast_u32s_i64s() {
+        assert_eq!(UpcastSigned::<i64s>::upcast_signed(u32s::halfs(1, 0xFFFFFFFF)), (i64s(1), i64s(0xFFFFFFFF)));
+    }
+
+    #[test]
+    fn upcast_u32s_f64s() {
+        assert_eq!(UpcastSigned::<f64s>::upcast_signed(u32s::halfs(0, 0xFFFFFFFF)), (f64s(0.0), f64s(4294967295.0)));
+        assert_eq!(UpcastSigned::<f64s>::upcast_signed(u32s::halfs(0x80000000, 7)), (f64s(2147483648.0), f64s(7.0)));
+    }
+
+    #[test]
+    fn upcast4_u8s() {
+        let v = u8s::from_fn(|i| (i * 7) as u8);
+        let (a, b, c, d): (u32s, u32s, u32s, u32s) = v.upcast4();
+        let q = u32s::WIDTH;
+        assert_eq!((a, b, c, d), (u32s::from_fn(|i| (i * 7) as u32),
+                                  u32s::from_fn(|i| ((i + q) * 7) as u32),
+                                  u32s::from_fn(|i| ((i + 2 * q) * 7) as u32),
+                                  u32s::from_fn(|i| ((i + 3 * q) * 7) as u32)));
+        assert_eq!(Upcast4::<i64s>::upcast4(i16s(-3)).0, i64s(-3));
+    }
+
     #[test]
     fn upcast_f32s() {
         assert_eq!(f32s::interleave(1.0, 2.0).upcast(), (f64s::interleave(1.0, 2.0), f64s::interleave(1.0, 2.0)));
@@ -0,0 +1,166 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use vecs::*;
+use intrin::upcast::*;
+use intrin::cast::*;
+
+pub trait Widen<T> : Packed where T : Packed {
+    /// Upcast `self` into `Self::WIDTH / T::WIDTH` vectors of type `T`, and
+    /// store them in order at the beginning of `out`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mut out = [f32s(0.0); 4];
+    /// u8s(3).widen(&mut out);
+    /// assert_eq!(out, [f32s(3.0); 4]);
+    /// # }
+    /// ```
+    fn widen(self, out: &mut [T]);
+}
+
+macro_rules! impl_widen {
+    ($from:ty => $to:ty) => {
+        impl Widen<$to> for $from {
+            #[inline(always)]
+            fn widen(self, out: &mut [$to]) {
+                let (a, b): ($to, $to) = self.upcast();
+                out[0] = a;
+                out[1] = b;
+            }
+        }
+    };
+    ($from:ty => $mid:ty => $to:ty) => {
+        impl Widen<$to> for $from {
+            #[inline(always)]
+            fn widen(self, out: &mut [$to]) {
+                let (a, b, c, d): ($to, $to, $to, $to) = self.upcast4();
+                out[0] = a;
+                out[1] = b;
+                out[2] = c;
+                out[3] = d;
+            }
+        }
+    };
+    ($from:ty => $mid:ty => $mid2:ty => $to:ty) => {
+        impl Widen<$to> for $from {
+            #[inline(always)]
+            fn widen(self, out: &mut [$to]) {
+                let mut mid = [<$mid2>::splat(0 as _); 4];
+                Widen::<$mid2>::widen(self, &mut mid);
+                for i in 0..4 {
+                    let (a, b): ($to, $to) = mid[i].upcast();
+                    out[i * 2] = a;
+                    out[i * 2 + 1] = b;
+                }
+            }
+        }
+    };
+    ($from:ty => $to:ty; $cast:ty, $as:ident) => {
+        impl Widen<$cast> for $from {
+            #[inline(always)]
+            fn widen(self, out: &mut [$cast]) {
+                let (a, b): ($to, $to) = self.upcast();
+                out[0] = a.$as();
+                out[1] = b.$as();
+            }
+        }
+    };
+    ($from:ty => $mid:ty => $to:ty; $cast:ty, $as:ident) => {
+        impl Widen<$cast> for $from {
+            #[inline(always)]
+            fn widen(self, out: &mut [$cast]) {
+                let mut mid = [<$to>::splat(0 as _); 4];
+                Widen::<$to>::widen(self, &mut mid);
+                for i in 0..4 {
+                    out[i] = mid[i].$as();
+                }
+            }
+        }
+    };
+}
+
+impl_widen!(u8x16 => u16x8);
+impl_widen!(u8x16 => u16x8 => u32x4);
+impl_widen!(u8x16 => u16x8 => u32x4 => u64x2);
+impl_widen!(u8x16 => u16x8 => u32x4; f32x4, as_f32s);
+impl_widen!(i8x16 => i16x8);
+impl_widen!(i8x16 => i16x8 => i32x4);
+impl_widen!(i8x16 => i16x8 => i32x4 => i64x2);
+impl_widen!(i8x16 => i16x8 => i32x4 => f64x2);
+impl_widen!(i8x16 => i16x8 => i32x4; f32x4, as_f32s);
+impl_widen!(u16x8 => u32x4);
+impl_widen!(u16x8 => u32x4 => u64x2);
+impl_widen!(u16x8 => u32x4; f32x4, as_f32s);
+impl_widen!(i16x8 => i32x4);
+impl_widen!(i16x8 => i32x4 => i64x2);
+impl_widen!(i16x8 => i32x4 => f64x2);
+impl_widen!(i16x8 => i32x4; f32x4, as_f32s);
+impl_widen!(u32x4 => u64x2);
+impl_widen!(i32x4 => i64x2);
+impl_widen!(i32x4 => f64x2);
+impl_widen!(f32x4 => f64x2);
+
+impl_widen!(u8x32 => u16x16);
+impl_widen!(u8x32 => u16x16 => u32x8);
+impl_widen!(u8x32 => u16x16 => u32x8 => u64x4);
+impl_widen!(u8x32 => u16x16 => u32x8; f32x8, as_f32s);
+impl_widen!(i8x32 => i16x16);
+impl_widen!(i8x32 => i16x16 => i32x8);
+impl_widen!(i8x32 => i16x16 => i32x8 => i64x4);
+impl_widen!(i8x32 => i16x16 => i32x8 => f64x4);
+impl_widen!(i8x32 => i16x16 => i32x8; f32x8, as_f32s);
+impl_widen!(u16x16 => u32x8);
+impl_widen!(u16x16 => u32x8 => u64x4);
+impl_widen!(u16x16 => u32x8; f32x8, as_f32s);
+impl_widen!(i16x16 => i32x8);
+impl_widen!(i16x16 => i32x8 => i64x4);
+impl_widen!(i16x16 => i32x8 => f64x4);
+impl_widen!(i16x16 => i32x8; f32x8, as_f32s);
+impl_widen!(u32x8 => u64x4);
+impl_widen!(i32x8 => i64x4);
+impl_widen!(i32x8 => f64x4);
+impl_widen!(f32x8 => f64x4);
+
+impl_widen!(u32x16 => u64x8);
+impl_widen!(i32x16 => i64x8);
+impl_widen!(i32x16 => f64x8);
+impl_widen!(f32x16 => f64x8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_widen {
+        (($($from:tt),*), ($($to:tt),*), ($($fn:ident),*)) => {
+            $(
+                #[test]
+                fn $fn() {
+                    let mut v = $from::splat(0 as <$from as Packed>::Scalar);
+                    for i in 0..$from::WIDTH {
+                        v = v.replace(i as u32, i as <$from as Packed>::Scalar);
+                    }
+                    let mut out = [$to::splat(0 as <$to as Packed>::Scalar); 8];
+                    v.widen(&mut out);
+                    for i in 0..$from::WIDTH {
+                        assert_eq!(out[i / $to::WIDTH].extract((i % $to::WIDTH) as u32),
+                                   v.extract(i as u32) as <$to as Packed>::Scalar);
+                    }
+                }
+            )*
+        }
+    }
+
+    test_widen!((u8x16, u8x16, u8x16, i8x16, i8x16, u16x8, i16x8, i16x8, i32x4, f32x4, u8x32, i8x32, u16x16, i16x16),
+                (u16x8, u32x4, f32x4, i64x2, f64x2, f32x4, i32x4, f64x2, i64x2, f64x2, u64x4, f32x8, u32x8, f64x4),
+                (widen_u8x16_u16x8, widen_u8x16_u32x4, widen_u8x16_f32x4, widen_i8x16_i64x2, widen_i8x16_f64x2, widen_u16x8_f32x4, widen_i16x8_i32x4, widen_i16x8_f64x2, widen_i32x4_i64x2, widen_f32x4_f64x2, widen_u8x32_u64x4, widen_i8x32_f32x8, widen_u16x16_u32x8, widen_i16x16_f64x4));
+}
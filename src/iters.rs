@@ -5,8 +5,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use vecs::{Packable, Packed};
+use vecs::*;
+use intrin::{Gather, Reverse};
+use zip::{SIMDZippedObject, SIMDZippedIterable, SIMDZippedIterator};
 use core_or_std::slice::from_raw_parts;
+use core_or_std::cmp;
+use core_or_std::marker::PhantomData;
+use core_or_std::ops::{Deref, DerefMut};
 
 pub trait SIMDObject : Sized {
     type Scalar : Packable;
@@ -85,6 +90,248 @@ pub trait SIMDIterator : SIMDIterable {
         }
     }
 
+    #[inline(always)]
+    /// Return an iterator which calls `func` on vectors of elements, where
+    /// `func` returns a tuple of narrower vectors which together hold as many
+    /// elements as its argument. The vectors of each tuple are yielded in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let widened = [1u8, 2, 3, 4, 5].simd_iter(u8s(0))
+    ///     .simd_map_expand(|v| -> (u16s, u16s) { v.upcast() })
+    ///     .scalar_collect();
+    /// assert_eq!(widened, vec![1u16, 2, 3, 4, 5]);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors returned by `func` don't hold as many elements
+    /// as the vectors of this iterator.
+    fn simd_map_expand<A, E, F>(self, func: F) -> SIMDMapExpand<Self, F, A>
+        where Self : SIMDArray, F : FnMut(Self::Vector) -> E, E : ExpandedVectors<Vector = A>, A : Packed {
+        assert!(A::WIDTH * E::COUNT == Self::Vector::WIDTH,
+                "The vectors returned by simd_map_expand must hold as many elements as the input vectors.");
+        let pos = self.scalar_pos();
+        SIMDMapExpand {
+            iter: self,
+            func: func,
+            buf: [A::default(); 8],
+            start: 0,
+            end: 0,
+            pos: pos,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator which calls `func` on pairs of vectors of elements,
+    /// where `func` returns a vector which holds as many elements as both of
+    /// its arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let narrowed = [1i32, -2, 3, 100000, -100000].simd_iter(i32s(0))
+    ///     .simd_map_contract(|a, b| -> i16s { a.saturating_downcast(b) })
+    ///     .scalar_collect();
+    /// assert_eq!(narrowed, vec![1i16, -2, 3, 32767, -32768]);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector returned by `func` doesn't hold as many elements
+    /// as both of its arguments.
+    fn simd_map_contract<A, F>(self, func: F) -> SIMDMapContract<Self, F>
+        where Self : SIMDArray, F : FnMut(Self::Vector, Self::Vector) -> A, A : Packed {
+        assert!(A::WIDTH == Self::Vector::WIDTH * 2,
+                "The vectors returned by simd_map_contract must hold as many elements as two input vectors.");
+        let pos = self.scalar_pos();
+        SIMDMapContract {
+            iter: self,
+            func: func,
+            pos: pos,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator which yields the elements of this iterator in
+    /// reverse order. Vectors are taken from the back of this iterator, and
+    /// their elements are reversed. The partially filled vector is yielded
+    /// by `end`, once no whole vectors remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let reversed = [1u32, 2, 3, 4, 5, 6, 7].simd_iter(u32s(0))
+    ///     .simd_rev()
+    ///     .scalar_collect();
+    /// assert_eq!(reversed, vec![7, 6, 5, 4, 3, 2, 1]);
+    /// # }
+    /// ```
+    fn simd_rev(self) -> SIMDRev<Self>
+        where Self : SIMDDoubleEndedIterator, Self::Vector : Reverse {
+        SIMDRev {
+            iter: self,
+        }
+    }
+
+    #[inline(always)]
+    /// Advance this iterator by `n` scalars, and return it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let skipped = [1u8, 2, 3, 4, 5].simd_iter(u8s(0))
+    ///     .simd_skip(2)
+    ///     .scalar_collect();
+    /// assert_eq!(skipped, vec![3, 4, 5]);
+    /// # }
+    /// ```
+    fn simd_skip(mut self, n: usize) -> Self {
+        for _ in 0..(n / self.width()) {
+            self.vector_inc();
+        }
+        for _ in 0..(n % self.width()) {
+            self.scalar_inc();
+        }
+        self
+    }
+
+    #[inline(always)]
+    /// Return an iterator which yields only the next `n` scalars of this
+    /// iterator. The partially filled vector holding the last of them is
+    /// yielded by `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let taken = [1u8; 100].simd_iter(u8s(0))
+    ///     .simd_take(37)
+    ///     .scalar_collect();
+    /// assert_eq!(taken, vec![1u8; 37]);
+    /// # }
+    /// ```
+    fn simd_take(self, n: usize) -> SIMDTake<Self> {
+        SIMDTake {
+            iter: self,
+            remaining: n,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator which yields every `step`th vector of this
+    /// iterator, beginning with the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = (0..u32s::WIDTH * 4).map(|i| i as u32).collect::<Vec<u32>>();
+    /// let firsts = data.simd_iter(u32s(0))
+    ///     .simd_step_by(2)
+    ///     .map(|v| v.extract(0))
+    ///     .collect::<Vec<u32>>();
+    /// assert_eq!(firsts, vec![0, u32s::WIDTH as u32 * 2]);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    fn simd_step_by(self, step: usize) -> SIMDStepBy<Self> {
+        assert!(step != 0, "simd_step_by requires a step greater than zero.");
+        SIMDStepBy {
+            iter: self,
+            step: step,
+            first: true,
+            done: false,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator which yields the elements of this iterator, followed
+    /// by the elements of `other`. If this iterator ends with a partially
+    /// filled vector, it is filled with the first elements of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let ring = [5u16, 6, 7, 1, 2, 3, 4];
+    /// let (tail, head) = ring.split_at(3);
+    /// let ordered = head.simd_iter(u16s(0))
+    ///     .simd_chain(tail.simd_iter(u16s(0)))
+    ///     .scalar_collect();
+    /// assert_eq!(ordered, vec![1, 2, 3, 4, 5, 6, 7]);
+    /// # }
+    /// ```
+    fn simd_chain<B>(self, other: B) -> SIMDChain<Self, B>
+        where Self : SIMDArray, B : SIMDIterator<Vector = Self::Vector> + SIMDArray {
+        let b_start = other.scalar_pos();
+        SIMDChain {
+            a: self,
+            b: other,
+            b_start: b_start,
+        }
+    }
+
+    #[inline(always)]
+    /// Return a zipped iterator which yields each vector of this iterator
+    /// alongside a vector of the scalar positions of its elements. The
+    /// positions are `u32s` for iterators of 32-bit elements, and `u64s` for
+    /// iterators of 64-bit elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [3.0f32, -1.0, 4.0, -1.0, 5.0, -9.0, 2.0, -6.0, 5.0];
+    /// let found = data.simd_iter(f32s(0.0))
+    ///     .simd_enumerate()
+    ///     .simd_map(|(i, v)| v.ne_mask(f32s(-1.0)) & i)
+    ///     .scalar_collect();
+    /// assert_eq!(found, vec![0, 0, 2, 0, 4, 5, 6, 7, 8]);
+    /// # }
+    /// ```
+    fn simd_enumerate(self) -> SIMDEnumerate<Self>
+        where Self::Vector : Enumerable {
+        SIMDEnumerate {
+            iter: self,
+        }
+    }
+
     #[inline(always)]
     /// Pack and run `func` over the iterator, returning no value and not
     /// modifying the iterator.
@@ -162,6 +409,12 @@ pub trait SIMDIterator : SIMDIterable {
     }
 }
 
+/// A SIMD iterator which can also be consumed from the back.
+pub trait SIMDDoubleEndedIterator : SIMDIterator + DoubleEndedIterator {
+    /// Remove one scalar from the back of the iterator.
+    fn scalar_inc_back(&mut self);
+}
+
 pub trait SIMDIteratorMut : SIMDIterator {
     #[inline(always)]
     /// Pack and run `func` over the iterator, modifying each element in-place.
@@ -175,6 +428,17 @@ pub trait SIMDArray : SIMDObject {
     fn load_scalar(&self, offset: usize) -> Self::Scalar;
     unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar;
 
+    /// Load a vector whose nth element is the scalar at `offset + stride * n`,
+    /// without checking the bounds of the array.
+    #[inline(always)]
+    unsafe fn load_strided_unchecked(&self, offset: usize, stride: usize) -> Self::Vector {
+        let mut ret = Self::Vector::default();
+        for i in 0..Self::Vector::WIDTH {
+            ret = ret.replace_unchecked(i, self.load_scalar_unchecked(offset + stride * i));
+        }
+        ret
+    }
+
     /// Return the length of this iterator, measured in scalars.
     fn scalar_len(&self) -> usize;
 
@@ -194,6 +458,7 @@ pub trait SIMDArrayMut : SIMDArray {
 #[derive(Clone, Debug)]
 pub struct SIMDIter<A : SIMDArray> {
     pub position: usize,
+    pub end_position: usize,
     pub data: A,
     pub default: A::Vector,
 }
@@ -205,7 +470,130 @@ pub struct SIMDMap<I, F> where I : SIMDIterable {
     pub func: F,
 }
 
-impl<'a, S, V> SIMDArrayMut for &'a mut [S] where S : 'a + Packable<Vector = V>, V : Packed<Scalar = S> {
+/// A lazy iterator which yields the vectors of a double-ended iterator in
+/// reverse order, with their elements reversed.
+#[derive(Debug)]
+pub struct SIMDRev<I> where I : SIMDIterable {
+    iter: I,
+}
+
+/// A lazy mapping iterator which maps each vector of a stream to several
+/// narrower vectors.
+#[derive(Debug)]
+pub struct SIMDMapExpand<I, F, A> where I : SIMDIterable {
+    iter: I,
+    func: F,
+    buf: [A; 8],
+    start: usize,
+    end: usize,
+    pos: usize,
+}
+
+/// A lazy mapping iterator which maps each pair of vectors of a stream to a
+/// wider vector.
+#[derive(Debug)]
+pub struct SIMDMapContract<I, F> where I : SIMDIterable {
+    iter: I,
+    func: F,
+    pos: usize,
+}
+
+/// A lazy iterator which yields a limited amount of scalars from its inner
+/// iterator.
+#[derive(Debug)]
+pub struct SIMDTake<I> where I : SIMDIterable {
+    iter: I,
+    remaining: usize,
+}
+
+/// A lazy iterator which yields every `step`th vector of its inner iterator.
+#[derive(Debug)]
+pub struct SIMDStepBy<I> where I : SIMDIterable {
+    iter: I,
+    step: usize,
+    first: bool,
+    done: bool,
+}
+
+/// A lazy iterator which yields the elements of one iterator, followed by the
+/// elements of another.
+#[derive(Debug)]
+pub struct SIMDChain<A, B> where A : SIMDIterable {
+    a: A,
+    b: B,
+    b_start: usize,
+}
+
+/// A lazy iterator which yields the vectors of its inner iterator alongside
+/// the scalar positions of their elements.
+#[derive(Debug)]
+pub struct SIMDEnumerate<I> where I : SIMDIterable {
+    iter: I,
+}
+
+/// A vector whose elements may be enumerated by a vector of unsigned integers
+/// of the same width.
+pub trait Enumerable : Packed {
+    type Indices : Pattern;
+
+    /// Return a vector of indices whose first element is `start`, counting up
+    /// by one. Indices which don't fit in `Self::Indices` wrap around.
+    fn indices(start: usize) -> Self::Indices;
+}
+
+macro_rules! impl_enumerable {
+    ($idx:tt, $el:ty, ($($vec:tt),*)) => {
+        $(
+            impl Enumerable for $vec {
+                type Indices = $idx;
+
+                #[inline(always)]
+                fn indices(start: usize) -> Self::Indices {
+                    $idx::ramp(start as $el, 1)
+                }
+            }
+        )*
+    }
+}
+
+impl_enumerable!(u32x4, u32, (u32x4, i32x4, f32x4));
+impl_enumerable!(u32x8, u32, (u32x8, i32x8, f32x8));
+impl_enumerable!(u32x16, u32, (u32x16, i32x16, f32x16));
+impl_enumerable!(u64x2, u64, (u64x2, i64x2, f64x2));
+impl_enumerable!(u64x4, u64, (u64x4, i64x4, f64x4));
+impl_enumerable!(u64x8, u64, (u64x8, i64x8, f64x8));
+
+/// A tuple of vectors of the same type, which may be returned by the function
+/// of a `SIMDMapExpand`.
+pub trait ExpandedVectors : Copy {
+    type Vector : Packed;
+
+    /// The amount of vectors in this tuple.
+    const COUNT: usize;
+
+    /// Store the vectors of this tuple in order at the beginning of `out`.
+    fn store_vectors(self, out: &mut [Self::Vector]);
+}
+
+macro_rules! impl_expanded_vectors {
+    ($count:expr, ($($v:ident),*), ($($n:tt),*)) => {
+        impl<V> ExpandedVectors for ($($v),*) where V : Packed {
+            type Vector = V;
+            const COUNT: usize = $count;
+
+            #[inline(always)]
+            fn store_vectors(self, out: &mut [V]) {
+                $(out[$n] = self.$n;)*
+            }
+        }
+    }
+}
+
+impl_expanded_vectors!(2, (V, V), (0, 1));
+impl_expanded_vectors!(4, (V, V, V, V), (0, 1, 2, 3));
+impl_expanded_vectors!(8, (V, V, V, V, V, V, V, V), (0, 1, 2, 3, 4, 5, 6, 7));
+
+impl<'a, S, V> SIMDArrayMut for &'a mut [S] where S : 'a + Packable<Vector = V>, V : Packed<Scalar = S> + Gather {
     #[inline(always)]
     fn store(&mut self, value: Self::Vector, offset: usize) {
         value.store(self, offset)
@@ -228,6 +616,94 @@ impl<'a, S, V> SIMDArrayMut for &'a mut [S] where S : 'a + Packable<Vector = V>,
     }
 }
 
+/// A contiguous collection of scalars which is packed into vectors of type
+/// `V`, rather than the default vector type of its scalars.
+#[derive(Debug)]
+pub struct AsPacked<A, V> {
+    pub data: A,
+    vector: PhantomData<V>,
+}
+
+impl<A, V> AsPacked<A, V> {
+    #[inline(always)]
+    pub fn new(data: A) -> Self {
+        AsPacked {
+            data: data,
+            vector: PhantomData,
+        }
+    }
+}
+
+impl<A, S, V> SIMDObject for AsPacked<A, V>
+    where A : Deref<Target = [S]>, S : Packable, V : Packed<Scalar = S> + Gather {
+    type Vector = V;
+    type Scalar = S;
+}
+
+impl<A, S, V> SIMDArray for AsPacked<A, V>
+    where A : Deref<Target = [S]>, S : Packable, V : Packed<Scalar = S> + Gather {
+    #[inline(always)]
+    fn load(&self, offset: usize) -> Self::Vector {
+        V::load(&self.data, offset)
+    }
+
+    #[inline(always)]
+    unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector {
+        debug_assert!(self.data[offset..].len() >= V::WIDTH);
+        V::load_unchecked(&self.data, offset)
+    }
+
+    #[inline(always)]
+    fn load_scalar(&self, offset: usize) -> Self::Scalar {
+        self.data[offset]
+    }
+
+    #[inline(always)]
+    unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar {
+        debug_assert!(offset < self.data.len());
+        *self.data.get_unchecked(offset)
+    }
+
+    #[inline(always)]
+    unsafe fn load_strided_unchecked(&self, offset: usize, stride: usize) -> Self::Vector {
+        V::gather_unchecked(&self.data, offset, stride)
+    }
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline(always)]
+    fn vector_len(&self) -> usize {
+        self.data.len() / V::WIDTH
+    }
+}
+
+impl<A, S, V> SIMDArrayMut for AsPacked<A, V>
+    where A : DerefMut<Target = [S]>, S : Packable, V : Packed<Scalar = S> + Gather {
+    #[inline(always)]
+    fn store(&mut self, value: Self::Vector, offset: usize) {
+        value.store(&mut self.data, offset)
+    }
+
+    #[inline(always)]
+    unsafe fn store_unchecked(&mut self, value: Self::Vector, offset: usize) {
+        value.store_unchecked(&mut self.data, offset)
+    }
+
+    #[inline(always)]
+    fn store_scalar(&mut self, value: Self::Scalar, offset: usize) {
+        self.data[offset] = value;
+    }
+
+    #[inline(always)]
+    unsafe fn store_scalar_unchecked(&mut self, value: Self::Scalar, offset: usize) {
+        debug_assert!(offset < self.data.len());
+        *self.data.get_unchecked_mut(offset) = value;
+    }
+}
+
 /// A slice-backed iterator which yields scalar elements using the Iterator API.
 #[derive(Debug)]
 pub struct Unpacked<T> where T : SIMDIterable {
@@ -330,6 +806,11 @@ macro_rules! impl_iter {
                 *self.get_unchecked(offset)
             }
 
+            #[inline(always)]
+            unsafe fn load_strided_unchecked(&self, offset: usize, stride: usize) -> Self::Vector {
+                <Self::Vector as Gather>::gather_unchecked(&self, offset, stride)
+            }
+
             #[inline(always)]
             fn scalar_len(&self) -> usize {
                 self.len()
@@ -344,9 +825,9 @@ macro_rules! impl_iter {
 }
 
 #[cfg(not(feature = "no-std"))]
-impl_iter!(Vec<S>, ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
-impl_iter!(&'a [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
-impl_iter!(&'a mut [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
+impl_iter!(Vec<S>, ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S> + Gather);
+impl_iter!(&'a [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S> + Gather);
+impl_iter!(&'a mut [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S> + Gather);
 
 impl<A> SIMDObject for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
     type Vector = A::Vector;
@@ -356,7 +837,7 @@ impl<A> SIMDObject for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::S
 impl<A> ExactSizeIterator for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
     #[inline(always)]
     fn len(&self) -> usize {
-        self.data.scalar_len() / self.width()
+        self.scalar_len() / self.width()
     }
 }
 
@@ -375,6 +856,27 @@ impl<A> Iterator for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Sca
     }
 }
 
+impl<A> DoubleEndedIterator for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position + self.width() <= self.end_position {
+            self.end_position -= self.width();
+            Some(unsafe { self.load_unchecked(self.end_position) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<A> SIMDDoubleEndedIterator for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
+    #[inline(always)]
+    fn scalar_inc_back(&mut self) {
+        if self.end_position > self.position {
+            self.end_position -= 1;
+        }
+    }
+}
+
 impl<A> SIMDArray for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
     #[inline(always)]
     fn load(&self, offset: usize) -> Self::Vector {
@@ -396,14 +898,20 @@ impl<A> SIMDArray for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Sc
         self.data.load_scalar_unchecked(offset)
     }
 
+    #[inline(always)]
+    unsafe fn load_strided_unchecked(&self, offset: usize, stride: usize) -> Self::Vector {
+        self.data.load_strided_unchecked(offset, stride)
+    }
+
     #[inline(always)]
     fn scalar_len(&self) -> usize {
-        self.data.scalar_len()
+        // Vectors taken from the back of the iterator are no longer part of it
+        self.end_position
     }
 
     #[inline(always)]
-   fn vector_len(&self) -> usize {
-        self.data.vector_len()
+    fn vector_len(&self) -> usize {
+        self.end_position / self.width()
     }
 }
 
@@ -452,7 +960,7 @@ impl<T, S, V> SIMDIterator for T where T : SIMDIterable + SIMDArray<Scalar = S,
             } else {
                 for i in self.scalar_pos()..self.scalar_len() {
                     unsafe {
-                        ret = ret.replace_unchecked(i + empty_amt, self.load_scalar_unchecked(i));
+                        ret = ret.replace_unchecked(i - self.scalar_pos() + empty_amt, self.load_scalar_unchecked(i));
                     }
                 }
             }
@@ -469,10 +977,12 @@ impl<T> SIMDIteratorMut for SIMDIter<T> where T : SIMDArrayMut {
     fn simd_for_each<F>(&mut self, mut func: F)
         where F : FnMut(&mut Self::Vector) -> () {
         let mut lastvec = Self::Vector::default();
+        let mut stored = false;
 
         while let Some(mut v) = self.next() {
             func(&mut v);
             lastvec = v;
+            stored = true;
             let offset = self.scalar_pos() - self.width();
             unsafe { self.data.store_unchecked(v, offset); }
         }
@@ -480,14 +990,14 @@ impl<T> SIMDIteratorMut for SIMDIter<T> where T : SIMDArrayMut {
         if let Some((mut p, n)) = self.end() {
             func(&mut p);
             let width = self.width();
-            if self.width() < self.scalar_len() {
+            if stored {
                 // We stored a vector in this buffer; overwrite the unused elements
                 unsafe {
                     self.data.store_unchecked(p, offset - n);
                     self.data.store_unchecked(lastvec, offset - width);
                 }
             } else {
-                // The buffer won't fit one vector; store elementwise
+                // No vector was stored before the partial one; store elementwise
                 for i in 0..(width - n) {
                     unsafe { self.data.store_scalar_unchecked(p.extract_unchecked(i + n), offset + i); }
                 }
@@ -538,26 +1048,625 @@ impl<A, B, I, F> Iterator for SIMDMap<I, F>
     }
 }
 
-impl<I, F> ExactSizeIterator for SIMDMap<I, F> where Self : Iterator, I : SIMDIterable {
+impl<A, B, I, F> DoubleEndedIterator for SIMDMap<I, F>
+    where I : SIMDIterable + DoubleEndedIterator, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
     #[inline(always)]
-    fn len(&self) -> usize {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(&mut self.func)
+    }
+}
+
+impl<A, B, I, F> SIMDDoubleEndedIterator for SIMDMap<I, F>
+    where I : SIMDDoubleEndedIterator, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
+    #[inline(always)]
+    fn scalar_inc_back(&mut self) {
+        self.iter.scalar_inc_back()
+    }
+}
+
+impl<I, F> ExactSizeIterator for SIMDMap<I, F> where Self : Iterator, I : SIMDIterable {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<A, B, I, F> SIMDObject for SIMDMap<I, F>
+    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
+    type Vector = A;
+    type Scalar = B;
+
+    #[inline(always)]
+    fn width(&self) -> usize {
+        Self::Vector::WIDTH
+    }
+}
+
+impl<A, B, I, F> SIMDIterable for SIMDMap<I, F>
+    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.iter.vector_pos()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.iter.vector_inc()
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        self.iter.scalar_inc()
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        // TODO: Is there a more sane return value (without invoking the closure)?
+        <Self::Vector as Packed>::default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iter.finalize()
+    }
+}
+
+impl<'a, A, B, I, F> SIMDIterator for SIMDMap<I, F>
+    where I : SIMDIterator, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        let (v, n) = self.iter.end()?;
+        let nr = n * I::Scalar::SIZE / Self::Scalar::SIZE;
+        Some(((self.func)(v), nr))
+    }
+}
+
+impl<I> Iterator for SIMDRev<I>
+    where I : SIMDDoubleEndedIterator, I::Vector : Reverse {
+    type Item = I::Vector;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|v| v.reverse())
+    }
+}
+
+impl<I> ExactSizeIterator for SIMDRev<I>
+    where I : SIMDDoubleEndedIterator, I::Vector : Reverse {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> SIMDObject for SIMDRev<I>
+    where I : SIMDDoubleEndedIterator, I::Vector : Reverse {
+    type Vector = I::Vector;
+    type Scalar = I::Scalar;
+}
+
+impl<I> SIMDIterable for SIMDRev<I>
+    where I : SIMDDoubleEndedIterator, I::Vector : Reverse {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.iter.vector_pos()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.next();
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        // The front of the reversed stream is the back of the inner iterator
+        self.iter.scalar_inc_back()
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        self.iter.default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iter.finalize()
+    }
+}
+
+impl<I> SIMDIterator for SIMDRev<I>
+    where I : SIMDDoubleEndedIterator, I::Vector : Reverse {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        // The partial vector of the inner iterator holds the elements at its
+        // front, so they are the last elements of the reversed stream
+        let (v, n) = self.iter.end()?;
+        let mut ret = self.iter.default();
+        for i in 0..(self.width() - n) {
+            unsafe {
+                ret = ret.replace_unchecked(n + i, v.extract_unchecked(self.width() - 1 - i));
+            }
+        }
+        Some((ret, n))
+    }
+}
+
+impl<I, F, A, E> SIMDMapExpand<I, F, A>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector) -> E, E : ExpandedVectors<Vector = A>, A : Packed {
+    /// Return the amount of scalars left in this iterator.
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        self.iter.scalar_len() - self.pos
+    }
+
+    /// Map the inner vector holding the scalar at `pos` into the buffer. A
+    /// whole vector starting at `pos` is loaded if one remains; otherwise the
+    /// last vector of the inner iterator is loaded.
+    #[inline(always)]
+    fn refill(&mut self) {
+        let width = I::Vector::WIDTH;
+        let len = self.iter.scalar_len();
+        debug_assert!(self.pos < len);
+        let v = if self.pos + width <= len {
+            self.start = self.pos;
+            unsafe { self.iter.load_unchecked(self.pos) }
+        } else if width <= len {
+            self.start = len - width;
+            unsafe { self.iter.load_unchecked(self.start) }
+        } else {
+            // The inner iterator is shorter than a vector
+            self.start = self.pos;
+            let mut ret = self.iter.default();
+            for i in self.pos..len {
+                unsafe {
+                    ret = ret.replace_unchecked(i - self.pos, self.iter.load_scalar_unchecked(i));
+                }
+            }
+            ret
+        };
+        (self.func)(v).store_vectors(&mut self.buf);
+        self.end = self.start + width;
+    }
+
+    /// Return the next scalar of the mapped stream, refilling the buffer if
+    /// needed.
+    #[inline(always)]
+    unsafe fn next_scalar(&mut self) -> A::Scalar {
+        if self.pos < self.start || self.pos >= self.end {
+            self.refill();
+        }
+        let n = self.pos - self.start;
+        self.pos += 1;
+        self.buf[n / A::WIDTH].extract_unchecked(n % A::WIDTH)
+    }
+}
+
+impl<I, F, A, E> Iterator for SIMDMapExpand<I, F, A>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector) -> E, E : ExpandedVectors<Vector = A>, A : Packed {
+    type Item = A;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = A::WIDTH;
+        if self.remaining() < width {
+            // Only a partial vector is left; end() will yield it
+            return None;
+        }
+
+        if self.pos < self.start || self.pos >= self.end {
+            self.refill();
+        }
+
+        let offset = self.pos - self.start;
+        if offset % width == 0 && self.pos + width <= self.end {
+            self.pos += width;
+            Some(self.buf[offset / width])
+        } else {
+            // The vector straddles two inner vectors, so gather its elements
+            let mut ret = A::default();
+            for i in 0..width {
+                unsafe {
+                    let el = self.next_scalar();
+                    ret = ret.replace_unchecked(i, el);
+                }
+            }
+            Some(ret)
+        }
+    }
+}
+
+impl<I, F, A, E> ExactSizeIterator for SIMDMapExpand<I, F, A>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector) -> E, E : ExpandedVectors<Vector = A>, A : Packed {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.remaining() / A::WIDTH
+    }
+}
+
+impl<I, F, A, E> SIMDObject for SIMDMapExpand<I, F, A>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector) -> E, E : ExpandedVectors<Vector = A>, A : Packed {
+    type Vector = A;
+    type Scalar = A::Scalar;
+}
+
+impl<I, F, A, E> SIMDIterable for SIMDMapExpand<I, F, A>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector) -> E, E : ExpandedVectors<Vector = A>, A : Packed {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.pos / self.width()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.next();
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        if self.pos < self.iter.scalar_len() {
+            self.pos += 1;
+        }
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        <Self::Vector as Packed>::default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iter.finalize();
+        self.pos = self.iter.scalar_len();
+    }
+}
+
+impl<I, F, A, E> SIMDIterator for SIMDMapExpand<I, F, A>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector) -> E, E : ExpandedVectors<Vector = A>, A : Packed {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        let width = A::WIDTH;
+        let remaining = self.remaining();
+        if remaining == 0 {
+            return None;
+        }
+
+        debug_assert!(remaining < width);
+        let empty_amt = width - remaining;
+        let mut ret = A::default();
+        for i in 0..remaining {
+            unsafe {
+                let el = self.next_scalar();
+                ret = ret.replace_unchecked(empty_amt + i, el);
+            }
+        }
+        Some((ret, empty_amt))
+    }
+}
+
+impl<I, F, A> SIMDMapContract<I, F>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector, I::Vector) -> A, A : Packed {
+    /// Return the amount of scalars left in this iterator.
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        self.iter.scalar_len() - self.pos
+    }
+}
+
+impl<I, F, A> Iterator for SIMDMapContract<I, F>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector, I::Vector) -> A, A : Packed {
+    type Item = A;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = I::Vector::WIDTH;
+        if self.remaining() < width * 2 {
+            // The remaining elements won't fill an output vector; end() will
+            // yield them
+            return None;
+        }
+
+        let (a, b) = unsafe {
+            (self.iter.load_unchecked(self.pos), self.iter.load_unchecked(self.pos + width))
+        };
+        self.pos += width * 2;
+        Some((self.func)(a, b))
+    }
+}
+
+impl<I, F, A> ExactSizeIterator for SIMDMapContract<I, F>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector, I::Vector) -> A, A : Packed {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.remaining() / A::WIDTH
+    }
+}
+
+impl<I, F, A> SIMDObject for SIMDMapContract<I, F>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector, I::Vector) -> A, A : Packed {
+    type Vector = A;
+    type Scalar = A::Scalar;
+}
+
+impl<I, F, A> SIMDIterable for SIMDMapContract<I, F>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector, I::Vector) -> A, A : Packed {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.pos / self.width()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.next();
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        if self.pos < self.iter.scalar_len() {
+            self.pos += 1;
+        }
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        <Self::Vector as Packed>::default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iter.finalize();
+        self.pos = self.iter.scalar_len();
+    }
+}
+
+impl<I, F, A> SIMDIterator for SIMDMapContract<I, F>
+    where I : SIMDIterator + SIMDArray, F : FnMut(I::Vector, I::Vector) -> A, A : Packed {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        let width = I::Vector::WIDTH;
+        let remaining = self.remaining();
+        if remaining == 0 {
+            return None;
+        }
+
+        // Right-align the remaining elements across both input vectors
+        debug_assert!(remaining < width * 2);
+        let empty_amt = width * 2 - remaining;
+        let (mut lo, mut hi) = (self.iter.default(), self.iter.default());
+        for i in 0..remaining {
+            let dst = empty_amt + i;
+            unsafe {
+                let el = self.iter.load_scalar_unchecked(self.pos + i);
+                if dst < width {
+                    lo = lo.replace_unchecked(dst, el);
+                } else {
+                    hi = hi.replace_unchecked(dst - width, el);
+                }
+            }
+        }
+        self.pos += remaining;
+        Some(((self.func)(lo, hi), empty_amt))
+    }
+}
+
+impl<I> Iterator for SIMDTake<I> where I : SIMDIterator {
+    type Item = I::Vector;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining >= self.width() {
+            let ret = self.iter.next()?;
+            self.remaining -= self.width();
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
+
+impl<I> ExactSizeIterator for SIMDTake<I> where I : SIMDIterator {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        cmp::min(self.iter.len(), self.remaining / self.width())
+    }
+}
+
+impl<I> SIMDObject for SIMDTake<I> where I : SIMDIterator {
+    type Vector = I::Vector;
+    type Scalar = I::Scalar;
+}
+
+impl<I> SIMDIterable for SIMDTake<I> where I : SIMDIterator {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.iter.vector_pos()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.iter.vector_inc();
+        self.remaining = self.remaining.saturating_sub(self.width());
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        self.iter.scalar_inc();
+        self.remaining = self.remaining.saturating_sub(1);
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        self.iter.default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iter.finalize();
+        self.remaining = 0;
+    }
+}
+
+impl<I> SIMDIterator for SIMDTake<I> where I : SIMDIterator {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let width = self.width();
+        let (v, n) = if let Some(v) = self.iter.next() {
+            (v, 0)
+        } else {
+            self.iter.end()?
+        };
+
+        // Right-align the first elements of the inner iterator's vector
+        let count = cmp::min(width - n, self.remaining);
+        let mut ret = self.iter.default();
+        for i in 0..count {
+            unsafe {
+                ret = ret.replace_unchecked(width - count + i, v.extract_unchecked(n + i));
+            }
+        }
+        self.finalize();
+        Some((ret, width - count))
+    }
+}
+
+impl<I> Iterator for SIMDStepBy<I> where I : SIMDIterator {
+    type Item = I::Vector;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.first {
+            self.first = false;
+        } else {
+            for _ in 1..self.step {
+                self.iter.vector_inc();
+            }
+        }
+
+        let ret = self.iter.next();
+        self.done = ret.is_none();
+        ret
+    }
+}
+
+impl<I> ExactSizeIterator for SIMDStepBy<I> where I : SIMDIterator {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        (self.iter.len() + self.step - 1) / self.step
+    }
+}
+
+impl<I> SIMDObject for SIMDStepBy<I> where I : SIMDIterator {
+    type Vector = I::Vector;
+    type Scalar = I::Scalar;
+}
+
+impl<I> SIMDIterable for SIMDStepBy<I> where I : SIMDIterator {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.iter.vector_pos()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.next();
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        self.iter.scalar_inc()
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        self.iter.default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iter.finalize();
+        self.done = true;
+    }
+}
+
+impl<I> SIMDIterator for SIMDStepBy<I> where I : SIMDIterator {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        // The inner iterator has already been advanced past the skipped
+        // vectors, so its partial vector is the next step
+        self.iter.end()
+    }
+}
+
+impl<I> Iterator for SIMDEnumerate<I> where I : SIMDIterator, I::Vector : Enumerable {
+    type Item = (<I::Vector as Enumerable>::Indices, I::Vector);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.iter.scalar_pos();
+        self.iter.next().map(|v| (I::Vector::indices(pos), v))
+    }
+}
+
+impl<I> ExactSizeIterator for SIMDEnumerate<I> where I : SIMDIterator, I::Vector : Enumerable {
+    #[inline(always)]
+    fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<A, B, I, F> SIMDObject for SIMDMap<I, F>
-    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
-    type Vector = A;
-    type Scalar = B;
+impl<I> SIMDZippedObject for SIMDEnumerate<I> where I : SIMDIterator, I::Vector : Enumerable {
+    type Vectors = (<I::Vector as Enumerable>::Indices, I::Vector);
+    type Scalars = (<<I::Vector as Enumerable>::Indices as Packed>::Scalar, I::Scalar);
 
     #[inline(always)]
     fn width(&self) -> usize {
-        Self::Vector::WIDTH
+        self.iter.width()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.iter.size()
     }
 }
 
-impl<A, B, I, F> SIMDIterable for SIMDMap<I, F>
-    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
+impl<I> SIMDZippedIterable for SIMDEnumerate<I> where I : SIMDIterator, I::Vector : Enumerable {
     #[inline(always)]
     fn scalar_pos(&self) -> usize {
         self.iter.scalar_pos()
@@ -579,9 +1688,8 @@ impl<A, B, I, F> SIMDIterable for SIMDMap<I, F>
     }
 
     #[inline(always)]
-    fn default(&self) -> Self::Vector {
-        // TODO: Is there a more sane return value (without invoking the closure)?
-        <Self::Vector as Packed>::default()
+    fn default(&self) -> Self::Vectors {
+        (<<I::Vector as Enumerable>::Indices as Packed>::default(), self.iter.default())
     }
 
     #[inline(always)]
@@ -590,13 +1698,155 @@ impl<A, B, I, F> SIMDIterable for SIMDMap<I, F>
     }
 }
 
-impl<'a, A, B, I, F> SIMDIterator for SIMDMap<I, F>
-    where I : SIMDIterator, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
+impl<I> SIMDZippedIterator for SIMDEnumerate<I> where I : SIMDIterator, I::Vector : Enumerable {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        let pos = self.iter.scalar_pos();
+        // The partial vector is right-aligned, so its first element is at
+        // index `n`
+        self.iter.end().map(|(v, n)| ((I::Vector::indices(pos.wrapping_sub(n)), v), n))
+    }
+}
+
+impl<A, B> SIMDChain<A, B>
+    where A : SIMDIterator + SIMDArray, B : SIMDIterator<Vector = A::Vector> + SIMDArray {
+    /// Return the amount of scalars left in the first iterator.
+    #[inline(always)]
+    fn a_remaining(&self) -> usize {
+        self.a.scalar_len().saturating_sub(self.a.scalar_pos())
+    }
+
+    /// Return the amount of scalars left in the second iterator.
+    #[inline(always)]
+    fn b_remaining(&self) -> usize {
+        self.b.scalar_len().saturating_sub(self.b.scalar_pos())
+    }
+
+    /// Return the next scalar of the chained stream, and advance past it.
+    #[inline(always)]
+    unsafe fn next_scalar(&mut self) -> A::Scalar {
+        if self.a_remaining() > 0 {
+            let ret = self.a.load_scalar_unchecked(self.a.scalar_pos());
+            self.a.scalar_inc();
+            ret
+        } else {
+            let ret = self.b.load_scalar_unchecked(self.b.scalar_pos());
+            self.b.scalar_inc();
+            ret
+        }
+    }
+}
+
+impl<A, B> Iterator for SIMDChain<A, B>
+    where A : SIMDIterator + SIMDArray, B : SIMDIterator<Vector = A::Vector> + SIMDArray {
+    type Item = A::Vector;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = self.width();
+        let a_remaining = self.a_remaining();
+        if a_remaining >= width {
+            return self.a.next();
+        }
+
+        if a_remaining > 0 {
+            if a_remaining + self.b_remaining() < width {
+                // The second iterator can't fill the seam vector; end() will
+                // yield it
+                return None;
+            }
+
+            // Fill the seam vector with the last elements of the first
+            // iterator and the first elements of the second
+            let mut ret = self.a.default();
+            for i in 0..width {
+                unsafe {
+                    let el = self.next_scalar();
+                    ret = ret.replace_unchecked(i, el);
+                }
+            }
+            return Some(ret);
+        }
+
+        self.b.next()
+    }
+}
+
+impl<A, B> ExactSizeIterator for SIMDChain<A, B>
+    where A : SIMDIterator + SIMDArray, B : SIMDIterator<Vector = A::Vector> + SIMDArray {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        // The partial vector of the first iterator is filled by the second
+        (self.a_remaining() + self.b_remaining()) / self.width()
+    }
+}
+
+impl<A, B> SIMDObject for SIMDChain<A, B>
+    where A : SIMDIterator + SIMDArray, B : SIMDIterator<Vector = A::Vector> + SIMDArray {
+    type Vector = A::Vector;
+    type Scalar = A::Scalar;
+}
+
+impl<A, B> SIMDIterable for SIMDChain<A, B>
+    where A : SIMDIterator + SIMDArray, B : SIMDIterator<Vector = A::Vector> + SIMDArray {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        // Positions in the second iterator continue from the end of the first
+        self.a.scalar_pos() + (self.b.scalar_pos() - self.b_start)
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.scalar_pos() / self.width()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.next();
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        if self.a_remaining() > 0 {
+            self.a.scalar_inc();
+        } else if self.b_remaining() > 0 {
+            self.b.scalar_inc();
+        }
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        self.a.default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.a.finalize();
+        self.b.finalize();
+    }
+}
+
+impl<A, B> SIMDIterator for SIMDChain<A, B>
+    where A : SIMDIterator + SIMDArray, B : SIMDIterator<Vector = A::Vector> + SIMDArray {
     #[inline(always)]
     fn end(&mut self) -> Option<(Self::Vector, usize)> {
-        let (v, n) = self.iter.end()?;
-        let nr = n * I::Scalar::SIZE / Self::Scalar::SIZE;
-        Some(((self.func)(v), nr))
+        if self.a_remaining() == 0 {
+            return self.b.end();
+        }
+
+        // Right-align the remaining elements of both iterators
+        let width = self.width();
+        let remaining = self.a_remaining() + self.b_remaining();
+        debug_assert!(remaining < width);
+        let empty_amt = width - remaining;
+        let mut ret = self.a.default();
+        for i in 0..remaining {
+            unsafe {
+                let el = self.next_scalar();
+                ret = ret.replace_unchecked(empty_amt + i, el);
+            }
+        }
+        Some((ret, empty_amt))
     }
 }
 
@@ -651,7 +1901,7 @@ impl<'a, T, I> IntoScalar<T> for I
                 }
                 ret.set_len(self.width() + offset - n);
             } else {
-                ret.set_len(self.len() * self.width());
+                ret.set_len(offset);
             }
         }
         ret
@@ -753,4 +2003,269 @@ mod tests {
                      3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
                      5, 0, 0, 0, 0, 0, 0, 0u8][..]);
     }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn map_expand_collect() {
+        for len in 0..200 {
+            let a = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+            let halves = a.simd_iter(u8s(0))
+                .simd_map_expand(|v| -> (u16s, u16s) { v.upcast() })
+                .scalar_collect();
+            assert_eq!(halves, a.iter().map(|&x| x as u16).collect::<Vec<u16>>());
+
+            let quarters = a.simd_iter(u8s(0))
+                .simd_map_expand(|v| {
+                    let mut out = [f32s(0.0); 4];
+                    v.widen(&mut out);
+                    (out[0], out[1], out[2], out[3])
+                })
+                .scalar_collect();
+            assert_eq!(quarters, a.iter().map(|&x| x as f32).collect::<Vec<f32>>());
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn map_contract_collect() {
+        for len in 0..200 {
+            let a = (0..len).map(|i| i as i32 * 1000 - 50000).collect::<Vec<i32>>();
+            let narrowed = a.simd_iter(i32s(0))
+                .simd_map_contract(|a, b| -> i16s { a.saturating_downcast(b) })
+                .scalar_collect();
+            assert_eq!(narrowed, a.iter().map(|&x| x.max(-32768).min(32767) as i16).collect::<Vec<i16>>());
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn map_expand_scalar_inc() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+            for skip in 0..(len.min(40) + 1) {
+                let mut iter = a.simd_iter(u8s(0))
+                    .simd_map_expand(|v| -> (u16s, u16s) { v.upcast() });
+                for _ in 0..skip {
+                    iter.scalar_inc();
+                }
+                assert_eq!(iter.len(), (len - skip) / u16s::WIDTH);
+                assert_eq!(iter.scalar_collect(), a.iter().skip(skip).map(|&x| x as u16).collect::<Vec<u16>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn map_contract_scalar_inc() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| i as i32 - 50).collect::<Vec<i32>>();
+            for skip in 0..(len.min(40) + 1) {
+                let mut iter = a.simd_iter(i32s(0))
+                    .simd_map_contract(|a, b| -> i16s { a.saturating_downcast(b) });
+                for _ in 0..skip {
+                    iter.scalar_inc();
+                }
+                assert_eq!(iter.len(), (len - skip) / i16s::WIDTH);
+                assert_eq!(iter.scalar_collect(), a.iter().skip(skip).map(|&x| x as i16).collect::<Vec<i16>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn rev_collect() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| i as u16).collect::<Vec<u16>>();
+            let reversed = a.simd_iter(u16s(0)).simd_rev().scalar_collect();
+            assert_eq!(reversed, a.iter().rev().cloned().collect::<Vec<u16>>());
+
+            let doubled = a.simd_iter(u16s(0)).simd_map(|v| v * u16s(2)).simd_rev().scalar_collect();
+            assert_eq!(doubled, a.iter().rev().map(|x| x * 2).collect::<Vec<u16>>());
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn rev_scalar_inc() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| i as u16).collect::<Vec<u16>>();
+            for skip in 0..(len.min(20) + 1) {
+                let mut iter = a.simd_iter(u16s(0)).simd_rev();
+                for _ in 0..skip {
+                    iter.scalar_inc();
+                }
+                assert_eq!(iter.scalar_collect(), a.iter().rev().skip(skip).cloned().collect::<Vec<u16>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn double_ended_iter() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| i as f32).collect::<Vec<f32>>();
+            let mut iter = a.simd_iter(f32s(0.0));
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            loop {
+                if let Some(v) = iter.next_back() {
+                    back.push(v);
+                } else {
+                    break;
+                }
+                if let Some(v) = iter.next() {
+                    front.push(v);
+                }
+            }
+            let width = f32s::WIDTH;
+            assert_eq!(front.len() + back.len(), len / width);
+            let mut seen = front.len() * width;
+            if let Some((v, n)) = iter.end() {
+                for i in n..width {
+                    assert_eq!(v.extract(i as u32), seen as f32);
+                    seen += 1;
+                }
+            }
+            for v in back.iter().rev() {
+                assert_eq!(v.extract(0), seen as f32);
+                seen += width;
+            }
+            assert_eq!(seen, len);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn skip_take_collect() {
+        let a = (0..100).map(|i| i as i32).collect::<Vec<i32>>();
+        for skip in 0..40 {
+            for take in 0..40 {
+                let collected = a.simd_iter(i32s(0))
+                    .simd_skip(skip)
+                    .simd_take(take)
+                    .simd_map(|v| v * i32s(3))
+                    .scalar_collect();
+                assert_eq!(collected, a[skip..skip + take].iter().map(|x| x * 3).collect::<Vec<i32>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn skip_for_each() {
+        let width = u8s::WIDTH;
+        for len in 0..(width * 3) {
+            for skip in 0..(len + 1) {
+                let mut a = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+                a.simd_iter_mut(u8s(0)).simd_skip(skip).simd_for_each(|v| *v = *v + u8s(100));
+                let expected = (0..len).map(|i| if i < skip { i as u8 } else { (i as u8).wrapping_add(100) });
+                assert_eq!(a, expected.collect::<Vec<u8>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn take_past_end() {
+        let a = [7u8; 20];
+        assert_eq!(a.simd_iter(u8s(0)).simd_take(100).scalar_collect(), vec![7u8; 20]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn step_by_collect() {
+        let width = u16s::WIDTH;
+        for len in 0..(width * 8) {
+            let a = (0..len).map(|i| i as u16).collect::<Vec<u16>>();
+            let stepped = a.simd_iter(u16s(0)).simd_step_by(3).scalar_collect();
+            let expected = a.chunks(width).enumerate()
+                .filter(|&(i, _)| i % 3 == 0)
+                .flat_map(|(_, c)| c.iter().cloned())
+                .collect::<Vec<u16>>();
+            assert_eq!(stepped, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn chain_collect() {
+        for len_a in 0..40 {
+            for len_b in 0..40 {
+                let a = (0..len_a).map(|i| i as f64).collect::<Vec<f64>>();
+                let b = (0..len_b).map(|i| -(i as f64)).collect::<Vec<f64>>();
+                let chained = a.simd_iter(f64s(0.0))
+                    .simd_chain(b.simd_iter(f64s(0.0)))
+                    .scalar_collect();
+                assert_eq!(chained, a.iter().chain(b.iter()).cloned().collect::<Vec<f64>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn chain_len_and_pos() {
+        let width = f64s::WIDTH;
+        for len_a in 0..20 {
+            for len_b in 0..20 {
+                let a = vec![1.0f64; len_a];
+                let b = vec![2.0f64; len_b];
+                let mut iter = a.simd_iter(f64s(0.0)).simd_chain(b.simd_iter(f64s(0.0)));
+                let mut consumed = 0;
+                assert_eq!(iter.len(), (len_a + len_b) / width);
+                while let Some(_) = iter.next() {
+                    consumed += width;
+                    assert_eq!(iter.scalar_pos(), consumed);
+                    assert_eq!(iter.len(), (len_a + len_b - consumed) / width);
+                }
+                assert_eq!(consumed, (len_a + len_b) / width * width);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn skip_adapters() {
+        for len in 0..70 {
+            let a = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+            let b = (0..len).map(|i| i as u8 ^ 0xFF).collect::<Vec<u8>>();
+            for skip in 0..(len + 1) {
+                let rev = a.simd_iter(u8s(0)).simd_rev().simd_skip(skip).scalar_collect();
+                assert_eq!(rev, a.iter().rev().skip(skip).cloned().collect::<Vec<u8>>());
+
+                let expanded = a.simd_iter(u8s(0))
+                    .simd_map_expand(|v| -> (u16s, u16s) { v.upcast() })
+                    .simd_skip(skip)
+                    .scalar_collect();
+                assert_eq!(expanded, a.iter().skip(skip).map(|&x| x as u16).collect::<Vec<u16>>());
+
+                let chained = a.simd_iter(u8s(0))
+                    .simd_chain(b.simd_iter(u8s(0)))
+                    .simd_skip(skip)
+                    .scalar_collect();
+                assert_eq!(chained, a.iter().chain(b.iter()).skip(skip).cloned().collect::<Vec<u8>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn enumerate_collect() {
+        for len in 0..50 {
+            let a = (0..len).map(|i| i as f32 * 2.0).collect::<Vec<f32>>();
+            let idx = a.simd_iter(f32s(0.0))
+                .simd_enumerate()
+                .simd_map(|(i, _)| i)
+                .scalar_collect();
+            assert_eq!(idx, (0..len).map(|i| i as u32).collect::<Vec<u32>>());
+
+            let b = (0..len).map(|i| -(i as i64)).collect::<Vec<i64>>();
+            let skip = len.min(3);
+            let sums = b.simd_iter(i64s(0))
+                .simd_skip(skip)
+                .simd_enumerate()
+                .simd_map(|(i, v)| i.be_i64s() + v)
+                .scalar_collect();
+            assert_eq!(sums, vec![0i64; len - skip]);
+        }
+    }
 }
@@ -201,6 +201,7 @@
 #![feature(cfg_target_feature)]
 #![feature(specialization)]
 #![feature(const_fn)]
+#![feature(i128_type)]
 #![cfg_attr(test, feature(test))]
 #![cfg_attr(test, feature(inclusive_range))]
 
@@ -216,6 +217,7 @@ extern crate stdsimd;
 mod shimvecs;
 
 #[macro_use] pub mod zip;
+#[macro_use] pub mod dispatch;
 pub mod vecs;
 pub mod vec_patterns;
 pub mod iters;
@@ -224,6 +226,10 @@ pub mod intrin;
 pub mod prelude;
 pub mod stride_zip;
 pub mod stride;
+pub mod upcast_zip;
+pub mod complex;
+pub mod half;
+pub mod wrapped;
 
 pub use prelude::*;
 
@@ -9,7 +9,13 @@ pub use iters::*;
 pub use into_iters::*;
 pub use vecs::{Packed, Pattern};
 pub use vecs::{u8s, i8s, u16s, i16s, u32s, i32s, f32s, u64s, i64s, f64s};
+pub use vecs::{u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2};
 pub use intrin::*;
 pub use zip::*;
 pub use stride_zip::*;
 pub use stride::*;
+pub use upcast_zip::*;
+pub use complex::*;
+pub use half::*;
+pub use wrapped::*;
+pub use dispatch::{SIMDLevel, detect_simd_level};
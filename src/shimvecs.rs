@@ -13,7 +13,10 @@
 use core_or_std::mem::{transmute, size_of};
 use core_or_std::ptr::{copy_nonoverlapping};
 use core_or_std::ops::{Mul, MulAssign, Div, DivAssign, Add, AddAssign, Sub, SubAssign, Shl, ShlAssign, Shr, ShrAssign, Rem, RemAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use core_or_std::ops::Index;
 use core_or_std::fmt::{Error, Debug, Formatter};
+#[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
+use vec_patterns::Lanes;
 
 macro_rules! impl_packed {
     ($el:ident, $pvec:ident, $vec:ident, $sz:expr, [$($elname:ident),+]) => {
@@ -41,6 +44,42 @@ macro_rules! impl_packed {
             }
         }
 
+        impl Index<usize> for $vec {
+            type Output = $el;
+
+            #[inline(always)]
+            fn index(&self, idx: usize) -> &$el {
+                &self.data[idx]
+            }
+        }
+
+        impl From<[$el; $sz]> for $vec {
+            #[inline(always)]
+            fn from(data: [$el; $sz]) -> $vec {
+                $vec { data: data }
+            }
+        }
+
+        impl From<$vec> for [$el; $sz] {
+            #[inline(always)]
+            fn from(vec: $vec) -> [$el; $sz] {
+                vec.data
+            }
+        }
+
+        // Only implemented while these are the vectors of the crate, as
+        // `Lanes` requires `Packed`
+        #[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
+        impl IntoIterator for $vec {
+            type Item = $el;
+            type IntoIter = Lanes<$vec>;
+
+            #[inline(always)]
+            fn into_iter(self) -> Lanes<$vec> {
+                Lanes::new(self)
+            }
+        }
+
         impl $vec {
             #[inline(always)]
             pub const fn new($($elname: $el),*) -> $vec {
@@ -11,11 +11,7 @@ use vecs::*;
 use iters::{SIMDIterable, SIMDIterator, SIMDArray, SIMDObject, UnsafeIterator};
 use core_or_std::iter::{Iterator, ExactSizeIterator};
 
-// For AVX2 gathers
-use core_or_std::mem::transmute;
-use stdsimd::vendor::*;
-use stdsimd::simd::{__m256i, __m128i};
-use intrin::Transmute;
+use intrin::Destride;
 
 /// A slice-backed iterator which packs every nth element of its constituent
 /// elements into a vector.
@@ -28,18 +24,70 @@ pub struct PackedStride<'a, A> where A : 'a + SIMDArray {
     default: <A as SIMDObject>::Vector
 }
 
-impl<'a, A> Iterator for PackedStride<'a, A> where A : 'a + SIMDArray {
+impl<'a, A> PackedStride<'a, A> where A : 'a + SIMDArray, A::Vector : Destride {
+    /// Load a vector of every `stride`th scalar of the underlying array,
+    /// beginning at `pos`. Strides of 2, 3, 4 and 8 load the contiguous
+    /// vectors spanning the stride and deinterleave them with shuffles, if the
+    /// vector type has shuffles for that stride. Other strides, and any load
+    /// which would overrun the array, are gathered.
+    ///
+    /// Each channel takes its own output of the destride in a separate arm,
+    /// so the shuffles which only produce the other channels are dead once
+    /// `base` is known. Use `stride_zip`, `stride_zip3` or `stride_zip4` to
+    /// deinterleave each block exactly once for all of its channels.
+    #[inline(always)]
+    unsafe fn load_packed(&self, pos: usize) -> <A as SIMDObject>::Vector {
+        let width = self.width();
+        let start = pos - self.base;
+
+        if start + self.stride * width > self.iter.scalar_len() {
+            return self.iter.load_strided_unchecked(pos, self.stride);
+        }
+
+        let load = |n: usize| self.iter.load_unchecked(start + width * n);
+        match self.stride {
+            2 if <A::Vector as Destride>::SHUFFLES_TWO => match self.base {
+                0 => load(0).destride_two(load(1)).0,
+                _ => load(0).destride_two(load(1)).1
+            },
+            3 if <A::Vector as Destride>::SHUFFLES_THREE => match self.base {
+                0 => load(0).destride_three(load(1), load(2)).0,
+                1 => load(0).destride_three(load(1), load(2)).1,
+                _ => load(0).destride_three(load(1), load(2)).2
+            },
+            4 if <A::Vector as Destride>::SHUFFLES_FOUR => match self.base {
+                0 => load(0).destride_four(load(1), load(2), load(3)).0,
+                1 => load(0).destride_four(load(1), load(2), load(3)).1,
+                2 => load(0).destride_four(load(1), load(2), load(3)).2,
+                _ => load(0).destride_four(load(1), load(2), load(3)).3
+            },
+            8 if <A::Vector as Destride>::SHUFFLES_FOUR && <A::Vector as Destride>::SHUFFLES_TWO => {
+                // Destride both halves by four, then split the matching
+                // quarters into their even and odd elements
+                let (lo, hi) = match self.base % 4 {
+                    0 => (load(0).destride_four(load(1), load(2), load(3)).0,
+                          load(4).destride_four(load(5), load(6), load(7)).0),
+                    1 => (load(0).destride_four(load(1), load(2), load(3)).1,
+                          load(4).destride_four(load(5), load(6), load(7)).1),
+                    2 => (load(0).destride_four(load(1), load(2), load(3)).2,
+                          load(4).destride_four(load(5), load(6), load(7)).2),
+                    _ => (load(0).destride_four(load(1), load(2), load(3)).3,
+                          load(4).destride_four(load(5), load(6), load(7)).3)
+                };
+                if self.base < 4 { lo.destride_two(hi).0 } else { lo.destride_two(hi).1 }
+            },
+            _ => self.iter.load_strided_unchecked(pos, self.stride)
+        }
+    }
+}
+
+impl<'a, A> Iterator for PackedStride<'a, A> where A : 'a + SIMDArray, A::Vector : Destride {
     type Item = <A as SIMDObject>::Vector;
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
         if self.pos + self.stride * self.width() < self.iter.scalar_len() {
-            let mut ret = <Self as SIMDObject>::Vector::default();
-            for i in 0..self.width() {
-                ret = ret.replace(i, unsafe {
-                    self.iter.load_scalar_unchecked(self.pos + self.stride * i)
-                });
-            }
+            let ret = unsafe { self.load_packed(self.pos) };
             self.vector_inc();
             Some(ret)
         } else {
@@ -48,7 +96,7 @@ impl<'a, A> Iterator for PackedStride<'a, A> where A : 'a + SIMDArray {
     }
 }
 
-impl<'a, A> ExactSizeIterator for PackedStride<'a, A> where A : SIMDArray {
+impl<'a, A> ExactSizeIterator for PackedStride<'a, A> where A : SIMDArray, A::Vector : Destride {
     #[inline(always)]
     fn len(&self) -> usize {
         self.iter.vector_len() / self.stride
@@ -266,7 +314,7 @@ impl<'a, A> SIMDObject for PackedStride<'a, A> where A : SIMDArray {
     }
 }
 
-impl<'a, A> SIMDArray for PackedStride<'a, A> where A : SIMDArray {
+impl<'a, A> SIMDArray for PackedStride<'a, A> where A : SIMDArray, A::Vector : Destride {
     #[inline(always)]
     fn load(&self, offset: usize) -> Self::Vector {
         assert!(self.base + self.stride * (offset + (self.width() - 1)) < self.iter.scalar_len());
@@ -275,13 +323,8 @@ impl<'a, A> SIMDArray for PackedStride<'a, A> where A : SIMDArray {
 
     #[inline(always)]
     unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector {
-        debug_assert!(offset + self.stride * (self.width() - 1) < self.iter.scalar_len());
-        let mut ret = <Self as SIMDObject>::Vector::default();
-
-        for i in 0..self.width() {
-            ret = ret.replace(i, self.iter.load_scalar_unchecked(self.base + self.stride * (offset + i)));
-        }
-        ret
+        debug_assert!(self.base + self.stride * (offset + (self.width() - 1)) < self.iter.scalar_len());
+        self.load_packed(self.base + self.stride * offset)
     }
 
     #[inline(always)]
@@ -305,7 +348,7 @@ impl<'a, A> SIMDArray for PackedStride<'a, A> where A : SIMDArray {
     }
 }
 
-impl<'a, A> SIMDIterable for PackedStride<'a, A> where A : SIMDArray {
+impl<'a, A> SIMDIterable for PackedStride<'a, A> where A : SIMDArray, A::Vector : Destride {
     #[inline(always)]
     fn scalar_pos(&self) -> usize {
         (self.pos - self.base) / self.stride
@@ -341,6 +384,7 @@ impl<'a, A> SIMDIterable for PackedStride<'a, A> where A : SIMDArray {
 mod tests {
     use super::super::*;
     use super::super::zip::*;
+    use test::{Bencher, black_box};
 
     use super::*;
 
@@ -380,4 +424,245 @@ mod tests {
             assert!(vec.scalar_reduce(true, |acc, s| acc && s % 2 == 0));
         }
     }
+
+    macro_rules! test_stride_loads {
+        (($($el:tt),*), ($($vec:tt),*), ($($fn:ident),*)) => {
+            $(
+                #[test]
+                #[cfg(not(feature = "no-std"))]
+                fn $fn() {
+                    for &count in &[2usize, 3, 4, 5, 8] {
+                        let x = (0..(count * 256)).map(|i| i as $el).collect::<Vec<$el>>();
+                        let y = &x[..];
+                        let defaults = vec![$vec::splat(0 as $el); count];
+                        for (base, iter) in y.stride(count, &defaults).into_iter().enumerate() {
+                            for offset in 0..(iter.scalar_len() - $vec::WIDTH + 1) {
+                                let v = iter.load(offset);
+                                for i in 0..$vec::WIDTH {
+                                    assert_eq!(v.extract(i as u32), x[base + count * (offset + i)]);
+                                }
+                            }
+                            for (n, v) in iter.enumerate() {
+                                for i in 0..$vec::WIDTH {
+                                    assert_eq!(v.extract(i as u32), x[base + count * (n * $vec::WIDTH + i)]);
+                                }
+                            }
+                        }
+                    }
+                }
+            )*
+        }
+    }
+
+    test_stride_loads!((u8, i16, u32, f32, u64, f64),
+                       (u8s, i16s, u32s, f32s, u64s, f64s),
+                       (stride_loads_u8, stride_loads_i16, stride_loads_u32, stride_loads_f32, stride_loads_u64, stride_loads_f64));
+
+    /// Load every channel of `a` one lane at a time, as strided loads did
+    /// before they were deinterleaved with shuffles.
+    #[cfg(not(feature = "no-std"))]
+    fn stride_replace<V>(a: &[V::Scalar], stride: usize) where V : Packed {
+        let width = V::WIDTH;
+        let mut pos = 0;
+        while pos + stride * width <= a.len() {
+            for channel in 0..stride {
+                let mut v = V::default();
+                for i in 0..width {
+                    v = v.replace(i, a[pos + channel + stride * i]);
+                }
+                black_box(v);
+            }
+            pos += stride * width;
+        }
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_three_scalar(b: &mut Bencher) {
+        let a = [0u8; 4095];
+        b.iter(|| {
+            for c in a.chunks(3) {
+                black_box((c[0], c[1], c[2]));
+            }
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_three_replace(b: &mut Bencher) {
+        let a = [0u8; 4095];
+        b.iter(|| stride_replace::<u8s>(&a, 3))
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_three_simd(b: &mut Bencher) {
+        let a = [0u8; 4095];
+        b.iter(|| {
+             (&a[..]).stride_three(tuplify!(3, u8s(0))).zip()
+                .simd_do_each(|x| { black_box(x); });
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_three_zip(b: &mut Bencher) {
+        let a = [0u8; 4095];
+        b.iter(|| {
+            (&a[..]).simd_iter(u8s(0)).stride_zip3()
+                .simd_do_each(|x| { black_box(x); });
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_three_scalar_32(b: &mut Bencher) {
+        let a = [0u32; 4095];
+        b.iter(|| {
+            for c in a.chunks(3) {
+                black_box((c[0], c[1], c[2]));
+            }
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_three_replace_32(b: &mut Bencher) {
+        let a = [0u32; 4095];
+        b.iter(|| stride_replace::<u32s>(&a, 3))
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_three_simd_32(b: &mut Bencher) {
+        let a = [0u32; 4095];
+        b.iter(|| {
+             (&a[..]).stride_three(tuplify!(3, u32s(0))).zip()
+                .simd_do_each(|x| { black_box(x); });
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_four_scalar(b: &mut Bencher) {
+        let a = [0u8; 4096];
+        b.iter(|| {
+            for c in a.chunks(4) {
+                black_box((c[0], c[1], c[2], c[3]));
+            }
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_four_replace(b: &mut Bencher) {
+        let a = [0u8; 4096];
+        b.iter(|| stride_replace::<u8s>(&a, 4))
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_four_simd(b: &mut Bencher) {
+        let a = [0u8; 4096];
+        b.iter(|| {
+             (&a[..]).stride_four(tuplify!(4, u8s(0))).zip()
+                .simd_do_each(|x| { black_box(x); });
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_four_zip(b: &mut Bencher) {
+        let a = [0u8; 4096];
+        b.iter(|| {
+            (&a[..]).simd_iter(u8s(0)).stride_zip4()
+                .simd_do_each(|x| { black_box(x); });
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_four_scalar_32(b: &mut Bencher) {
+        let a = [0u32; 4096];
+        b.iter(|| {
+            for c in a.chunks(4) {
+                black_box((c[0], c[1], c[2], c[3]));
+            }
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_four_replace_32(b: &mut Bencher) {
+        let a = [0u32; 4096];
+        b.iter(|| stride_replace::<u32s>(&a, 4))
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_four_simd_32(b: &mut Bencher) {
+        let a = [0u32; 4096];
+        b.iter(|| {
+             (&a[..]).stride_four(tuplify!(4, u32s(0))).zip()
+                .simd_do_each(|x| { black_box(x); });
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_eight_scalar(b: &mut Bencher) {
+        let a = [0u8; 4096];
+        b.iter(|| {
+            for c in a.chunks(8) {
+                black_box((c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]));
+            }
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_eight_replace(b: &mut Bencher) {
+        let a = [0u8; 4096];
+        b.iter(|| stride_replace::<u8s>(&a, 8))
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_eight_simd(b: &mut Bencher) {
+        let a = [0u8; 4096];
+        b.iter(|| {
+            for mut iter in (&a[..]).stride(8, &[u8s(0); 8]) {
+                iter.simd_do_each(|x| { black_box(x); });
+            }
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_nine_scalar_32(b: &mut Bencher) {
+        let a = [0u32; 4095];
+        b.iter(|| {
+            for c in a.chunks(9) {
+                black_box((c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8]));
+            }
+        })
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_nine_replace_32(b: &mut Bencher) {
+        let a = [0u32; 4095];
+        b.iter(|| stride_replace::<u32s>(&a, 9))
+    }
+
+    #[bench]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_nine_simd_32(b: &mut Bencher) {
+        let a = [0u32; 4095];
+        b.iter(|| {
+            for mut iter in (&a[..]).stride(9, &[u32s(0); 9]) {
+                iter.simd_do_each(|x| { black_box(x); });
+            }
+        })
+    }
 }
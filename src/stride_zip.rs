@@ -95,19 +95,331 @@ impl<T> Iterator for StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
 
 impl<T> SIMDZippedIterator for StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
     fn end(&mut self) -> Option<(Self::Vectors, usize)> {
-        let first = self.iter.next();
-        let (end, n) = self.iter.end().unwrap_or((self.iter.default(), 0));
-        if let Some(first) = first {
-            Some((first.destride_two(end), (self.width() + n) / 2))
-        } else {
-            if let Some(v) = self.peek {
-                self.peek = None;
-                Some((v.destride_two(end), (self.width() + n) / 2))
-            } else if n > 0 {
-                Some((end.destride_two(self.iter.default()), n / 2))
+        let first = self.peek.take().or_else(|| self.iter.next());
+        let last = self.iter.end();
+        let mut ret = [self.iter.default(); 2];
+        let empty_amt = match first {
+            Some(v) => destride_end(&[v], last, &mut ret),
+            None => destride_end(&[], last, &mut ret)
+        }?;
+        Some(((ret[0], ret[1]), empty_amt))
+    }
+}
+
+/// Deinterleave the remaining elements of a strided iterator into one
+/// right-aligned vector per channel. `full` contains the whole vectors which
+/// could not form a complete group, and `last` is the partial vector returned
+/// by `SIMDIterator::end`. Elements after the last complete group are
+/// dropped. Returns the amount of empty elements at the front of each
+/// channel, or `None` if no complete group remains.
+#[inline(always)]
+fn destride_end<V>(full: &[V], last: Option<(V, usize)>, channels: &mut [V]) -> Option<usize>
+    where V : Packed {
+    let width = V::WIDTH;
+    let count = channels.len();
+    let (last, last_empty) = last.unwrap_or((V::default(), width));
+    let full_len = full.len() * width;
+    let len = full_len + width - last_empty;
+    let groups = len / count;
+
+    if groups == 0 {
+        return None;
+    }
+
+    debug_assert!(groups <= width);
+    let empty_amt = width - groups;
+    for i in 0..(groups * count) {
+        unsafe {
+            let scalar = if i < full_len {
+                full[i / width].extract_unchecked(i % width)
             } else {
-                None
-            }
+                last.extract_unchecked(last_empty + i - full_len)
+            };
+            let mut channel = channels[i % count];
+            channels[i % count] = channel.replace_unchecked(empty_amt + i / count, scalar);
         }
     }
+    Some(empty_amt)
+}
+
+/// An iterator which destrides a single iterator into three channels, such
+/// that the 0th, 3rd, 6th... elements are packed into the first vector, the
+/// 1st, 4th, 7th... elements are packed into the second vector, and so on.
+pub struct StrideZip3<T> where T : SIMDIterator, T::Vector : Destride {
+    base: usize,
+    peek: [T::Vector; 3],
+    peeked: usize,
+    iter: T
+}
+
+/// An iterator which destrides a single iterator into four channels, such
+/// that the 0th, 4th, 8th... elements are packed into the first vector, the
+/// 1st, 5th, 9th... elements are packed into the second vector, and so on.
+pub struct StrideZip4<T> where T : SIMDIterator, T::Vector : Destride {
+    base: usize,
+    peek: [T::Vector; 4],
+    peeked: usize,
+    iter: T
+}
+
+/// A trait which can destride an iterator into a zipped iterator of 3-tuples.
+pub trait IntoStrideZip3 : Sized {
+    /// Return an iterator which yields every 3rd element of `self` in its
+    /// first vector, every 3rd element after the first in its second vector,
+    /// and so on.
+    ///
+    /// Only complete groups of 3 elements are yielded, so any elements after
+    /// the last complete group are skipped.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let rgb = [10u8, 20, 30].iter().cycle().take(300).cloned().collect::<Vec<u8>>();
+    /// let sums = rgb.simd_iter(u8s(0)).stride_zip3()
+    ///     .simd_map(|(r, g, b)| r + g + b)
+    ///     .scalar_collect();
+    /// assert_eq!(sums, vec![60u8; 100]);
+    /// # }
+    /// ```
+    fn stride_zip3(self) -> StrideZip3<Self>
+        where Self : SIMDIterator, Self::Vector : Destride;
+}
+
+/// A trait which can destride an iterator into a zipped iterator of 4-tuples.
+pub trait IntoStrideZip4 : Sized {
+    /// Return an iterator which yields every 4th element of `self` in its
+    /// first vector, every 4th element after the first in its second vector,
+    /// and so on.
+    ///
+    /// Only complete groups of 4 elements are yielded, so any elements after
+    /// the last complete group are skipped.
+    fn stride_zip4(self) -> StrideZip4<Self>
+        where Self : SIMDIterator, Self::Vector : Destride;
+}
+
+impl<T> IntoStrideZip3 for T where T : SIMDIterator, T::Vector : Destride {
+    fn stride_zip3(self) -> StrideZip3<Self> {
+        StrideZip3 {
+            base: self.scalar_pos(),
+            peek: [T::Vector::default(); 3],
+            peeked: 0,
+            iter: self
+        }
+    }
+}
+
+impl<T> IntoStrideZip4 for T where T : SIMDIterator, T::Vector : Destride {
+    fn stride_zip4(self) -> StrideZip4<Self> {
+        StrideZip4 {
+            base: self.scalar_pos(),
+            peek: [T::Vector::default(); 4],
+            peeked: 0,
+            iter: self
+        }
+    }
+}
+
+impl<T> SIMDZippedObject for StrideZip3<T> where T : SIMDIterator, T::Vector : Destride {
+    type Scalars = (T::Scalar, T::Scalar, T::Scalar);
+    type Vectors = (T::Vector, T::Vector, T::Vector);
+
+    /// Return the vector length of this object.
+    #[inline(always)]
+    fn width(&self) -> usize {
+        T::Vector::WIDTH
+    }
+
+    /// Return the scalar length of this object.
+    #[inline(always)]
+    fn size(&self) -> usize {
+        T::Scalar::SIZE
+    }
+}
+
+impl<T> SIMDZippedObject for StrideZip4<T> where T : SIMDIterator, T::Vector : Destride {
+    type Scalars = (T::Scalar, T::Scalar, T::Scalar, T::Scalar);
+    type Vectors = (T::Vector, T::Vector, T::Vector, T::Vector);
+
+    /// Return the vector length of this object.
+    #[inline(always)]
+    fn width(&self) -> usize {
+        T::Vector::WIDTH
+    }
+
+    /// Return the scalar length of this object.
+    #[inline(always)]
+    fn size(&self) -> usize {
+        T::Scalar::SIZE
+    }
+}
+
+impl<T> ExactSizeIterator for StrideZip3<T> where T : SIMDIterator, T::Vector : Destride {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len() / 3
+    }
+}
+
+impl<T> ExactSizeIterator for StrideZip4<T> where T : SIMDIterator, T::Vector : Destride {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len() / 4
+    }
+}
+
+impl<T> SIMDZippedIterable for StrideZip3<T> where T : SIMDIterator, T::Vector : Destride {
+    fn scalar_pos(&self) -> usize {
+        (self.iter.scalar_pos() - self.base) / 3
+    }
+
+    fn vector_pos(&self) -> usize {
+        (self.iter.vector_pos() - (self.base / self.width())) / 3
+    }
+
+    fn vector_inc(&mut self) {
+        self.iter.vector_inc();
+        self.iter.vector_inc();
+        self.iter.vector_inc();
+    }
+
+    fn scalar_inc(&mut self) {
+        self.iter.scalar_inc();
+        self.iter.scalar_inc();
+        self.iter.scalar_inc();
+    }
+
+    fn default(&self) -> Self::Vectors {
+        tuplify!(3, self.iter.default())
+    }
+
+    fn finalize(&mut self) {
+        self.peeked = 0;
+        self.iter.finalize();
+    }
+}
+
+impl<T> SIMDZippedIterable for StrideZip4<T> where T : SIMDIterator, T::Vector : Destride {
+    fn scalar_pos(&self) -> usize {
+        (self.iter.scalar_pos() - self.base) / 4
+    }
+
+    fn vector_pos(&self) -> usize {
+        (self.iter.vector_pos() - (self.base / self.width())) / 4
+    }
+
+    fn vector_inc(&mut self) {
+        self.iter.vector_inc();
+        self.iter.vector_inc();
+        self.iter.vector_inc();
+        self.iter.vector_inc();
+    }
+
+    fn scalar_inc(&mut self) {
+        self.iter.scalar_inc();
+        self.iter.scalar_inc();
+        self.iter.scalar_inc();
+        self.iter.scalar_inc();
+    }
+
+    fn default(&self) -> Self::Vectors {
+        tuplify!(4, self.iter.default())
+    }
+
+    fn finalize(&mut self) {
+        self.peeked = 0;
+        self.iter.finalize();
+    }
+}
+
+impl<T> Iterator for StrideZip3<T> where T : SIMDIterator, T::Vector : Destride {
+    type Item = <Self as SIMDZippedObject>::Vectors;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Hold on to any vectors which don't form a whole group, so end() can
+        // destride them with the partial vector
+        while self.peeked < 3 {
+            self.peek[self.peeked] = self.iter.next()?;
+            self.peeked += 1;
+        }
+        self.peeked = 0;
+        Some(self.peek[0].destride_three(self.peek[1], self.peek[2]))
+    }
+}
+
+impl<T> Iterator for StrideZip4<T> where T : SIMDIterator, T::Vector : Destride {
+    type Item = <Self as SIMDZippedObject>::Vectors;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Hold on to any vectors which don't form a whole group, so end() can
+        // destride them with the partial vector
+        while self.peeked < 4 {
+            self.peek[self.peeked] = self.iter.next()?;
+            self.peeked += 1;
+        }
+        self.peeked = 0;
+        Some(self.peek[0].destride_four(self.peek[1], self.peek[2], self.peek[3]))
+    }
+}
+
+impl<T> SIMDZippedIterator for StrideZip3<T> where T : SIMDIterator, T::Vector : Destride {
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        let last = self.iter.end();
+        let mut ret = [self.iter.default(); 3];
+        let empty_amt = destride_end(&self.peek[..self.peeked], last, &mut ret);
+        self.peeked = 0;
+        Some(((ret[0], ret[1], ret[2]), empty_amt?))
+    }
+}
+
+impl<T> SIMDZippedIterator for StrideZip4<T> where T : SIMDIterator, T::Vector : Destride {
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        let last = self.iter.end();
+        let mut ret = [self.iter.default(); 4];
+        let empty_amt = destride_end(&self.peek[..self.peeked], last, &mut ret);
+        self.peeked = 0;
+        Some(((ret[0], ret[1], ret[2], ret[3]), empty_amt?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_zip_end() {
+        for len in 0..150 {
+            let data = (0..len).map(|i| i as u32 + 1).collect::<Vec<u32>>();
+            let sum = |count: usize, channel: usize| -> u32 {
+                // Elements after the last complete group aren't yielded
+                data.iter().take(len / count * count).enumerate()
+                    .filter(|&(i, _)| i % count == channel).map(|(_, x)| x).sum()
+            };
+
+            let (a, b) = data.simd_iter(u32s(0)).stride_zip()
+                .simd_reduce((u32s(0), u32s(0)), |(aa, ab), (a, b)| (aa + a, ab + b));
+            assert_eq!((a.sum(), b.sum()), (sum(2, 0), sum(2, 1)));
+
+            let (a, b, c) = data.simd_iter(u32s(0)).stride_zip3()
+                .simd_reduce((u32s(0), u32s(0), u32s(0)), |(aa, ab, ac), (a, b, c)| (aa + a, ab + b, ac + c));
+            assert_eq!((a.sum(), b.sum(), c.sum()), (sum(3, 0), sum(3, 1), sum(3, 2)));
+
+            let (a, b, c, d) = data.simd_iter(u32s(0)).stride_zip4()
+                .simd_reduce((u32s(0), u32s(0), u32s(0), u32s(0)), |(aa, ab, ac, ad), (a, b, c, d)| (aa + a, ab + b, ac + c, ad + d));
+            assert_eq!((a.sum(), b.sum(), c.sum(), d.sum()), (sum(4, 0), sum(4, 1), sum(4, 2), sum(4, 3)));
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn stride_zip4_collect() {
+        let rgba = (0..403).map(|i| (i % 4) as u8 * 10 + 1).collect::<Vec<u8>>();
+        let alpha = rgba.simd_iter(u8s(0)).stride_zip4()
+            .simd_map(|(_, _, _, a)| a)
+            .scalar_collect();
+        // The final, incomplete pixel isn't yielded
+        assert_eq!(alpha, vec![31u8; 100]);
+    }
 }
@@ -0,0 +1,308 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use iters::{SIMDArray, SIMDIterator, SIMDIterable, SIMDObject, UnsafeIterator};
+use vecs::{Packed, Packable};
+use intrin::Widen;
+use zip::{SIMDZippedIterable, SIMDZippedIterator, SIMDZippedObject};
+
+/// A lazy iterator which zips an iterator of narrow elements with an iterator
+/// of wide elements. Each vector of the narrow iterator is upcast into several
+/// vectors of type `W`, which are as wide as the vectors of the wide iterator.
+pub struct UpcastZip<T, W> {
+    iters: T,
+    pos: usize,
+    buf: [W; 8],
+    cursor: usize,
+    ended: bool,
+}
+
+/// A trait which can transform a pair of iterators of different widths into
+/// an `UpcastZip`.
+pub trait IntoUpcastZip<A, B> : Sized {
+    /// Return an iterator which may iterate over the narrow iterator `A` and
+    /// the wide iterator `B` in lockstep, upcasting the vectors of `A` into
+    /// vectors of `W`. `W` must be as wide as the vectors of `B`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let pixels = [200u8; 100];
+    /// let weights = [0.5f32; 100];
+    /// let weighted = (pixels.simd_iter(u8s(0)), weights.simd_iter(f32s(0.0)))
+    ///     .upcast_zip::<f32s>()
+    ///     .simd_map(|(p, w)| p * w)
+    ///     .scalar_collect();
+    /// assert_eq!(weighted, vec![100.0f32; 100]);
+    /// # }
+    /// ```
+    fn upcast_zip<W>(self) -> UpcastZip<(A, B), W>
+        where A : SIMDIterator + SIMDArray, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed;
+}
+
+impl<A, B> IntoUpcastZip<A, B> for (A, B) {
+    #[inline(always)]
+    fn upcast_zip<W>(self) -> UpcastZip<(A, B), W>
+        where A : SIMDIterator + SIMDArray, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed {
+        let ratio = A::Vector::WIDTH / W::WIDTH;
+        assert!(W::WIDTH == B::Vector::WIDTH,
+                "You can only upcast into vectors as wide as the zipped iterator.");
+        assert!(ratio <= 8);
+        if self.0.scalar_len() - self.0.scalar_pos() != self.1.scalar_len() - self.1.scalar_pos() {
+            panic!("You can only zip iterators of the same length.");
+        }
+        UpcastZip {
+            pos: self.1.scalar_pos(),
+            buf: [W::default(); 8],
+            cursor: A::Vector::WIDTH,
+            ended: false,
+            iters: self,
+        }
+    }
+}
+
+impl<A, B, W> UpcastZip<(A, B), W>
+    where A : SIMDIterator, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed {
+    /// Upcast the next vector of the narrow iterator into the buffer. Once no
+    /// whole vectors remain, the partial vector is upcast instead, and its
+    /// elements are left right-aligned in the buffer.
+    #[inline(always)]
+    fn refill(&mut self) {
+        debug_assert!(!self.ended);
+        if let Some(v) = self.iters.0.next() {
+            v.widen(&mut self.buf);
+            self.cursor = 0;
+        } else if let Some((v, n)) = self.iters.0.end() {
+            v.widen(&mut self.buf);
+            self.cursor = n;
+            self.ended = true;
+        }
+    }
+
+    /// Return the next element of the narrow iterator, upcast into `W`. The
+    /// caller must ensure that an element remains.
+    #[inline(always)]
+    unsafe fn next_scalar(&mut self) -> W::Scalar {
+        if self.cursor == A::Vector::WIDTH {
+            self.refill();
+        }
+        let ret = self.buffered(self.cursor);
+        self.cursor += 1;
+        ret
+    }
+
+    /// Return the amount of scalars left in this iterator.
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        self.iters.1.scalar_len() - self.pos
+    }
+
+    /// Return the `n`th element of the buffer.
+    #[inline(always)]
+    unsafe fn buffered(&self, n: usize) -> W::Scalar {
+        self.buf[n / W::WIDTH].extract_unchecked(n % W::WIDTH)
+    }
+
+    /// Return the default vector of the narrow iterator, upcast into `W`.
+    #[inline(always)]
+    fn default_chunk(&self) -> W {
+        let mut ret = [W::default(); 8];
+        self.iters.0.default().widen(&mut ret);
+        ret[0]
+    }
+}
+
+impl<A, B, W> ExactSizeIterator for UpcastZip<(A, B), W>
+    where A : SIMDIterator, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.remaining() / W::WIDTH
+    }
+}
+
+impl<A, B, W> Iterator for UpcastZip<(A, B), W>
+    where A : SIMDIterator, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed {
+    type Item = (W, B::Vector);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = W::WIDTH;
+        if self.remaining() < width {
+            // Only a partial vector is left; end() will yield it
+            return None;
+        }
+
+        if self.cursor == A::Vector::WIDTH {
+            self.refill();
+        }
+
+        let a = if self.cursor % width == 0 {
+            self.cursor += width;
+            self.buf[self.cursor / width - 1]
+        } else {
+            // The buffer isn't aligned to our width, either because the
+            // partial vector of the narrow iterator is right-aligned or
+            // because we were advanced by scalars, so realign its elements
+            let mut ret = W::default();
+            for i in 0..width {
+                unsafe {
+                    ret = ret.replace_unchecked(i, self.next_scalar());
+                }
+            }
+            ret
+        };
+        let b = unsafe { self.iters.1.next_unchecked(self.pos) };
+        self.pos += width;
+        Some((a, b))
+    }
+}
+
+impl<A, B, W> SIMDZippedObject for UpcastZip<(A, B), W>
+    where A : SIMDIterator, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed {
+    type Vectors = (W, B::Vector);
+    type Scalars = (W::Scalar, B::Scalar);
+
+    #[inline(always)]
+    fn width(&self) -> usize {
+        W::WIDTH
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        W::Scalar::SIZE
+    }
+}
+
+impl<A, B, W> SIMDZippedIterable for UpcastZip<(A, B), W>
+    where A : SIMDIterator, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.pos / self.width()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.next();
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        if self.remaining() > 0 {
+            unsafe { self.next_scalar(); }
+            self.pos += 1;
+        }
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vectors {
+        (self.default_chunk(), self.iters.1.default())
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iters.0.finalize();
+        self.cursor = A::Vector::WIDTH;
+        self.ended = true;
+        self.pos = self.iters.1.scalar_len();
+    }
+}
+
+impl<A, B, W> SIMDZippedIterator for UpcastZip<(A, B), W>
+    where A : SIMDIterator, A::Vector : Widen<W>, B : SIMDIterator + UnsafeIterator + SIMDArray, W : Packed {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        let remaining = self.remaining();
+        if remaining == 0 {
+            return None;
+        }
+
+        debug_assert!(remaining < W::WIDTH);
+        let empty_amt = W::WIDTH - remaining;
+        let mut a = self.default_chunk();
+        for i in 0..remaining {
+            unsafe {
+                a = a.replace_unchecked(empty_amt + i, self.next_scalar());
+            }
+        }
+        let b = unsafe { self.iters.1.end_unchecked(self.pos, empty_amt) };
+        self.pos += remaining;
+        Some(((a, b), empty_amt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn upcast_zip_u8_f32() {
+        for len in 0..200 {
+            let pixels = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+            let weights = (0..len).map(|i| i as f32 * 0.5).collect::<Vec<f32>>();
+            let weighted = (pixels.simd_iter(u8s(0)), weights.simd_iter(f32s(0.0)))
+                .upcast_zip::<f32s>()
+                .simd_map(|(p, w)| p * w)
+                .scalar_collect();
+            let expected = pixels.iter().zip(weights.iter())
+                .map(|(&p, &w)| p as f32 * w)
+                .collect::<Vec<f32>>();
+            assert_eq!(weighted, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn upcast_zip_i16_i32() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| -(i as i16)).collect::<Vec<i16>>();
+            let b = (0..len).map(|i| i as i32 * 1000).collect::<Vec<i32>>();
+            let sum = (a.simd_iter(i16s(0)), b.simd_iter(i32s(0)))
+                .upcast_zip::<i32s>()
+                .simd_reduce(i32s(0), |acc, (a, b)| acc + a + b)
+                .sum();
+            assert_eq!(sum, (0..len).map(|i| i * 1000 - i).sum::<i32>());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn upcast_zip_mismatched_tail() {
+        (vec![1u8; 100].simd_iter(u8s(0)), vec![1.0f32; 97].simd_iter(f32s(0.0)))
+            .upcast_zip::<f32s>();
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn upcast_zip_scalar_inc() {
+        for len in 0..100 {
+            for skip in 0..len.min(40) {
+                let pixels = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+                let weights = (0..len).map(|i| i as f32).collect::<Vec<f32>>();
+                let mut iter = (pixels.simd_iter(u8s(0)), weights.simd_iter(f32s(0.0)))
+                    .upcast_zip::<f32s>();
+                for _ in 0..skip {
+                    iter.scalar_inc();
+                }
+                assert_eq!(iter.scalar_pos(), skip);
+                assert_eq!(iter.len(), (len - skip) / f32s::WIDTH);
+                let products = iter.simd_map(|(p, w)| p * w).scalar_collect();
+                let expected = (skip..len).map(|i| (i as u8) as f32 * i as f32).collect::<Vec<f32>>();
+                assert_eq!(products, expected);
+            }
+        }
+    }
+}
@@ -21,6 +21,59 @@ pub trait Pattern : Packed {
     /// index.
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self;
 
+    /// Return a vector whose element at index `n` is `start + step * n`.
+    /// Integer vectors wrap on overflow.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::ramp(10, 2), u32x4::new(10, 12, 14, 16));
+    /// assert_eq!(f64x2::ramp(1.0, 0.5), f64x2::new(1.0, 1.5));
+    /// # }
+    /// ```
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self;
+
+    /// Return a vector whose element at index `n` is `func(n)`. `func` is
+    /// called once for each index, in ascending order.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16x8::from_fn(|i| 1 << i), u16x8::new(1, 2, 4, 8, 16, 32, 64, 128));
+    /// # }
+    /// ```
+    fn from_fn<F>(func: F) -> Self where F : FnMut(usize) -> Self::Scalar;
+
+    /// Return a vector containing the elements of `data`, which must contain
+    /// exactly `Self::WIDTH` elements.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let table = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    /// assert_eq!(u8x16::from_slice(&table).extract(3), 3);
+    /// # }
+    /// ```
+    fn from_slice(data: &[Self::Scalar]) -> Self;
+
+    /// Return an iterator over the elements of this vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32x4::new(1, 2, 3, 4).lanes().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    /// # }
+    /// ```
+    fn lanes(self) -> Lanes<Self>;
+
     /// Return a vector whose first `off` elements are `hi`, and whose last
     /// `Self::WIDTH - off` elements are `lo`.
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self;
@@ -36,6 +89,63 @@ pub trait Pattern : Packed {
     fn zeroes() -> Self;
 }
 
+/// An iterator over the elements of a vector.
+///
+/// The vectors of `stdsimd` are foreign types, so the orphan rule forbids
+/// this crate from implementing `IntoIterator`, `Index` or array conversions
+/// such as `From<[T; N]>` for them. Use `Pattern::lanes`, `Packed::extract`,
+/// `Packed::from_array` and `Packed::to_array` instead, which work for every
+/// vector. The vectors defined by this crate also implement `IntoIterator`
+/// and the `From` conversions to and from arrays.
+#[derive(Debug, Clone)]
+pub struct Lanes<T> where T : Packed {
+    vec: T,
+    front: usize,
+    back: usize,
+}
+
+impl<T> Lanes<T> where T : Packed {
+    /// Return an iterator over the elements of `vec`.
+    #[inline(always)]
+    pub fn new(vec: T) -> Self {
+        Lanes { vec: vec, front: 0, back: T::WIDTH }
+    }
+}
+
+impl<T> Iterator for Lanes<T> where T : Packed {
+    type Item = T::Scalar;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let ret = unsafe { self.vec.extract_unchecked(self.front) };
+            self.front += 1;
+            Some(ret)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<T> DoubleEndedIterator for Lanes<T> where T : Packed {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { self.vec.extract_unchecked(self.back) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Lanes<T> where T : Packed {}
+
 const PART_MASK: [u8; 128] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                               0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                               0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -64,6 +174,27 @@ impl Pattern for u8x64 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)), start.wrapping_add(step.wrapping_mul(32)), start.wrapping_add(step.wrapping_mul(33)), start.wrapping_add(step.wrapping_mul(34)), start.wrapping_add(step.wrapping_mul(35)), start.wrapping_add(step.wrapping_mul(36)), start.wrapping_add(step.wrapping_mul(37)), start.wrapping_add(step.wrapping_mul(38)), start.wrapping_add(step.wrapping_mul(39)), start.wrapping_add(step.wrapping_mul(40)), start.wrapping_add(step.wrapping_mul(41)), start.wrapping_add(step.wrapping_mul(42)), start.wrapping_add(step.wrapping_mul(43)), start.wrapping_add(step.wrapping_mul(44)), start.wrapping_add(step.wrapping_mul(45)), start.wrapping_add(step.wrapping_mul(46)), start.wrapping_add(step.wrapping_mul(47)), start.wrapping_add(step.wrapping_mul(48)), start.wrapping_add(step.wrapping_mul(49)), start.wrapping_add(step.wrapping_mul(50)), start.wrapping_add(step.wrapping_mul(51)), start.wrapping_add(step.wrapping_mul(52)), start.wrapping_add(step.wrapping_mul(53)), start.wrapping_add(step.wrapping_mul(54)), start.wrapping_add(step.wrapping_mul(55)), start.wrapping_add(step.wrapping_mul(56)), start.wrapping_add(step.wrapping_mul(57)), start.wrapping_add(step.wrapping_mul(58)), start.wrapping_add(step.wrapping_mul(59)), start.wrapping_add(step.wrapping_mul(60)), start.wrapping_add(step.wrapping_mul(61)), start.wrapping_add(step.wrapping_mul(62)), start.wrapping_add(step.wrapping_mul(63)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15), func(16), func(17), func(18), func(19), func(20), func(21), func(22), func(23), func(24), func(25), func(26), func(27), func(28), func(29), func(30), func(31), func(32), func(33), func(34), func(35), func(36), func(37), func(38), func(39), func(40), func(41), func(42), func(43), func(44), func(45), func(46), func(47), func(48), func(49), func(50), func(51), func(52), func(53), func(54), func(55), func(56), func(57), func(58), func(59), func(60), func(61), func(62), func(63))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -72,13 +203,13 @@ impl Pattern for u8x64 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -173,6 +304,27 @@ impl Pattern for u8x32 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15), func(16), func(17), func(18), func(19), func(20), func(21), func(22), func(23), func(24), func(25), func(26), func(27), func(28), func(29), func(30), func(31))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -181,13 +333,13 @@ impl Pattern for u8x32 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -250,6 +402,27 @@ impl Pattern for u8x16 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -258,13 +431,13 @@ impl Pattern for u8x16 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -311,6 +484,27 @@ impl Pattern for i8x64 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)), start.wrapping_add(step.wrapping_mul(32)), start.wrapping_add(step.wrapping_mul(33)), start.wrapping_add(step.wrapping_mul(34)), start.wrapping_add(step.wrapping_mul(35)), start.wrapping_add(step.wrapping_mul(36)), start.wrapping_add(step.wrapping_mul(37)), start.wrapping_add(step.wrapping_mul(38)), start.wrapping_add(step.wrapping_mul(39)), start.wrapping_add(step.wrapping_mul(40)), start.wrapping_add(step.wrapping_mul(41)), start.wrapping_add(step.wrapping_mul(42)), start.wrapping_add(step.wrapping_mul(43)), start.wrapping_add(step.wrapping_mul(44)), start.wrapping_add(step.wrapping_mul(45)), start.wrapping_add(step.wrapping_mul(46)), start.wrapping_add(step.wrapping_mul(47)), start.wrapping_add(step.wrapping_mul(48)), start.wrapping_add(step.wrapping_mul(49)), start.wrapping_add(step.wrapping_mul(50)), start.wrapping_add(step.wrapping_mul(51)), start.wrapping_add(step.wrapping_mul(52)), start.wrapping_add(step.wrapping_mul(53)), start.wrapping_add(step.wrapping_mul(54)), start.wrapping_add(step.wrapping_mul(55)), start.wrapping_add(step.wrapping_mul(56)), start.wrapping_add(step.wrapping_mul(57)), start.wrapping_add(step.wrapping_mul(58)), start.wrapping_add(step.wrapping_mul(59)), start.wrapping_add(step.wrapping_mul(60)), start.wrapping_add(step.wrapping_mul(61)), start.wrapping_add(step.wrapping_mul(62)), start.wrapping_add(step.wrapping_mul(63)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15), func(16), func(17), func(18), func(19), func(20), func(21), func(22), func(23), func(24), func(25), func(26), func(27), func(28), func(29), func(30), func(31), func(32), func(33), func(34), func(35), func(36), func(37), func(38), func(39), func(40), func(41), func(42), func(43), func(44), func(45), func(46), func(47), func(48), func(49), func(50), func(51), func(52), func(53), func(54), func(55), func(56), func(57), func(58), func(59), func(60), func(61), func(62), func(63))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -319,13 +513,13 @@ impl Pattern for i8x64 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -420,6 +614,27 @@ impl Pattern for i8x32 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15), func(16), func(17), func(18), func(19), func(20), func(21), func(22), func(23), func(24), func(25), func(26), func(27), func(28), func(29), func(30), func(31))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -428,13 +643,13 @@ impl Pattern for i8x32 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -497,6 +712,27 @@ impl Pattern for i8x16 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -505,13 +741,13 @@ impl Pattern for i8x16 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -558,6 +794,27 @@ impl Pattern for u16x32 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15), func(16), func(17), func(18), func(19), func(20), func(21), func(22), func(23), func(24), func(25), func(26), func(27), func(28), func(29), func(30), func(31))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -566,13 +823,13 @@ impl Pattern for u16x32 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -635,6 +892,27 @@ impl Pattern for u16x16 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -643,13 +921,13 @@ impl Pattern for u16x16 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -696,6 +974,27 @@ impl Pattern for u16x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -704,13 +1003,13 @@ impl Pattern for u16x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -749,6 +1048,27 @@ impl Pattern for i16x32 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15), func(16), func(17), func(18), func(19), func(20), func(21), func(22), func(23), func(24), func(25), func(26), func(27), func(28), func(29), func(30), func(31))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -757,13 +1077,13 @@ impl Pattern for i16x32 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -826,6 +1146,27 @@ impl Pattern for i16x16 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -834,13 +1175,13 @@ impl Pattern for i16x16 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -887,6 +1228,27 @@ impl Pattern for i16x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -895,13 +1257,13 @@ impl Pattern for i16x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -940,6 +1302,27 @@ impl Pattern for u32x16 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -948,13 +1331,13 @@ impl Pattern for u32x16 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1001,6 +1384,27 @@ impl Pattern for u32x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1009,13 +1413,13 @@ impl Pattern for u32x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1054,6 +1458,27 @@ impl Pattern for u32x4 {
         Self::new(hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1062,13 +1487,13 @@ impl Pattern for u32x4 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1103,6 +1528,27 @@ impl Pattern for i32x16 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1111,13 +1557,13 @@ impl Pattern for i32x16 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1164,6 +1610,27 @@ impl Pattern for i32x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1172,13 +1639,13 @@ impl Pattern for i32x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1217,6 +1684,27 @@ impl Pattern for i32x4 {
         Self::new(hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1225,13 +1713,13 @@ impl Pattern for i32x4 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1266,6 +1754,27 @@ impl Pattern for f32x16 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step * 1.0, start + step * 2.0, start + step * 3.0, start + step * 4.0, start + step * 5.0, start + step * 6.0, start + step * 7.0, start + step * 8.0, start + step * 9.0, start + step * 10.0, start + step * 11.0, start + step * 12.0, start + step * 13.0, start + step * 14.0, start + step * 15.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7), func(8), func(9), func(10), func(11), func(12), func(13), func(14), func(15))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1274,13 +1783,13 @@ impl Pattern for f32x16 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1327,6 +1836,27 @@ impl Pattern for f32x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step * 1.0, start + step * 2.0, start + step * 3.0, start + step * 4.0, start + step * 5.0, start + step * 6.0, start + step * 7.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1335,13 +1865,13 @@ impl Pattern for f32x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1380,6 +1910,27 @@ impl Pattern for f32x4 {
         Self::new(hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step * 1.0, start + step * 2.0, start + step * 3.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1388,13 +1939,13 @@ impl Pattern for f32x4 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1429,6 +1980,27 @@ impl Pattern for u64x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1437,13 +2009,13 @@ impl Pattern for u64x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1482,6 +2054,27 @@ impl Pattern for u64x4 {
         Self::new(hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1490,13 +2083,13 @@ impl Pattern for u64x4 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1531,6 +2124,27 @@ impl Pattern for u64x2 {
         Self::new(hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1539,13 +2153,13 @@ impl Pattern for u64x2 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1578,6 +2192,27 @@ impl Pattern for i64x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1586,13 +2221,13 @@ impl Pattern for i64x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1631,6 +2266,27 @@ impl Pattern for i64x4 {
         Self::new(hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1639,13 +2295,13 @@ impl Pattern for i64x4 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1680,6 +2336,27 @@ impl Pattern for i64x2 {
         Self::new(hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step.wrapping_mul(1)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1688,13 +2365,13 @@ impl Pattern for i64x2 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1727,6 +2404,27 @@ impl Pattern for f64x8 {
         Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step * 1.0, start + step * 2.0, start + step * 3.0, start + step * 4.0, start + step * 5.0, start + step * 6.0, start + step * 7.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3), func(4), func(5), func(6), func(7))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1735,13 +2433,13 @@ impl Pattern for f64x8 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx512-notyet")]
+    #[cfg(all(target_feature = "avx512-notyet", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm512_mask_mov_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx512-notyet"))]
+    #[cfg(any(not(target_feature = "avx512-notyet"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1780,6 +2478,27 @@ impl Pattern for f64x4 {
         Self::new(hi, lo, hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step * 1.0, start + step * 2.0, start + step * 3.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1), func(2), func(3))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1788,13 +2507,13 @@ impl Pattern for f64x4 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "avx2")]
+    #[cfg(all(target_feature = "avx2", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm256_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "avx2"))]
+    #[cfg(any(not(target_feature = "avx2"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
@@ -1829,6 +2548,27 @@ impl Pattern for f64x2 {
         Self::new(hi, lo)
     }
 
+    #[inline(always)]
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step * 1.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {
+        Self::new(func(0), func(1))
+    }
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {
+        Lanes::new(self)
+    }
+
     #[inline(always)]
     fn partition_mask(off: usize) -> Self {
         debug_assert!(off <= Self::WIDTH);
@@ -1837,13 +2577,13 @@ impl Pattern for f64x2 {
     }
 
     #[inline(always)]
-    #[cfg(target_feature = "sse4.1")]
+    #[cfg(all(target_feature = "sse4.1", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         unsafe { transmute(_mm_blendv_epi8(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
     }
 
     #[inline(always)]
-    #[cfg(not(target_feature = "sse4.1"))]
+    #[cfg(any(not(target_feature = "sse4.1"), feature = "force-scalar"))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
         assert!(off <= Self::WIDTH);
         match off {
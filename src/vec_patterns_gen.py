@@ -41,6 +41,59 @@ pub trait Pattern : Packed {
     /// index.
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self;
 
+    /// Return a vector whose element at index `n` is `start + step * n`.
+    /// Integer vectors wrap on overflow.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::ramp(10, 2), u32x4::new(10, 12, 14, 16));
+    /// assert_eq!(f64x2::ramp(1.0, 0.5), f64x2::new(1.0, 1.5));
+    /// # }
+    /// ```
+    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self;
+
+    /// Return a vector whose element at index `n` is `func(n)`. `func` is
+    /// called once for each index, in ascending order.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16x8::from_fn(|i| 1 << i), u16x8::new(1, 2, 4, 8, 16, 32, 64, 128));
+    /// # }
+    /// ```
+    fn from_fn<F>(func: F) -> Self where F : FnMut(usize) -> Self::Scalar;
+
+    /// Return a vector containing the elements of `data`, which must contain
+    /// exactly `Self::WIDTH` elements.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let table = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    /// assert_eq!(u8x16::from_slice(&table).extract(3), 3);
+    /// # }
+    /// ```
+    fn from_slice(data: &[Self::Scalar]) -> Self;
+
+    /// Return an iterator over the elements of this vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i32x4::new(1, 2, 3, 4).lanes().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    /// # }
+    /// ```
+    fn lanes(self) -> Lanes<Self>;
+
     /// Return a vector whose first `off` elements are `hi`, and whose last
     /// `Self::WIDTH - off` elements are `lo`.
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self;
@@ -56,6 +109,63 @@ pub trait Pattern : Packed {
     fn zeroes() -> Self;
 }
 
+/// An iterator over the elements of a vector.
+///
+/// The vectors of `stdsimd` are foreign types, so the orphan rule forbids
+/// this crate from implementing `IntoIterator`, `Index` or array conversions
+/// such as `From<[T; N]>` for them. Use `Pattern::lanes`, `Packed::extract`,
+/// `Packed::from_array` and `Packed::to_array` instead, which work for every
+/// vector. The vectors defined by this crate also implement `IntoIterator`
+/// and the `From` conversions to and from arrays.
+#[derive(Debug, Clone)]
+pub struct Lanes<T> where T : Packed {
+    vec: T,
+    front: usize,
+    back: usize,
+}
+
+impl<T> Lanes<T> where T : Packed {
+    /// Return an iterator over the elements of `vec`.
+    #[inline(always)]
+    pub fn new(vec: T) -> Self {
+        Lanes { vec: vec, front: 0, back: T::WIDTH }
+    }
+}
+
+impl<T> Iterator for Lanes<T> where T : Packed {
+    type Item = T::Scalar;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let ret = unsafe { self.vec.extract_unchecked(self.front) };
+            self.front += 1;
+            Some(ret)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<T> DoubleEndedIterator for Lanes<T> where T : Packed {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { self.vec.extract_unchecked(self.back) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Lanes<T> where T : Packed {}
+
 const PART_MASK: [u8; 128] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                               0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                               0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -89,8 +199,37 @@ for e, v, l, f, b, s, m in zip(els, vecs, lens, feats, blends, elsz, masks):
     print(f"    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {{")
     args = ", ".join("hi, lo" for _ in range(l // 2))
     print(f"        Self::new({args})")
+    print(f"    }}\n")
+
+    # Generate ramp
+    print(f"    #[inline(always)]")
+    print(f"    fn ramp(start: Self::Scalar, step: Self::Scalar) -> Self {{")
+    if e.startswith("f"):
+        args = ", ".join(["start"] + [f"start + step * {i}.0" for i in range(1, l)])
+    else:
+        args = ", ".join(["start"] + [f"start.wrapping_add(step.wrapping_mul({i}))" for i in range(1, l)])
+    print(f"        Self::new({args})")
     print(f"    }}")
 
+    # Generate from_fn, from_slice & lanes
+    args = ", ".join(f"func({i})" for i in range(l))
+    print(f"""
+    #[inline(always)]
+    fn from_fn<F>(mut func: F) -> Self where F : FnMut(usize) -> Self::Scalar {{
+        Self::new({args})
+    }}
+
+    #[inline(always)]
+    fn from_slice(data: &[Self::Scalar]) -> Self {{
+        assert_eq!(data.len(), Self::WIDTH);
+        Self::load(data, 0)
+    }}
+
+    #[inline(always)]
+    fn lanes(self) -> Lanes<Self> {{
+        Lanes::new(self)
+    }}""")
+
     # Generate partition_mask
     print(f"""
     #[inline(always)]
@@ -103,7 +242,7 @@ for e, v, l, f, b, s, m in zip(els, vecs, lens, feats, blends, elsz, masks):
     # Generate partition polyfill
     print(f"""
     #[inline(always)]
-    #[cfg(target_feature = "{f}")]
+    #[cfg(all(target_feature = "{f}", not(feature = "force-scalar")))]
     fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {{
         unsafe {{ transmute({b}(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }}
     }}
@@ -111,7 +250,7 @@ for e, v, l, f, b, s, m in zip(els, vecs, lens, feats, blends, elsz, masks):
 
     # Generate partition polyfill
     print(f"    #[inline(always)]")
-    print(f"    #[cfg(not(target_feature = \"{f}\"))]")
+    print(f"    #[cfg(any(not(target_feature = \"{f}\"), feature = \"force-scalar\"))]")
     print(f"    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {{")
     print(f"        assert!(off <= Self::WIDTH);")
     print(f"        match off {{")
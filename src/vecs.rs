@@ -5,9 +5,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-#[cfg(target_feature = "sse")]
+#[cfg(all(target_feature = "sse", not(feature = "force-scalar")))]
 pub use stdsimd::simd::{u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2};
-#[cfg(not(target_feature = "sse"))]
+#[cfg(any(not(target_feature = "sse"), feature = "force-scalar"))]
 pub use shimvecs::{u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2};
 pub use vec_patterns::Pattern;
 use core_or_std::fmt::Debug;
@@ -18,6 +18,13 @@ pub trait Packed : Sized + Copy + Debug + Merge {
     /// The type which fits into this SIMD vector
     type Scalar : Packable;
 
+    /// An array which holds `Self::WIDTH` elements of `Self::Scalar`.
+    ///
+    /// This associated type is required, so it is a breaking change for
+    /// crates which implement `Packed` for their own vectors; they must name
+    /// the array type of their vectors.
+    type Array : Copy;
+
     /// The number of elements in this vector
     const WIDTH: usize;
 
@@ -47,6 +54,31 @@ pub trait Packed : Sized + Copy + Debug + Merge {
     /// element. Opposite operation of `Self::splat`.
     fn coalesce(self) -> Self::Scalar;
 
+    /// Return a vector containing the elements of `data`, in order.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32x4::from_array([1, 2, 3, 4]), u32x4::new(1, 2, 3, 4));
+    /// # }
+    /// ```
+    fn from_array(data: Self::Array) -> Self;
+
+    /// Return an array containing the elements of this vector, in order.
+    /// Opposite operation of `Self::from_array`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64x2::new(1.0, 2.0).to_array(), [1.0, 2.0]);
+    /// # }
+    /// ```
+    fn to_array(self) -> Self::Array;
+
     /// Return a vector with all elements initialized to `data`. Opposite
     /// operation for `Self::coalesce`.
     fn splat(data: Self::Scalar) -> Self;
@@ -106,6 +138,7 @@ macro_rules! impl_packed {
 
         impl Packed for $vec {
             type Scalar = $el;
+            type Array = [$el; $width];
 
             const WIDTH: usize = $width;
 
@@ -161,6 +194,18 @@ macro_rules! impl_packed {
                 $vec::replace_unchecked(*self, idx as u32, data)
             }
 
+            #[inline(always)]
+            fn from_array(data: Self::Array) -> Self {
+                $vec::load(&data, 0)
+            }
+
+            #[inline(always)]
+            fn to_array(self) -> Self::Array {
+                let mut ret = [$el::default(); $width];
+                $vec::store(self, &mut ret, 0);
+                ret
+            }
+
             #[inline(always)]
             fn splat(data: $el) -> Self {
                 $vec::splat(data)
@@ -244,4 +289,26 @@ mod tests {
                   (u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2),
                   (scalar_product_u8x64, scalar_product_u8x32, scalar_product_u8x16, scalar_product_i8x64, scalar_product_i8x32, scalar_product_i8x16, scalar_product_u16x32, scalar_product_u16x16, scalar_product_u16x8, scalar_product_i16x32, scalar_product_i16x16, scalar_product_i16x8, scalar_product_u32x16, scalar_product_u32x8, scalar_product_u32x4, scalar_product_i32x16, scalar_product_i32x8, scalar_product_i32x4, scalar_product_f32x16, scalar_product_f32x8, scalar_product_f32x4, scalar_product_u64x8, scalar_product_u64x4, scalar_product_u64x2, scalar_product_i64x8, scalar_product_i64x4, scalar_product_i64x2, scalar_product_f64x8, scalar_product_f64x4, scalar_product_f64x2),
                   (1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1));
+
+    macro_rules! test_array {
+        (($($vec:tt),*), ($($fn:ident),*)) => (
+            $(
+                #[test]
+                fn $fn() {
+                    let v = $vec::from_fn(|i| i as <$vec as Packed>::Scalar);
+                    let arr = v.to_array();
+                    assert_eq!($vec::from_array(arr), v);
+                    assert_eq!($vec::from_slice(&arr[..]), v);
+                    assert_eq!(v.lanes().len(), $vec::WIDTH);
+                    for (i, el) in v.lanes().enumerate() {
+                        assert_eq!(el, i as <$vec as Packed>::Scalar);
+                        assert_eq!(arr[i], el);
+                    }
+                }
+            )*
+        )
+    }
+
+    test_array!((u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2),
+                (array_u8x64, array_u8x32, array_u8x16, array_i8x64, array_i8x32, array_i8x16, array_u16x32, array_u16x16, array_u16x8, array_i16x32, array_i16x16, array_i16x8, array_u32x16, array_u32x8, array_u32x4, array_i32x16, array_i32x8, array_i32x4, array_f32x16, array_f32x8, array_f32x4, array_u64x8, array_u64x4, array_u64x2, array_i64x8, array_i64x4, array_i64x2, array_f64x8, array_f64x4, array_f64x2));
 }
@@ -0,0 +1,718 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![allow(unused_imports)]
+
+//! Vectors of types which don't have a SIMD vector of their own, and are
+//! stored in a vector of an integer type instead. Pointer-sized integers are
+//! stored in vectors of integers of the same size, booleans are stored as
+//! bytes which are 0 or 1, and 128-bit integers are stored as pairs of 64-bit
+//! integers, with the low half first.
+//!
+//! ```
+//! extern crate faster;
+//! use faster::*;
+//!
+//! # fn main() {
+//! let flags = [true, false, true, true, false];
+//! let set = flags.simd_iter(bools(false))
+//!     .simd_reduce(0, |acc, v| acc + v.to_mask().count_ones());
+//! assert_eq!(set, 3 * 8);
+//! # }
+//! ```
+
+use vecs::*;
+use intrin::*;
+use stdsimd::vendor::*;
+use core_or_std::mem::{size_of, transmute};
+use core_or_std::slice::{from_raw_parts, from_raw_parts_mut};
+use core_or_std::ops::{Add, AddAssign, Sub, SubAssign, Mul, BitAnd, BitOr, BitXor};
+
+/// Reinterpret a slice as a slice of another type which is no larger.
+#[inline(always)]
+unsafe fn cast_slice<T, U>(data: &[T]) -> &[U] {
+    from_raw_parts(data.as_ptr() as *const U, data.len() * size_of::<T>() / size_of::<U>())
+}
+
+/// Reinterpret a slice as a slice of another type which is no larger.
+#[inline(always)]
+unsafe fn cast_slice_mut<T, U>(data: &mut [T]) -> &mut [U] {
+    from_raw_parts_mut(data.as_mut_ptr() as *mut U, data.len() * size_of::<T>() / size_of::<U>())
+}
+
+macro_rules! impl_wrapped_packed {
+    ($el:ident, $pvec:ident, $vec:ident, $inner:ident, $innerel:ident, $width:expr, [$($feat:expr),*], [$($nfeat:expr),*],
+     $x:ident => $to_el:expr, $y:ident => $to_inner:expr, $one:expr, $product:expr) => {
+        /// A SIMD vector of this type, stored as a vector of a primitive of
+        /// the same size.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $vec($inner);
+
+        /// A SIMD vector of this type.
+        #[allow(non_camel_case_types)]
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        pub type $pvec = $vec;
+
+        /// Return a vector of this type with all elements initialized to
+        /// `data`.
+        #[inline(always)]
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        pub fn $pvec(data: $el) -> $pvec {
+            $vec::splat(data)
+        }
+
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        impl Packable for $el {
+            type Vector = $vec;
+            const SIZE: usize = size_of::<$innerel>();
+        }
+
+        impl Packed for $vec {
+            type Scalar = $el;
+            type Array = [$el; $width];
+
+            const WIDTH: usize = $width;
+
+            #[inline(always)]
+            fn load(data: &[$el], offset: usize) -> $vec {
+                $vec($inner::load(unsafe { cast_slice(data) }, offset))
+            }
+
+            #[inline(always)]
+            unsafe fn load_unchecked(data: &[$el], offset: usize) -> $vec {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                $vec($inner::load_unchecked(cast_slice(data), offset))
+            }
+
+            #[inline(always)]
+            fn store(self, data: &mut [$el], offset: usize) {
+                self.0.store(unsafe { cast_slice_mut(data) }, offset);
+            }
+
+            #[inline(always)]
+            unsafe fn store_unchecked(self, data: &mut [$el], offset: usize) {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                self.0.store_unchecked(cast_slice_mut(data), offset);
+            }
+
+            #[inline(always)]
+            fn coalesce(self) -> Self::Scalar {
+                for i in 1..Self::WIDTH {
+                    debug_assert!(self.extract(i - 1) == self.extract(i));
+                }
+                self.extract(0)
+            }
+
+            #[inline(always)]
+            fn extract(&self, idx: usize) -> Self::Scalar {
+                let $x = self.0.extract(idx as u32);
+                $to_el
+            }
+
+            #[inline(always)]
+            unsafe fn extract_unchecked(&self, idx: usize) -> Self::Scalar {
+                debug_assert!(idx < Self::WIDTH);
+                let $x = self.0.extract_unchecked(idx as u32);
+                $to_el
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, idx: usize, data: Self::Scalar) -> Self {
+                let $y = data;
+                $vec(self.0.replace(idx as u32, $to_inner))
+            }
+
+            #[inline(always)]
+            unsafe fn replace_unchecked(&mut self, idx: usize, data: Self::Scalar) -> Self {
+                debug_assert!(idx < Self::WIDTH);
+                let $y = data;
+                $vec(self.0.replace_unchecked(idx as u32, $to_inner))
+            }
+
+            #[inline(always)]
+            fn from_array(data: Self::Array) -> Self {
+                Self::load(&data, 0)
+            }
+
+            #[inline(always)]
+            fn to_array(self) -> Self::Array {
+                let mut ret = [$el::default(); $width];
+                Packed::store(self, &mut ret, 0);
+                ret
+            }
+
+            #[inline(always)]
+            fn splat(data: $el) -> Self {
+                let $y = data;
+                $vec($inner::splat($to_inner))
+            }
+
+            #[inline(always)]
+            fn default() -> Self {
+                $vec($inner::splat(0))
+            }
+
+            #[inline(always)]
+            fn product(&self) -> Self::Scalar {
+                self.scalar_reduce($one, $product)
+            }
+
+            #[inline(always)]
+            fn scalar_reduce<T, F>(&self, mut acc: T, mut func: F) -> T
+            where F: FnMut(T, Self::Scalar) -> T {
+                for i in 0..Self::WIDTH {
+                    acc = func(acc, self.extract(i))
+                }
+                acc
+            }
+        }
+
+        impl Merge for $vec {
+            #[inline(always)]
+            fn merge_halves(&self, other: Self) -> Self {
+                $vec(self.0.merge_halves(other.0))
+            }
+
+            #[inline(always)]
+            fn merge_interleaved(&self, other: Self) -> Self {
+                $vec(self.0.merge_interleaved(other.0))
+            }
+
+            #[inline(always)]
+            fn merge_partitioned(&self, other: Self, offset: usize) -> Self {
+                $vec(self.0.merge_partitioned(other.0, offset))
+            }
+        }
+
+        impl Gather for $vec {
+            #[inline(always)]
+            unsafe fn gather_unchecked(data: &[$el], offset: usize, stride: usize) -> Self {
+                $vec($inner::gather_unchecked(cast_slice(data), offset, stride))
+            }
+        }
+    }
+}
+
+macro_rules! impl_wrapped_int {
+    ($el:ident, $pvec:ident, $vec:ident, $inner:ident, $innerel:ident, $width:expr, [$($feat:expr),*], [$($nfeat:expr),*]) => {
+        impl_wrapped_packed!($el, $pvec, $vec, $inner, $innerel, $width, [$($feat),*], [$($nfeat),*],
+                             x => x as $el, y => y as $innerel, 1 as $el, |acc, x| acc * x);
+
+        impl $vec {
+            /// Return the vector of the same size primitive holding the
+            /// elements of this vector.
+            #[inline(always)]
+            pub fn to_inner(self) -> $inner {
+                self.0
+            }
+
+            /// Return a vector holding the elements of `inner`.
+            #[inline(always)]
+            pub fn from_inner(inner: $inner) -> Self {
+                $vec(inner)
+            }
+        }
+
+        impl_wrapped_ops!($vec, [Add, add, AddAssign, add_assign], [Sub, sub, SubAssign, sub_assign]);
+        impl_wrapped_ops!($vec, [Mul, mul], [BitAnd, bitand], [BitOr, bitor], [BitXor, bitxor]);
+    }
+}
+
+macro_rules! impl_wrapped_ops {
+    ($vec:ident, $([$trait:ident, $fn:ident $(, $atrait:ident, $afn:ident)*]),*) => {
+        $(
+            impl $trait for $vec {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fn(self, other: Self) -> Self {
+                    $vec(self.0.$fn(other.0))
+                }
+            }
+
+            $(
+                impl $atrait for $vec {
+                    #[inline(always)]
+                    fn $afn(&mut self, other: Self) {
+                        *self = self.$fn(other);
+                    }
+                }
+            )*
+        )*
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl_wrapped_int!(usize, usizes, usizex8, u64x8, u64, 8, ["avx512"], ["avx1024"]);
+#[cfg(target_pointer_width = "64")]
+impl_wrapped_int!(usize, usizes, usizex4, u64x4, u64, 4, ["avx2"], ["avx512"]);
+#[cfg(target_pointer_width = "64")]
+impl_wrapped_int!(usize, usizes, usizex2, u64x2, u64, 2, [], ["avx2"]);
+#[cfg(target_pointer_width = "64")]
+impl_wrapped_int!(isize, isizes, isizex8, i64x8, i64, 8, ["avx512"], ["avx1024"]);
+#[cfg(target_pointer_width = "64")]
+impl_wrapped_int!(isize, isizes, isizex4, i64x4, i64, 4, ["avx2"], ["avx512"]);
+#[cfg(target_pointer_width = "64")]
+impl_wrapped_int!(isize, isizes, isizex2, i64x2, i64, 2, [], ["avx2"]);
+
+#[cfg(target_pointer_width = "32")]
+impl_wrapped_int!(usize, usizes, usizex16, u32x16, u32, 16, ["avx512"], ["avx1024"]);
+#[cfg(target_pointer_width = "32")]
+impl_wrapped_int!(usize, usizes, usizex8, u32x8, u32, 8, ["avx2"], ["avx512"]);
+#[cfg(target_pointer_width = "32")]
+impl_wrapped_int!(usize, usizes, usizex4, u32x4, u32, 4, [], ["avx2"]);
+#[cfg(target_pointer_width = "32")]
+impl_wrapped_int!(isize, isizes, isizex16, i32x16, i32, 16, ["avx512"], ["avx1024"]);
+#[cfg(target_pointer_width = "32")]
+impl_wrapped_int!(isize, isizes, isizex8, i32x8, i32, 8, ["avx2"], ["avx512"]);
+#[cfg(target_pointer_width = "32")]
+impl_wrapped_int!(isize, isizes, isizex4, i32x4, i32, 4, [], ["avx2"]);
+
+macro_rules! impl_wrapped_bool {
+    ($vec:ident, $inner:ident, $width:expr, [$($feat:expr),*], [$($nfeat:expr),*]) => {
+        // Elements are always 0 or 1, so storing them to a bool slice is safe
+        impl_wrapped_packed!(bool, bools, $vec, $inner, u8, $width, [$($feat),*], [$($nfeat),*],
+                             x => x != 0, y => y as u8, true, |acc, x| acc && x);
+
+        impl $vec {
+            /// Return a mask in the format of `Eq::eq_mask` whose elements
+            /// are filled with 1s where this vector is true.
+            ///
+            /// ```
+            /// extern crate faster;
+            /// use faster::*;
+            ///
+            /// # fn main() {
+            /// assert_eq!(bools(true).to_mask(), u8s(0xFF));
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn to_mask(self) -> $inner {
+                self.0 * $inner::splat(0xFF)
+            }
+
+            /// Return a vector which is true where the lowest bit of each
+            /// element of `mask` is set.
+            ///
+            /// ```
+            /// extern crate faster;
+            /// use faster::*;
+            ///
+            /// # fn main() {
+            /// assert_eq!(bools::from_mask(u8s(3).eq_mask(u8s(3))), bools(true));
+            /// # }
+            /// ```
+            #[inline(always)]
+            pub fn from_mask(mask: $inner) -> Self {
+                $vec(mask & $inner::splat(1))
+            }
+        }
+    }
+}
+
+impl_wrapped_bool!(boolx64, u8x64, 64, ["avx512"], ["avx1024"]);
+impl_wrapped_bool!(boolx32, u8x32, 32, ["avx2"], ["avx512"]);
+impl_wrapped_bool!(boolx16, u8x16, 16, [], ["avx2"]);
+
+/// Addition and subtraction of vectors of 128-bit integers, which are stored
+/// as pairs of 64-bit integers with the low half first.
+trait WideArith {
+    fn wide_add(self, other: Self) -> Self;
+    fn wide_sub(self, other: Self) -> Self;
+}
+
+macro_rules! wide_arith_fallback {
+    () => {
+        #[inline(always)]
+        fn wide_add(self, other: Self) -> Self {
+            // Carry out of the low half of each element into the high half
+            let mut ret = self;
+            for i in 0..(Self::WIDTH as u32 / 2) {
+                let (lo, carry) = self.extract(i * 2).overflowing_add(other.extract(i * 2));
+                let hi = self.extract(i * 2 + 1)
+                    .wrapping_add(other.extract(i * 2 + 1))
+                    .wrapping_add(carry as u64);
+                ret = ret.replace(i * 2, lo).replace(i * 2 + 1, hi);
+            }
+            ret
+        }
+
+        #[inline(always)]
+        fn wide_sub(self, other: Self) -> Self {
+            // Borrow from the high half of each element into the low half
+            let mut ret = self;
+            for i in 0..(Self::WIDTH as u32 / 2) {
+                let (lo, borrow) = self.extract(i * 2).overflowing_sub(other.extract(i * 2));
+                let hi = self.extract(i * 2 + 1)
+                    .wrapping_sub(other.extract(i * 2 + 1))
+                    .wrapping_sub(borrow as u64);
+                ret = ret.replace(i * 2, lo).replace(i * 2 + 1, hi);
+            }
+            ret
+        }
+    }
+}
+
+macro_rules! impl_wide_arith {
+    ($vec:ident) => {
+        impl WideArith for $vec {
+            wide_arith_fallback!();
+        }
+    };
+    ($vec:ident, $feat:tt, $cmpgt:ident, $bslli:ident) => {
+        impl WideArith for $vec {
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn wide_add(self, other: Self) -> Self {
+                let sum = self + other;
+                // The low half carried if it wrapped around to less than
+                // `self`. x86 only compares signed integers, so flip the sign
+                // bits to compare them as unsigned integers.
+                let bias = $vec::splat(1 << 63);
+                unsafe {
+                    let carry: $vec = transmute($cmpgt(transmute(self ^ bias), transmute(sum ^ bias)));
+                    // Shift each carry into the high half, where subtracting
+                    // the all-ones mask adds one. Each element fills a 128-bit
+                    // lane, so the shift never crosses elements.
+                    let carry: $vec = transmute($bslli(transmute(carry), 8));
+                    sum - carry
+                }
+            }
+
+            #[inline(always)]
+            #[cfg(all(target_feature = $feat, not(feature = "force-scalar")))]
+            fn wide_sub(self, other: Self) -> Self {
+                let diff = self - other;
+                // The low half borrowed if `other` is greater than `self`
+                let bias = $vec::splat(1 << 63);
+                unsafe {
+                    let borrow: $vec = transmute($cmpgt(transmute(other ^ bias), transmute(self ^ bias)));
+                    let borrow: $vec = transmute($bslli(transmute(borrow), 8));
+                    diff + borrow
+                }
+            }
+
+            #[cfg(any(not(target_feature = $feat), feature = "force-scalar"))]
+            wide_arith_fallback!();
+        }
+    }
+}
+
+impl_wide_arith!(u64x2, "sse4.2", _mm_cmpgt_epi64, _mm_slli_si128);
+impl_wide_arith!(u64x4, "avx2", _mm256_cmpgt_epi64, _mm256_slli_si256);
+impl_wide_arith!(u64x8);
+
+macro_rules! impl_wide_packed {
+    ($el:ident, $pvec:ident, $vec:ident, $inner:ident, $width:expr, [$($feat:expr),*], [$($nfeat:expr),*]) => {
+        /// A SIMD vector of 128-bit integers, stored as pairs of 64-bit
+        /// integers with the low half first.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $vec(pub $inner);
+
+        /// A SIMD vector of this primitive type.
+        #[allow(non_camel_case_types)]
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        pub type $pvec = $vec;
+
+        /// Return a vector of this type with all elements initialized to
+        /// `data`.
+        #[inline(always)]
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        pub fn $pvec(data: $el) -> $pvec {
+            $vec::splat(data)
+        }
+
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        impl Packable for $el {
+            type Vector = $vec;
+            const SIZE: usize = 16;
+        }
+
+        impl Packed for $vec {
+            type Scalar = $el;
+            type Array = [$el; $width];
+
+            const WIDTH: usize = $width;
+
+            #[inline(always)]
+            fn load(data: &[$el], offset: usize) -> $vec {
+                $vec($inner::load(unsafe { cast_slice(data) }, offset * 2))
+            }
+
+            #[inline(always)]
+            unsafe fn load_unchecked(data: &[$el], offset: usize) -> $vec {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                $vec($inner::load_unchecked(cast_slice(data), offset * 2))
+            }
+
+            #[inline(always)]
+            fn store(self, data: &mut [$el], offset: usize) {
+                self.0.store(unsafe { cast_slice_mut(data) }, offset * 2);
+            }
+
+            #[inline(always)]
+            unsafe fn store_unchecked(self, data: &mut [$el], offset: usize) {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                self.0.store_unchecked(cast_slice_mut(data), offset * 2);
+            }
+
+            #[inline(always)]
+            fn coalesce(self) -> Self::Scalar {
+                for i in 1..Self::WIDTH {
+                    debug_assert!(self.extract(i - 1) == self.extract(i));
+                }
+                self.extract(0)
+            }
+
+            #[inline(always)]
+            fn extract(&self, idx: usize) -> Self::Scalar {
+                assert!(idx < Self::WIDTH);
+                unsafe { self.extract_unchecked(idx) }
+            }
+
+            #[inline(always)]
+            unsafe fn extract_unchecked(&self, idx: usize) -> Self::Scalar {
+                debug_assert!(idx < Self::WIDTH);
+                let lo = self.0.extract_unchecked(idx as u32 * 2);
+                let hi = self.0.extract_unchecked(idx as u32 * 2 + 1);
+                (((hi as u128) << 64) | lo as u128) as $el
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, idx: usize, data: Self::Scalar) -> Self {
+                assert!(idx < Self::WIDTH);
+                unsafe { self.replace_unchecked(idx, data) }
+            }
+
+            #[inline(always)]
+            unsafe fn replace_unchecked(&mut self, idx: usize, data: Self::Scalar) -> Self {
+                debug_assert!(idx < Self::WIDTH);
+                let data = data as u128;
+                $vec(self.0.replace_unchecked(idx as u32 * 2, data as u64)
+                     .replace_unchecked(idx as u32 * 2 + 1, (data >> 64) as u64))
+            }
+
+            #[inline(always)]
+            fn from_array(data: Self::Array) -> Self {
+                Self::load(&data, 0)
+            }
+
+            #[inline(always)]
+            fn to_array(self) -> Self::Array {
+                let mut ret = [0; $width];
+                Packed::store(self, &mut ret, 0);
+                ret
+            }
+
+            #[inline(always)]
+            fn splat(data: $el) -> Self {
+                let data = data as u128;
+                $vec($inner::from_fn(|i| if i % 2 == 0 { data as u64 } else { (data >> 64) as u64 }))
+            }
+
+            #[inline(always)]
+            fn default() -> Self {
+                $vec($inner::splat(0))
+            }
+
+            #[inline(always)]
+            fn product(&self) -> Self::Scalar {
+                let mut acc = 1 as $el;
+                for i in 0..Self::WIDTH {
+                    acc *= self.extract(i)
+                }
+                acc
+            }
+
+            #[inline(always)]
+            fn scalar_reduce<T, F>(&self, mut acc: T, mut func: F) -> T
+            where F: FnMut(T, Self::Scalar) -> T {
+                for i in 0..Self::WIDTH {
+                    acc = func(acc, self.extract(i))
+                }
+                acc
+            }
+        }
+
+        impl Merge for $vec {
+            #[inline(always)]
+            fn merge_halves(&self, other: Self) -> Self {
+                // Don't split an element between both vectors
+                $vec(self.0.merge_partitioned(other.0, $width / 2 * 2))
+            }
+
+            #[inline(always)]
+            fn merge_interleaved(&self, other: Self) -> Self {
+                let mut ret = *self;
+                for i in (1..$width).filter(|i| i % 2 == 1) {
+                    ret = ret.replace(i, other.extract(i));
+                }
+                ret
+            }
+
+            #[inline(always)]
+            fn merge_partitioned(&self, other: Self, offset: usize) -> Self {
+                assert!(offset < Self::WIDTH);
+                $vec(self.0.merge_partitioned(other.0, offset * 2))
+            }
+        }
+
+        impl Gather for $vec {
+            #[inline(always)]
+            unsafe fn gather_unchecked(data: &[$el], offset: usize, stride: usize) -> Self {
+                debug_assert!(offset + stride * (Self::WIDTH - 1) < data.len());
+                let mut ret = Self::default();
+                for i in 0..$width {
+                    ret = ret.replace_unchecked(i, *data.get_unchecked(offset + stride * i));
+                }
+                ret
+            }
+        }
+
+        impl Add for $vec {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, other: Self) -> Self {
+                $vec(self.0.wide_add(other.0))
+            }
+        }
+
+        impl Sub for $vec {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, other: Self) -> Self {
+                $vec(self.0.wide_sub(other.0))
+            }
+        }
+
+        impl AddAssign for $vec {
+            #[inline(always)]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $vec {
+            #[inline(always)]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl_wrapped_ops!($vec, [BitAnd, bitand], [BitOr, bitor], [BitXor, bitxor]);
+    }
+}
+
+impl_wide_packed!(u128, u128s, u128x4, u64x8, 4, ["avx512"], ["avx1024"]);
+impl_wide_packed!(u128, u128s, u128x2, u64x4, 2, ["avx2"], ["avx512"]);
+impl_wide_packed!(u128, u128s, u128x1, u64x2, 1, [], ["avx2"]);
+impl_wide_packed!(i128, i128s, i128x4, u64x8, 4, ["avx512"], ["avx1024"]);
+impl_wide_packed!(i128, i128s, i128x2, u64x4, 2, ["avx2"], ["avx512"]);
+impl_wide_packed!(i128, i128s, i128x1, u64x2, 1, [], ["avx2"]);
+
+#[cfg(test)]
+mod tests {
+    use vecs::*;
+    use intrin::*;
+    use iters::*;
+    use into_iters::*;
+    use wrapped::*;
+
+    macro_rules! test_wrapped {
+        ($name:ident, $vec:ident, $el:ty, $val:expr) => {
+            #[test]
+            fn $name() {
+                let data = (0..$vec::WIDTH * 2 + 1).map($val).collect::<Vec<$el>>();
+                let v = $vec::load(&data, 1);
+                for i in 0..$vec::WIDTH {
+                    assert_eq!(v.extract(i), data[i + 1]);
+                }
+                let mut out = data.clone();
+                $vec::splat(data[0]).store(&mut out, $vec::WIDTH);
+                for i in 0..$vec::WIDTH {
+                    assert_eq!(out[i + $vec::WIDTH], data[0]);
+                    assert_eq!(out[i], data[i]);
+                }
+                assert_eq!($vec::from_array(v.to_array()), v);
+                assert_eq!($vec::gather(&data, 0, 2).extract($vec::WIDTH - 1), data[($vec::WIDTH - 1) * 2]);
+
+                let mut replaced = v;
+                replaced = replaced.replace($vec::WIDTH - 1, data[0]);
+                assert_eq!(replaced.extract($vec::WIDTH - 1), data[0]);
+                assert_eq!(v.merge_partitioned(replaced, $vec::WIDTH - 1), replaced);
+            }
+        }
+    }
+
+    test_wrapped!(wrapped_usizes, usizes, usize, |i| i * 0x1234567);
+    test_wrapped!(wrapped_isizes, isizes, isize, |i| -(i as isize) * 0x1234567);
+    test_wrapped!(wrapped_bools, bools, bool, |i| i % 3 == 0);
+    test_wrapped!(wrapped_u128s, u128s, u128, |i| (i as u128) << 100 | i as u128);
+    test_wrapped!(wrapped_i128s, i128s, i128, |i| -((i as i128) << 100) - 1);
+
+    #[test]
+    fn wrapped_bool_mask() {
+        let v = boolx16::from_array([true, false, false, true, true, false, false, true,
+                                     false, false, false, true, true, true, true, false]);
+        assert_eq!(v.to_mask(), u8x16::new(0xFF, 0, 0, 0xFF, 0xFF, 0, 0, 0xFF,
+                                           0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0));
+        assert_eq!(boolx16::from_mask(v.to_mask()), v);
+        assert_eq!(boolx16::from_mask(u8x16::splat(0xFE)), boolx16::splat(false));
+        assert_eq!(v.product(), false);
+        assert_eq!(boolx16::splat(true).product(), true);
+    }
+
+    #[test]
+    fn wrapped_wide_carry() {
+        let max = u64::max_value() as u128;
+        assert_eq!((u128x2::splat(max) + u128x2::splat(1)).extract(1), max + 1);
+        assert_eq!((u128x2::splat(max + 1) - u128x2::splat(1)).extract(0), max);
+        assert_eq!((u128x2::splat(u128::max_value()) + u128x2::splat(2)).extract(0), 1);
+        assert_eq!((u128x2::splat(0) - u128x2::splat(1)).extract(1), u128::max_value());
+        assert_eq!((i128x2::splat(-1) + i128x2::splat(1)).extract(0), 0);
+        assert_eq!((i128x2::splat(i128::min_value()) - i128x2::splat(1)).extract(1), i128::max_value());
+        assert_eq!((i128x1::splat(-5) + i128x1::splat(3)).extract(0), -2);
+
+        // Carries stay within their own element
+        let a = u128x2::from_array([max, 1]);
+        let b = u128x2::from_array([1, max]);
+        assert_eq!((a + b).to_array(), [max + 1, max + 1]);
+        assert_eq!((a - b).to_array(), [max - 1, 1u128.wrapping_sub(max)]);
+        assert_eq!((b - a).to_array(), [1u128.wrapping_sub(max), max - 1]);
+
+        let mut v = u128x4::from_array([0, max, max << 64, u128::max_value()]);
+        v += u128x4::splat(1);
+        assert_eq!(v.to_array(), [1, max + 1, (max << 64) + 1, 0]);
+        v -= u128x4::splat(1);
+        assert_eq!(v.to_array(), [0, max, max << 64, u128::max_value()]);
+    }
+
+    #[test]
+    fn wrapped_wide_merge() {
+        let a = u128x4::from_array([1, 2, 3, 4]);
+        let b = u128x4::from_array([5, 6, 7, 8]);
+        assert_eq!(a.merge_halves(b).to_array(), [1, 2, 7, 8]);
+        assert_eq!(a.merge_interleaved(b).to_array(), [1, 6, 3, 8]);
+        assert_eq!(a.merge_partitioned(b, 1).to_array(), [1, 6, 7, 8]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn wrapped_iter() {
+        let idx = (0..100usize).collect::<Vec<usize>>();
+        assert_eq!(idx.simd_iter(usizes(0)).simd_reduce(0, |acc, v| v.scalar_reduce(acc, |acc, x| acc + x)), 4950);
+
+        let big = (0..100u128).map(|x| x << 64).collect::<Vec<u128>>();
+        let sum = big.simd_iter(u128s(0)).simd_reduce(u128s(0), |acc, v| acc + v)
+            .scalar_reduce(0, |acc, x| acc + x);
+        assert_eq!(sum, 4950 << 64);
+    }
+}
@@ -5,8 +5,11 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use iters::{SIMDIterator, SIMDIterable, SIMDObject, UnsafeIterator};
-use vecs::{Packed, Packable};
+use iters::{SIMDIterator, SIMDIterable, SIMDObject, SIMDArray, UnsafeIterator};
+use vecs::{Packed, u8s, u64s};
+use into_iters::IntoSIMDRefIterator;
+use intrin::{Sad, Sum};
+use core_or_std::fmt;
 
 /// A macro which takes a number n and an expression, and returns a tuple
 /// containing n copies of the expression. Only works for numbers less than or
@@ -38,9 +41,119 @@ use vecs::{Packed, Packable};
 }
 
 /// A lazy iterator which returns tuples of the elements of its contained
-/// iterators.
+/// iterators. Each contained iterator is read from the position it had when
+/// it was zipped, and `pos` counts the scalars zipped since then.
 pub struct Zip<T> {
-    iters: T
+    iters: T,
+    pos: usize,
+    scalar_end: usize,
+}
+
+/// The ways a `Zip` may handle iterators which contain different amounts of
+/// elements.
+///
+/// # Examples
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # fn main() {
+/// let a = [1u32; 10];
+/// let b = [2u32; 20];
+///
+/// let shortest = (a.simd_iter(u32s(0)), b.simd_iter(u32s(0)))
+///     .zip_with_policy(ZipPolicy::Shortest)
+///     .simd_map(|(a, b)| a + b)
+///     .scalar_collect();
+/// assert_eq!(shortest, vec![3u32; 10]);
+///
+/// let padded = (a.simd_iter(u32s(0)), b.simd_iter(u32s(0)))
+///     .zip_with_policy(ZipPolicy::PadWithDefault)
+///     .simd_map(|(a, b)| a + b)
+///     .scalar_collect();
+/// assert_eq!(&padded[..10], &[3u32; 10][..]);
+/// assert_eq!(&padded[10..], &[2u32; 10][..]);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZipPolicy {
+    /// Stop once the shortest iterator runs out of elements. The remaining
+    /// elements of every longer iterator are ignored.
+    Shortest,
+    /// Require every iterator to contain the same amount of elements. This is
+    /// the policy used by `zip` and `try_zip`.
+    Strict,
+    /// Stop once the longest iterator runs out of elements. The vectors of
+    /// shorter iterators are filled with their default elements once they
+    /// run out.
+    PadWithDefault,
+}
+
+/// An error which describes the first of a set of zipped iterators whose
+/// length differs from the first iterator's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZipLengthError {
+    /// The position of the mismatched iterator in the zipped tuple.
+    pub index: usize,
+    /// The amount of scalars left in the first iterator.
+    pub expected: usize,
+    /// The amount of scalars left in the mismatched iterator.
+    pub found: usize,
+}
+
+impl fmt::Display for ZipLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "You can only zip iterators of the same length (iterator {} has {} elements, but iterator 0 has {}).",
+               self.index, self.found, self.expected)
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+impl ::std::error::Error for ZipLengthError {}
+
+/// Return an error describing the first length in `lens` which differs from
+/// the first length.
+#[inline(always)]
+fn check_lengths(lens: &[usize]) -> Result<(), ZipLengthError> {
+    for (i, &len) in lens.iter().enumerate() {
+        if len != lens[0] {
+            return Err(ZipLengthError {
+                index: i,
+                expected: lens[0],
+                found: len
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Load the `count` elements of `iter` beginning at `pos` into a vector, after
+/// `empty` default elements. Elements past the end of `iter` are left as the
+/// default.
+#[inline(always)]
+unsafe fn load_lanes<T>(iter: &T, pos: usize, count: usize, empty: usize) -> T::Vector
+    where T : SIMDIterable + SIMDArray {
+    let width = iter.width();
+    let len = iter.scalar_len();
+    debug_assert!(count + empty == width);
+
+    if pos + count <= len {
+        if empty == 0 {
+            return iter.load_unchecked(pos);
+        } else if pos + count >= width {
+            // Right-align the partial vector to ensure the load is vectorized
+            return iter.default().merge_partitioned(iter.load_unchecked(pos + count - width), empty);
+        }
+    }
+
+    let mut ret = iter.default();
+    for i in 0..count {
+        if pos + i < len {
+            ret = ret.replace_unchecked(empty + i, iter.load_scalar_unchecked(pos + i));
+        }
+    }
+    ret
 }
 
 /// A lazy mapping iterator which applies its function to a stream of tuples of
@@ -50,10 +163,120 @@ pub struct SIMDZipMap<I, F> where I : SIMDZippedIterator {
     func: F,
 }
 
+/// An iterator which yields the remaining elements of a zipped iterator as
+/// tuples of scalars.
+pub struct SIMDZipUnpacked<T> where T : SIMDZippedIterable {
+    iter: T,
+    buf: Option<T::Vectors>,
+    lane: usize,
+}
+
+/// A tuple of vectors, whose elements may be extracted as tuples of scalars.
+pub trait PackedTuple : Copy {
+    type Scalars;
+
+    /// A tuple of `Vec`s which may hold the elements of each vector.
+    #[cfg(not(feature = "no-std"))]
+    type Vecs : ScalarTupleSink<Self>;
+
+    /// Return a tuple of default vectors.
+    fn default() -> Self;
+
+    /// Return a tuple containing the element at `idx` of each vector.
+    fn extract(&self, idx: usize) -> Self::Scalars;
+
+    /// Return a tuple of `Vec`s containing `len` uninitialized elements.
+    #[cfg(not(feature = "no-std"))]
+    unsafe fn uninitialized_vecs(len: usize) -> Self::Vecs;
+
+    /// Set the length of each `Vec` in `vecs` to `len`.
+    #[cfg(not(feature = "no-std"))]
+    unsafe fn set_vecs_len(vecs: &mut Self::Vecs, len: usize);
+}
+
+/// A tuple of scalar buffers, into which the vectors of a `PackedTuple` may
+/// be stored.
+pub trait ScalarTupleSink<V> {
+    /// Store each vector of `value` in its buffer, beginning at `offset`.
+    unsafe fn store_unchecked(&mut self, value: V, offset: usize);
+
+    /// Store the element at `idx` of each vector of `value` in its buffer at
+    /// `offset`.
+    unsafe fn store_lane_unchecked(&mut self, value: V, idx: usize, offset: usize);
+
+    /// Return the length of the shortest buffer.
+    fn min_len(&self) -> usize;
+}
+
+/// A trait which can store the tuples of vectors yielded by a zipped iterator
+/// in tuples of scalar buffers.
+///
+/// # Examples
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # fn main() {
+/// let a = [3.0f32; 10];
+/// let b = [4.0f32; 10];
+/// let (sums, products) = (a.simd_iter(f32s(0.0)), b.simd_iter(f32s(0.0))).zip()
+///     .simd_map(|(a, b)| (a + b, a * b))
+///     .scalar_collect();
+/// assert_eq!(sums, vec![7.0f32; 10]);
+/// assert_eq!(products, vec![12.0f32; 10]);
+/// # }
+/// ```
+pub trait IntoScalarTuple : SIMDZippedObject where Self::Vectors : PackedTuple {
+    /// Take an iterator of tuples of SIMD vectors, and store each of their
+    /// elements in-order in a tuple of Vecs.
+    #[cfg(not(feature = "no-std"))]
+    fn scalar_collect(&mut self) -> <Self::Vectors as PackedTuple>::Vecs;
+
+    /// Take an iterator of tuples of SIMD vectors, and store each of their
+    /// elements in-order in `fill`, which is a tuple of mutable slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any slice in `fill` is shorter than the remaining elements
+    /// of the iterator.
+    fn scalar_fill<S>(&mut self, fill: S) -> S
+        where S : ScalarTupleSink<Self::Vectors>;
+}
+
 /// A trait which can transform a collection of iterators into a `Zip`
 pub trait IntoSIMDZip : Sized {
     /// Return an iterator which may iterate over `self` in lockstep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterators don't contain the same amount of elements.
     fn zip(self) -> Zip<Self>;
+
+    /// Return an iterator which may iterate over `self` in lockstep, or an
+    /// error if the iterators don't contain the same amount of elements.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let a = [0u8; 100];
+    /// let b = [0u8; 99];
+    /// let err = (a.simd_iter(u8s(0)), b.simd_iter(u8s(0))).try_zip().err().unwrap();
+    /// assert_eq!(err, ZipLengthError { index: 1, expected: 100, found: 99 });
+    /// # }
+    /// ```
+    fn try_zip(self) -> Result<Zip<Self>, ZipLengthError>;
+
+    /// Return an iterator which may iterate over `self` in lockstep, treating
+    /// iterators of different lengths as described by `policy`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `policy` is `ZipPolicy::Strict` and the iterators don't
+    /// contain the same amount of elements.
+    fn zip_with_policy(self, policy: ZipPolicy) -> Zip<Self>;
 }
 
 pub trait SIMDZippedObject : Sized {
@@ -88,13 +311,31 @@ pub trait SIMDZippedIterable : SIMDZippedObject + ExactSizeIterator<Item = <Self
     /// Advance the iterable such that it procudes no more items.
     fn finalize(&mut self);
 
-    // #[inline(always)]
-    // /// Create a an iterator over the remaining scalar elements in this iterator
-    // fn unpack(self) -> Unpacked<Self> {
-    //     Unpacked {
-    //         iter: self,
-    //     }
-    // }
+    #[inline(always)]
+    /// Create an iterator over the remaining elements in this iterator, which
+    /// yields tuples of scalars.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let a = [1u32, 2, 3];
+    /// let b = [4.0f32, 5.0, 6.0];
+    /// let pairs = (a.simd_iter(u32s(0)), b.simd_iter(f32s(0.0)))
+    ///     .zip()
+    ///     .unpack()
+    ///     .collect::<Vec<(u32, f32)>>();
+    /// assert_eq!(pairs, vec![(1, 4.0), (2, 5.0), (3, 6.0)]);
+    /// # }
+    /// ```
+    fn unpack(self) -> SIMDZipUnpacked<Self> {
+        SIMDZipUnpacked {
+            lane: self.width(),
+            buf: None,
+            iter: self,
+        }
+    }
 
     // #[inline(always)]
     // /// Create an iterator which returns `amt` vectors at a time.
@@ -118,9 +359,11 @@ pub trait SIMDZippedIterator : SIMDZippedIterable {
     fn end(&mut self) -> Option<(Self::Vectors, usize)>;
 
     #[inline(always)]
-    /// Return an iterator which calls `func` on vectors of elements.
-    fn simd_map<A, B, F>(self, func: F) -> SIMDZipMap<Self, F>
-        where F : FnMut(Self::Vectors) -> A, A : Packed<Scalar = B>, B : Packable {
+    /// Return an iterator which calls `func` on vectors of elements. `func`
+    /// may return a vector, or a tuple of vectors which are as wide as the
+    /// vectors of this iterator.
+    fn simd_map<A, F>(self, func: F) -> SIMDZipMap<Self, F>
+        where F : FnMut(Self::Vectors) -> A {
         SIMDZipMap {
             iter: self,
             func: func,
@@ -130,6 +373,19 @@ pub trait SIMDZippedIterator : SIMDZippedIterable {
     #[inline(always)]
     /// Pack and run `func` over the iterator, returning no value and not
     /// modifying the iterator.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mut dot = 0.0;
+    /// ((&[2.0f32; 10][..]).simd_iter(f32s(0.0)),
+    ///  (&[3.0f32; 10][..]).simd_iter(f32s(0.0))).zip()
+    ///     .simd_do_each(|(a, b)| dot += (a * b).sum());
+    /// assert_eq!(dot, 60.0);
+    /// # }
+    /// ```
     fn simd_do_each<F>(&mut self, mut func: F)
         where F : FnMut(Self::Vectors) -> () {
         while let Some(v) = self.next() {
@@ -152,12 +408,30 @@ pub trait SIMDZippedIterator : SIMDZippedIterable {
     /// use faster::*;
     ///
     /// # fn main() {
-    /// let reduced = (&[2.0f32; 100][..]).simd_iter(f32s(0.0))
-    ///     .simd_reduce(f32s(0.0), |acc, v| acc + v);
+    /// let reduced = ((&[2.0f32; 100][..]).simd_iter(f32s(0.0)),
+    ///                (&[1.0f32; 100][..]).simd_iter(f32s(0.0))).zip()
+    ///     .simd_reduce(f32s(0.0), |acc, (a, b)| acc + a * b);
     /// # }
     /// ```
     ///
-    /// In this example, on a machine with 4-element vectors, the argument to
+    /// The accumulator may be of any type, so several reductions can be
+    /// computed in a single pass:
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let xs = [3.0f32; 100];
+    /// let (sum, sum_sq) = ((&xs[..]).simd_iter(f32s(0.0)),
+    ///                      (&xs[..]).simd_iter(f32s(0.0))).zip()
+    ///     .simd_reduce((f32s(0.0), f32s(0.0)), |(s, sq), (a, b)| (s + a, sq + a * b));
+    /// assert_eq!(sum.sum(), 300.0);
+    /// assert_eq!(sum_sq.sum(), 900.0);
+    /// # }
+    /// ```
+    ///
+    /// In the first example, on a machine with 4-element vectors, the argument to
     /// the last call of the closure is
     ///
     /// ```rust,ignore
@@ -194,7 +468,7 @@ pub trait SIMDZippedIterator : SIMDZippedIterable {
     fn simd_reduce<A, F>(&mut self, mut start: A, mut func: F) -> A
         where F : FnMut(A, Self::Vectors) -> A {
 
-        while let Some(mut v) = self.next() {
+        while let Some(v) = self.next() {
             start = func(start, v);
         }
         if let Some((v, _)) = self.end() {
@@ -207,39 +481,152 @@ pub trait SIMDZippedIterator : SIMDZippedIterable {
 
 macro_rules! impl_iter_zip {
     (($($a:tt),*), ($($b:tt),*), ($($n:tt),*)) => (
-        impl<$($a),*> IntoSIMDZip for ($($a),*) where $($a : SIMDIterator + UnsafeIterator),* {
+        impl<$($a),*> IntoSIMDZip for ($($a),*) where $($a : SIMDIterator + UnsafeIterator + SIMDArray),* {
             #[inline(always)]
             fn zip(self) -> Zip<Self> {
-                if $(self.0.len() != self.$n.len())||* {
-                    panic!("You can only zip iterators of the same length.");
+                self.zip_with_policy(ZipPolicy::Strict)
+            }
+
+            #[inline(always)]
+            fn try_zip(self) -> Result<Zip<Self>, ZipLengthError> {
+                check_lengths(&[$(self.$n.scalar_len() - self.$n.scalar_pos()),*])?;
+                Ok(self.zip_with_policy(ZipPolicy::Strict))
+            }
+
+            #[inline(always)]
+            fn zip_with_policy(self, policy: ZipPolicy) -> Zip<Self> {
+                let lens = [$(self.$n.scalar_len() - self.$n.scalar_pos()),*];
+                let len = match policy {
+                    ZipPolicy::Strict => {
+                        if let Err(e) = check_lengths(&lens) {
+                            panic!("{}", e);
+                        }
+                        lens[0]
+                    },
+                    ZipPolicy::Shortest => *lens.iter().min().unwrap(),
+                    ZipPolicy::PadWithDefault => *lens.iter().max().unwrap(),
+                };
+                Zip {
+                    iters: self,
+                    pos: 0,
+                    scalar_end: len,
                 }
-                Zip { iters: self }
+            }
+        }
+
+        impl<$($a),*> PackedTuple for ($($a),*) where $($a : Packed),* {
+            type Scalars = ($($a::Scalar),*);
+            #[cfg(not(feature = "no-std"))]
+            type Vecs = ($(Vec<$a::Scalar>),*);
+
+            #[inline(always)]
+            fn default() -> Self {
+                ($(<$a as Packed>::default()),*)
+            }
+
+            #[inline(always)]
+            fn extract(&self, idx: usize) -> Self::Scalars {
+                ($(self.$n.extract(idx)),*)
+            }
+
+            #[inline(always)]
+            #[cfg(not(feature = "no-std"))]
+            unsafe fn uninitialized_vecs(len: usize) -> Self::Vecs {
+                ($({
+                    let mut v = Vec::<$a::Scalar>::with_capacity(len);
+                    v.set_len(len);
+                    v
+                }),*)
+            }
+
+            #[inline(always)]
+            #[cfg(not(feature = "no-std"))]
+            unsafe fn set_vecs_len(vecs: &mut Self::Vecs, len: usize) {
+                $(vecs.$n.set_len(len);)*
+            }
+        }
+
+        #[cfg(not(feature = "no-std"))]
+        impl<$($a),*> ScalarTupleSink<($($a),*)> for ($(Vec<$a::Scalar>),*) where $($a : Packed),* {
+            #[inline(always)]
+            unsafe fn store_unchecked(&mut self, value: ($($a),*), offset: usize) {
+                $(value.$n.store_unchecked(&mut self.$n[..], offset);)*
+            }
+
+            #[inline(always)]
+            unsafe fn store_lane_unchecked(&mut self, value: ($($a),*), idx: usize, offset: usize) {
+                $(*self.$n.get_unchecked_mut(offset) = value.$n.extract_unchecked(idx);)*
+            }
+
+            #[inline(always)]
+            fn min_len(&self) -> usize {
+                *[$(self.$n.len()),*].iter().min().unwrap()
+            }
+        }
+
+        impl<'a, $($a),*> ScalarTupleSink<($($a),*)> for ($(&'a mut [$a::Scalar]),*) where $($a : Packed),* {
+            #[inline(always)]
+            unsafe fn store_unchecked(&mut self, value: ($($a),*), offset: usize) {
+                $(value.$n.store_unchecked(&mut self.$n[..], offset);)*
+            }
+
+            #[inline(always)]
+            unsafe fn store_lane_unchecked(&mut self, value: ($($a),*), idx: usize, offset: usize) {
+                $(*self.$n.get_unchecked_mut(offset) = value.$n.extract_unchecked(idx);)*
+            }
+
+            #[inline(always)]
+            fn min_len(&self) -> usize {
+                *[$(self.$n.len()),*].iter().min().unwrap()
             }
         }
 
         impl<$($a),*> ExactSizeIterator for Zip<($($a),*)>
-            where $($a : SIMDIterator + UnsafeIterator),* {
+            where $($a : SIMDIterator + UnsafeIterator + SIMDArray),* {
             #[inline(always)]
             fn len(&self) -> usize {
-                self.iters.0.len()
+                (self.scalar_end - self.pos) / self.width()
             }
         }
 
         impl<$($a),*> Iterator for Zip<($($a),*)>
-            where $($a : SIMDIterator + UnsafeIterator),* {
+            where $($a : SIMDIterator + UnsafeIterator + SIMDArray),* {
             type Item = ($(<$a as Iterator>::Item),*);
 
             #[inline(always)]
             fn next(&mut self) -> Option<<Self as SIMDZippedObject>::Vectors> {
-                let pos = self.iters.0.scalar_pos();
-                self.iters.0.next().map(|v| unsafe {
-                    (v, $(self.iters.$n.next_unchecked(pos)),*)
-                })
+                let pos = self.pos;
+                let width = self.width();
+                if pos + width <= self.scalar_end {
+                    self.pos += width;
+                    Some(unsafe {
+                        ($(load_lanes(&self.iters.$n, self.iters.$n.scalar_pos() + pos, width, 0)),*)
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<$($a),*> DoubleEndedIterator for Zip<($($a),*)>
+            where $($a : SIMDIterator + UnsafeIterator + SIMDArray),* {
+            #[inline(always)]
+            fn next_back(&mut self) -> Option<<Self as SIMDZippedObject>::Vectors> {
+                let width = self.width();
+                if self.pos + width <= self.scalar_end {
+                    self.scalar_end -= width;
+                    let pos = self.scalar_end;
+                    Some(unsafe {
+                        ($(load_lanes(&self.iters.$n, self.iters.$n.scalar_pos() + pos, width, 0)),*)
+                    })
+                } else {
+                    None
+                }
             }
         }
 
         impl<$($a),*> SIMDZippedObject for Zip<($($a),*)>
-            where $($a : SIMDIterator + UnsafeIterator),* {
+            where $($a : SIMDIterator + UnsafeIterator + SIMDArray),* {
             type Vectors = ($($a::Vector),*);
             type Scalars = ($($a::Scalar),*);
 
@@ -255,55 +642,92 @@ macro_rules! impl_iter_zip {
         }
 
         impl<$($a),*> SIMDZippedIterator for Zip<($($a),*)>
-            where $($a : SIMDIterator + UnsafeIterator),* {
+            where $($a : SIMDIterator + UnsafeIterator + SIMDArray),* {
 
             #[inline(always)]
             fn end(&mut self) -> Option<(Self::Vectors, usize)> {
-                let pos = self.iters.0.scalar_pos();
-                self.iters.0.end().map(|(v, n)| unsafe {
-                    ((v, $(self.iters.$n.end_unchecked(pos, n)),*), n)
-                })
+                let pos = self.pos;
+                if pos >= self.scalar_end {
+                    return None;
+                }
+
+                let count = self.scalar_end - pos;
+                debug_assert!(count < self.width(),
+                              "end() may only be called once next() returns None");
+                $(debug_assert_eq!(self.iters.$n.width(), self.width(),
+                                   "zipped iterators must have the same vector width");)*
+                let empty_amt = self.width() - count;
+                self.pos = self.scalar_end;
+                Some((unsafe {
+                    ($(load_lanes(&self.iters.$n, self.iters.$n.scalar_pos() + pos, count, empty_amt)),*)
+                }, empty_amt))
             }
         }
 
         impl<$($a),*> SIMDZippedIterable for Zip<($($a),*)>
-            where $($a : SIMDIterator + UnsafeIterator),* {
+            where $($a : SIMDIterator + UnsafeIterator + SIMDArray),* {
 
             #[inline(always)]
             fn scalar_pos(&self) -> usize {
-                self.iters.0.scalar_pos()
+                self.pos
             }
 
             #[inline(always)]
             fn vector_pos(&self) -> usize {
-                self.iters.0.vector_pos()
+                self.pos / self.width()
             }
 
             #[inline(always)]
             fn vector_inc(&mut self) {
-                self.iters.0.vector_inc();
+                self.pos += self.width();
             }
 
             #[inline(always)]
             fn scalar_inc(&mut self) {
-                self.iters.0.scalar_inc();
+                self.pos += 1;
             }
 
             #[inline(always)]
             fn default(&self) -> Self::Vectors {
-                (self.iters.0.default(), $(self.iters.$n.default()),*)
+                ($(self.iters.$n.default()),*)
             }
 
             #[inline(always)]
             fn finalize(&mut self) {
-                self.iters.0.finalize();
+                self.pos = self.scalar_end;
             }
         }
     );
 }
 
+impl<T> Iterator for SIMDZipUnpacked<T>
+    where T : SIMDZippedIterator, T::Vectors : PackedTuple {
+    type Item = <T::Vectors as PackedTuple>::Scalars;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.lane >= self.iter.width() {
+            if let Some(v) = self.iter.next() {
+                self.buf = Some(v);
+                self.lane = 0;
+            } else if let Some((v, empty_amt)) = self.iter.end() {
+                // The final vector is right-aligned, so skip its empty lanes
+                self.buf = Some(v);
+                self.lane = empty_amt;
+            } else {
+                self.buf = None;
+                return None;
+            }
+        }
+
+        let ret = self.buf.map(|v| v.extract(self.lane));
+        self.lane += 1;
+        ret
+    }
+}
+
 impl<I, F, A> Iterator for SIMDZipMap<I, F>
-    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : Packed {
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A {
     type Item = A;
 
     #[inline(always)]
@@ -312,8 +736,16 @@ impl<I, F, A> Iterator for SIMDZipMap<I, F>
     }
 }
 
+impl<I, F, A> DoubleEndedIterator for SIMDZipMap<I, F>
+    where I : SIMDZippedIterator + DoubleEndedIterator, F : FnMut(I::Vectors) -> A {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(&mut self.func)
+    }
+}
+
 impl<I, F, A> ExactSizeIterator for SIMDZipMap<I, F>
-    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : Packed {
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A {
     #[inline(always)]
     fn len(&self) -> usize {
         self.iter.len()
@@ -365,45 +797,242 @@ where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : Packed {
     #[inline(always)]
     fn end(&mut self) -> Option<(Self::Vector, usize)> {
         let (v, n) = self.iter.end()?;
-        let nr = n * self.iter.size() / self.size();
+        let nr = n * self.iter.size() / <Self as SIMDObject>::size(self);
         Some(((self.func)(v), nr))
     }
 }
 
+impl<I, F, A> SIMDZippedObject for SIMDZipMap<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    type Vectors = A;
+    type Scalars = A::Scalars;
+
+    #[inline(always)]
+    fn width(&self) -> usize {
+        self.iter.width()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.iter.size()
+    }
+}
+
+impl<I, F, A> SIMDZippedIterable for SIMDZipMap<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn vector_pos(&self) -> usize {
+        self.iter.vector_pos()
+    }
+
+    #[inline(always)]
+    fn vector_inc(&mut self) {
+        self.iter.vector_inc()
+    }
+
+    #[inline(always)]
+    fn scalar_inc(&mut self) {
+        self.iter.scalar_inc()
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vectors {
+        <A as PackedTuple>::default()
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self) {
+        self.iter.finalize()
+    }
+}
+
+impl<I, F, A> SIMDZippedIterator for SIMDZipMap<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        // Every vector of the tuple is as wide as the vectors of the zipped
+        // iterator, so the amount of empty elements is unchanged
+        let (v, n) = self.iter.end()?;
+        Some(((self.func)(v), n))
+    }
+}
+
+/// Store the tuples of vectors yielded by `iter` in-order in `fill`, and
+/// return the amount of elements stored in each buffer.
+#[inline(always)]
+fn fill_tuples<I, S>(iter: &mut I, fill: &mut S) -> usize
+    where I : SIMDZippedIterator, I::Vectors : PackedTuple, S : ScalarTupleSink<I::Vectors> {
+    let width = iter.width();
+    let len = fill.min_len();
+    let mut offset = 0;
+    let mut lastvec = <I::Vectors as PackedTuple>::default();
+
+    assert!(iter.len() * width <= len, "A buffer is shorter than the zipped iterator.");
+    while let Some(vec) = iter.next() {
+        unsafe { fill.store_unchecked(vec, offset); }
+        offset += width;
+        lastvec = vec;
+    }
+
+    if let Some((p, n)) = iter.end() {
+        assert!(offset + width - n <= len, "A buffer is shorter than the zipped iterator.");
+        if offset > 0 {
+            // We stored a vector in this buffer; overwrite the unused elements
+            unsafe {
+                fill.store_unchecked(p, offset - n);
+                fill.store_unchecked(lastvec, offset - width);
+            }
+        } else {
+            // The buffer won't fit one vector; store elementwise
+            for i in 0..(width - n) {
+                unsafe { fill.store_lane_unchecked(p, i + n, offset + i); }
+            }
+        }
+        offset + width - n
+    } else {
+        offset
+    }
+}
+
+impl<I> IntoScalarTuple for I
+    where I : SIMDZippedIterator, I::Vectors : PackedTuple {
+    #[inline(always)]
+    #[cfg(not(feature = "no-std"))]
+    fn scalar_collect(&mut self) -> <Self::Vectors as PackedTuple>::Vecs {
+        unsafe {
+            let mut ret = <Self::Vectors as PackedTuple>::uninitialized_vecs((self.len() + 1) * self.width());
+            let len = fill_tuples(self, &mut ret);
+            <Self::Vectors as PackedTuple>::set_vecs_len(&mut ret, len);
+            ret
+        }
+    }
+
+    #[inline(always)]
+    fn scalar_fill<S>(&mut self, mut fill: S) -> S
+        where S : ScalarTupleSink<Self::Vectors> {
+        fill_tuples(self, &mut fill);
+        fill
+    }
+}
+
 
 impl_iter_zip!((A, B),
                (AA, BB),
-               (1));
+               (0, 1));
 impl_iter_zip!((A, B, C),
                (AA, BB, CC),
-               (1, 2));
+               (0, 1, 2));
 impl_iter_zip!((A, B, C, D),
                (AA, BB, CC, DD),
-               (1, 2, 3));
+               (0, 1, 2, 3));
 impl_iter_zip!((A, B, C, D, E),
                (AA, BB, CC, DD, EE),
-               (1, 2, 3, 4));
+               (0, 1, 2, 3, 4));
 impl_iter_zip!((A, B, C, D, E, F),
                (AA, BB, CC, DD, EE, FF),
-               (1, 2, 3, 4, 5));
+               (0, 1, 2, 3, 4, 5));
 impl_iter_zip!((A, B, C, D, E, F, G),
                (AA, BB, CC, DD, EE, FF, GG),
-               (1, 2, 3, 4, 5, 6));
+               (0, 1, 2, 3, 4, 5, 6));
 impl_iter_zip!((A, B, C, D, E, F, G, H),
                (AA, BB, CC, DD, EE, FF, GG, HH),
-               (1, 2, 3, 4, 5, 6, 7));
+               (0, 1, 2, 3, 4, 5, 6, 7));
 impl_iter_zip!((A, B, C, D, E, F, G, H, I),
                (AA, BB, CC, DD, EE, FF, GG, HH, II),
-               (1, 2, 3, 4, 5, 6, 7, 8));
+               (0, 1, 2, 3, 4, 5, 6, 7, 8));
 impl_iter_zip!((A, B, C, D, E, F, G, H, I, J),
                (AA, BB, CC, DD, EE, FF, GG, HH, II, JJ),
-               (1, 2, 3, 4, 5, 6, 7, 8, 9));
+               (0, 1, 2, 3, 4, 5, 6, 7, 8, 9));
 impl_iter_zip!((A, B, C, D, E, F, G, H, I, J, K),
                (AA, BB, CC, DD, EE, FF, GG, HH, II, JJ, KK),
-               (1, 2, 3, 4, 5, 6, 7, 8, 9, 10));
+               (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10));
 impl_iter_zip!((A, B, C, D, E, F, G, H, I, J, K, L),
                (AA, BB, CC, DD, EE, FF, GG, HH, II, JJ, KK, LL),
-               (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11));
+               (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11));
 impl_iter_zip!((A, B, C, D, E, F, G, H, I, J, K, L, M),
                (AA, BB, CC, DD, EE, FF, GG, HH, II, JJ, KK, LL, MM),
-               (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+               (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+
+/// Return the sum of the absolute differences of the elements of `a` and
+/// `b`.
+///
+/// # Panics
+///
+/// This function panics if `a` and `b` have different lengths.
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # fn main() {
+/// let frame = [10u8; 300];
+/// let reference = [7u8; 300];
+/// assert_eq!(simd_sad(&frame, &reference), 900);
+/// # }
+/// ```
+#[inline(always)]
+pub fn simd_sad(a: &[u8], b: &[u8]) -> u64 {
+    (a.simd_iter(u8s(0)), b.simd_iter(u8s(0))).zip()
+        .simd_reduce(u64s(0), |acc, (a, b)| acc + a.sad(b))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn simd_sad() {
+        let a = (0..1001).map(|i| (i * 37) as u8).collect::<Vec<u8>>();
+        let b = (0..1001).map(|i| (i * 91 + 5) as u8).collect::<Vec<u8>>();
+        let expected = a.iter().zip(b.iter()).map(|(&x, &y)| (x as i64 - y as i64).abs() as u64).sum::<u64>();
+        assert_eq!(super::simd_sad(&a, &b), expected);
+        assert_eq!(super::simd_sad(&[], &[]), 0);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn zip_different_positions() {
+        let a = (0..40).map(|i| i as u32).collect::<Vec<u32>>();
+        let b = (0..30).map(|i| i as u32 * 100).collect::<Vec<u32>>();
+        let sums = (a.simd_iter(u32s(0)).simd_skip(10), b.simd_iter(u32s(0)))
+            .zip()
+            .simd_map(|(x, y)| x + y)
+            .scalar_collect();
+        assert_eq!(sums, (0..30).map(|i| i as u32 * 101 + 10).collect::<Vec<u32>>());
+
+        let short = (a.simd_iter(u32s(0)).simd_skip(37), b.simd_iter(u32s(0)))
+            .zip_with_policy(ZipPolicy::Shortest)
+            .simd_map(|(x, y)| x + y)
+            .scalar_collect();
+        assert_eq!(short, vec![37, 138, 239]);
+
+        let padded = (a.simd_iter(u32s(0)).simd_skip(20), b.simd_iter(u32s(0)).simd_skip(5))
+            .zip_with_policy(ZipPolicy::PadWithDefault)
+            .simd_map(|(x, y)| x + y)
+            .scalar_collect();
+        assert_eq!(padded, (0..25).map(|i| (if i < 20 { i as u32 + 20 } else { 0 }) + (i as u32 + 5) * 100).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn scalar_fill_tuple_short() {
+        let a = [1u32; 100];
+        let mut sums = [0u32; 100];
+        let mut products = [0u32; 50];
+        (a.simd_iter(u32s(0)), a.simd_iter(u32s(0))).zip()
+            .simd_map(|(x, y)| (x + y, x * y))
+            .scalar_fill((&mut sums[..], &mut products[..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn simd_sad_mismatched() {
+        super::simd_sad(&[1, 2, 3], &[1, 2]);
+    }
+}
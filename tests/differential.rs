@@ -0,0 +1,661 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// Compares the baseline intrinsics against a scalar reference, lane by lane, on
+// randomly generated vectors. Run this with and without the `force-scalar`
+// feature to check both the SIMD and the fallback implementations.
+
+extern crate faster;
+#[macro_use] extern crate proptest;
+
+#[cfg(test)]
+#[cfg(not(feature = "no-std"))]
+mod tests {
+    use faster::*;
+    use proptest::collection::vec;
+    use proptest::strategy::{BoxedStrategy, Strategy};
+    use std::{f32, f64};
+
+    macro_rules! int_lanes {
+        ($name:ident, $el:ident, $vec:ty) => {
+            /// Generate the elements of a vector, with a bias towards the
+            /// extremes of the element type.
+            fn $name() -> BoxedStrategy<Vec<$el>> {
+                let width = <$vec as Packed>::WIDTH;
+                vec((::proptest::num::$el::ANY, ::proptest::num::u8::ANY).prop_map(|(x, sel)| {
+                    match sel % 8 {
+                        0 => ::std::$el::MAX,
+                        1 => ::std::$el::MIN,
+                        2 => 0,
+                        3 => 1,
+                        _ => x,
+                    }
+                }), width..width + 1).boxed()
+            }
+        }
+    }
+
+    macro_rules! float_lanes {
+        ($name:ident, $el:ident, $vec:ty) => {
+            /// Generate the elements of a vector, with a bias towards zeroes,
+            /// infinities, NaN and the extremes of the element type.
+            fn $name() -> BoxedStrategy<Vec<$el>> {
+                let width = <$vec as Packed>::WIDTH;
+                vec((::proptest::num::$el::ANY, ::proptest::num::u8::ANY).prop_map(|(x, sel)| {
+                    match sel % 16 {
+                        0 => $el::NAN,
+                        1 => $el::INFINITY,
+                        2 => $el::NEG_INFINITY,
+                        3 => 0.0,
+                        4 => -0.0,
+                        5 => $el::MAX,
+                        6 => $el::MIN,
+                        7 => $el::MIN_POSITIVE,
+                        _ => x,
+                    }
+                }), width..width + 1).boxed()
+            }
+        }
+    }
+
+    int_lanes!(lanes_u8, u8, u8s);
+    int_lanes!(lanes_i8, i8, i8s);
+    int_lanes!(lanes_u16, u16, u16s);
+    int_lanes!(lanes_i16, i16, i16s);
+    int_lanes!(lanes_u32, u32, u32s);
+    int_lanes!(lanes_i32, i32, i32s);
+    int_lanes!(lanes_u64, u64, u64s);
+    int_lanes!(lanes_i64, i64, i64s);
+    float_lanes!(lanes_f32, f32, f32s);
+    float_lanes!(lanes_f64, f64, f64s);
+
+    /// Generate the elements of a vector whose pairwise sums and differences
+    /// never overflow.
+    fn half_lanes_i16() -> BoxedStrategy<Vec<i16>> {
+        lanes_i16().prop_map(|v| v.into_iter().map(|x| x / 2).collect()).boxed()
+    }
+
+    /// Generate the elements of a vector whose pairwise sums and differences
+    /// never overflow.
+    fn half_lanes_i32() -> BoxedStrategy<Vec<i32>> {
+        lanes_i32().prop_map(|v| v.into_iter().map(|x| x / 2).collect()).boxed()
+    }
+
+    macro_rules! sum_lanes {
+        ($name:ident, $lanes:ident, $el:ident, $vec:ty) => {
+            /// Generate the elements of a vector whose sum never overflows.
+            fn $name() -> BoxedStrategy<Vec<$el>> {
+                let width = <$vec as Packed>::WIDTH as $el;
+                $lanes().prop_map(move |v| v.into_iter().map(|x| x / width).collect()).boxed()
+            }
+        }
+    }
+
+    sum_lanes!(sum_lanes_u8, lanes_u8, u8, u8s);
+    sum_lanes!(sum_lanes_i8, lanes_i8, i8, i8s);
+    sum_lanes!(sum_lanes_u16, lanes_u16, u16, u16s);
+    sum_lanes!(sum_lanes_i16, lanes_i16, i16, i16s);
+    sum_lanes!(sum_lanes_u32, lanes_u32, u32, u32s);
+    sum_lanes!(sum_lanes_i32, lanes_i32, i32, i32s);
+    sum_lanes!(sum_lanes_u64, lanes_u64, u64, u64s);
+    sum_lanes!(sum_lanes_i64, lanes_i64, i64, i64s);
+
+    macro_rules! integral_lanes {
+        ($name:ident, $el:ident, $vec:ty, $src:ident, $map:expr) => {
+            /// Generate the elements of a vector of small integers, whose
+            /// sums and products are exact regardless of their order.
+            fn $name() -> BoxedStrategy<Vec<$el>> {
+                let width = <$vec as Packed>::WIDTH;
+                vec(::proptest::num::$src::ANY.prop_map($map), width..width + 1).boxed()
+            }
+        }
+    }
+
+    integral_lanes!(integral_lanes_f32, f32, f32s, i16, |x| x as f32);
+    integral_lanes!(integral_lanes_f64, f64, f64s, i16, |x| x as f64);
+    integral_lanes!(factor_lanes_u32, u32, u32s, u8, |x| (x % 3) as u32);
+    integral_lanes!(factor_lanes_i32, i32, i32s, u8, |x| (x % 4) as i32 - 1);
+    integral_lanes!(factor_lanes_u64, u64, u64s, u8, |x| (x % 3) as u64);
+    integral_lanes!(factor_lanes_i64, i64, i64s, u8, |x| (x % 4) as i64 - 1);
+    integral_lanes!(factor_lanes_f32, f32, f32s, u8, |x| (x % 4) as f32 - 1.0);
+    integral_lanes!(factor_lanes_f64, f64, f64s, u8, |x| (x % 4) as f64 - 1.0);
+
+    /// Return true if `a` and `b` are both NaN, or are bitwise equal.
+    fn same_f32(a: f32, b: f32) -> bool {
+        (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits()
+    }
+
+    /// Return true if `a` and `b` are both NaN, or are bitwise equal.
+    fn same_f64(a: f64, b: f64) -> bool {
+        (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits()
+    }
+
+    /// Return true if `a` is within `ulps` units in the last place of `b`,
+    /// treating all NaNs as equal and zeroes of either sign as equal.
+    fn within_ulps_f32(a: f32, b: f32, ulps: u32) -> bool {
+        if a.is_nan() || b.is_nan() {
+            a.is_nan() && b.is_nan()
+        } else if a == b {
+            true
+        } else if a.is_sign_negative() != b.is_sign_negative() || a.is_infinite() || b.is_infinite() {
+            false
+        } else {
+            let (a, b) = (a.to_bits(), b.to_bits());
+            (if a > b { a - b } else { b - a }) <= ulps
+        }
+    }
+
+    /// Round `x` to the nearest integer, rounding ties to even.
+    fn round_even_f32(x: f32) -> f32 {
+        let r = x.round();
+        if (r - x).abs() == 0.5 { (x / 2.0).round() * 2.0 } else { r }
+    }
+
+    /// Round `x` to the nearest integer, rounding ties to even.
+    fn round_even_f64(x: f64) -> f64 {
+        let r = x.round();
+        if (r - x).abs() == 0.5 { (x / 2.0).round() * 2.0 } else { r }
+    }
+
+    fn same<T : PartialEq>(a: T, b: T) -> bool {
+        a == b
+    }
+
+    /// The relative error of `rcpps` and `rsqrtps` is at most 1.5 * 2^-12,
+    /// which is 3072 ULPs of an f32.
+    const APPROX_ULPS: u32 = 3072;
+
+    macro_rules! test_unary {
+        ($name:ident, $lanes:ident, $vec:ty, |$x:ident| $simd:expr, |$s:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes()) {
+                    let $x = <$vec as Pattern>::from_slice(&a);
+                    let out = $simd;
+                    for i in 0..<$vec as Packed>::WIDTH {
+                        let $s = a[i];
+                        prop_assert!($same(out.extract(i as u32), $scalar));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_binary {
+        ($name:ident, $lanes:ident, $vec:ty, |$x:ident, $y:ident| $simd:expr, |$s:ident, $t:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes(), b in $lanes()) {
+                    let ($x, $y) = (<$vec as Pattern>::from_slice(&a), <$vec as Pattern>::from_slice(&b));
+                    let out = $simd;
+                    for i in 0..<$vec as Packed>::WIDTH {
+                        let ($s, $t) = (a[i], b[i]);
+                        prop_assert!($same(out.extract(i as u32), $scalar));
+                    }
+                }
+            }
+        }
+    }
+
+    // Horizontal operations place the result of each pair of `self` at even
+    // indices, and the result of each pair of `other` at odd indices.
+    macro_rules! test_horizontal {
+        ($name:ident, $lanes:ident, $vec:ty, $op:ident, |$s:ident, $t:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes(), b in $lanes()) {
+                    let out = <$vec as Pattern>::from_slice(&a).$op(<$vec as Pattern>::from_slice(&b));
+                    for i in 0..<$vec as Packed>::WIDTH {
+                        let src = if i % 2 == 0 { &a } else { &b };
+                        let pair = i - i % 2;
+                        let ($s, $t) = (src[pair], src[pair + 1]);
+                        prop_assert!($same(out.extract(i as u32), $scalar));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_downcast {
+        ($name:ident, $lanes:ident, $vec:ty, $out:ty, |$s:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes(), b in $lanes()) {
+                    let out: $out = <$vec as Pattern>::from_slice(&a).saturating_downcast(<$vec as Pattern>::from_slice(&b));
+                    let width = <$vec as Packed>::WIDTH;
+                    for i in 0..<$out as Packed>::WIDTH {
+                        let $s = if i < width { a[i] } else { b[i - width] };
+                        prop_assert!($same(out.extract(i as u32), $scalar));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_upcast {
+        ($name:ident, $lanes:ident, $vec:ty, $out:ty, |$s:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes()) {
+                    let (lo, hi): ($out, $out) = <$vec as Pattern>::from_slice(&a).upcast();
+                    let width = <$out as Packed>::WIDTH;
+                    for i in 0..width {
+                        let $s = a[i];
+                        prop_assert!($same(lo.extract(i as u32), $scalar));
+                        let $s = a[width + i];
+                        prop_assert!($same(hi.extract(i as u32), $scalar));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_reduce {
+        ($name:ident, $lanes:ident, $vec:ty, |$x:ident| $simd:expr, |$a:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes()) {
+                    let $x = <$vec as Pattern>::from_slice(&a);
+                    let $a = &a;
+                    prop_assert!($same($simd, $scalar));
+                }
+            }
+        }
+    }
+
+    // Permutations move the element at index `$src(i)` of `self` to index `i`.
+    macro_rules! test_permute {
+        ($name:ident, $lanes:ident, $vec:ty, $op:ident, |$i:ident| $src:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes()) {
+                    let out = <$vec as Pattern>::from_slice(&a).$op();
+                    for $i in 0..<$vec as Packed>::WIDTH {
+                        prop_assert!($same(out.extract($i as u32), a[$src]));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_lanewise {
+        ($name:ident, $lanes:ident, $vec:ty, |$x:ident, $y:ident| $simd:expr, |$i:ident, $s:ident, $t:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes(), b in $lanes()) {
+                    let ($x, $y) = (<$vec as Pattern>::from_slice(&a), <$vec as Pattern>::from_slice(&b));
+                    let out = $simd;
+                    for $i in 0..<$vec as Packed>::WIDTH {
+                        let ($s, $t) = (a[$i], b[$i]);
+                        prop_assert!($same(out.extract($i as u32), $scalar));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_partitioned {
+        ($name:ident, $lanes:ident, $vec:ty, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes(), b in $lanes()) {
+                    let (x, y) = (<$vec as Pattern>::from_slice(&a), <$vec as Pattern>::from_slice(&b));
+                    for offset in 0..<$vec as Packed>::WIDTH + 1 {
+                        let out = x.merge_partitioned(y, offset);
+                        for i in 0..<$vec as Packed>::WIDTH {
+                            prop_assert!($same(out.extract(i as u32), if i < offset { a[i] } else { b[i] }));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Destriding the concatenation of `n` vectors places every `n`th element,
+    // beginning at `k`, in the `k`th output.
+    macro_rules! test_destride {
+        ($name:ident, $lanes:ident, $vec:ty, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes(), b in $lanes(), c in $lanes(), d in $lanes()) {
+                    let v = |x: &Vec<_>| <$vec as Pattern>::from_slice(x);
+                    let src = [&a[..], &b[..], &c[..], &d[..]].concat();
+                    let two = v(&a).destride_two(v(&b));
+                    let three = v(&a).destride_three(v(&b), v(&c));
+                    let four = v(&a).destride_four(v(&b), v(&c), v(&d));
+                    for i in 0..<$vec as Packed>::WIDTH {
+                        let j = i as u32;
+                        prop_assert!($same(two.0.extract(j), src[i * 2]));
+                        prop_assert!($same(two.1.extract(j), src[i * 2 + 1]));
+                        prop_assert!($same(three.0.extract(j), src[i * 3]));
+                        prop_assert!($same(three.1.extract(j), src[i * 3 + 1]));
+                        prop_assert!($same(three.2.extract(j), src[i * 3 + 2]));
+                        prop_assert!($same(four.0.extract(j), src[i * 4]));
+                        prop_assert!($same(four.1.extract(j), src[i * 4 + 1]));
+                        prop_assert!($same(four.2.extract(j), src[i * 4 + 2]));
+                        prop_assert!($same(four.3.extract(j), src[i * 4 + 3]));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_gather {
+        ($name:ident, $lanes:ident, $vec:ty, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes(), b in $lanes(), c in $lanes(), d in $lanes(),
+                         offset in 0usize..4, stride in 0usize..5) {
+                    let data = [&a[..], &b[..], &c[..], &d[..]].concat();
+                    let out = <$vec as Gather>::gather(&data, offset, stride);
+                    for i in 0..<$vec as Packed>::WIDTH {
+                        prop_assert!($same(out.extract(i as u32), data[offset + stride * i]));
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! test_widen {
+        ($name:ident, $lanes:ident, $vec:ty, $out:ty, |$s:ident| $scalar:expr, $same:expr) => {
+            proptest! {
+                #[test]
+                fn $name(a in $lanes()) {
+                    let width = <$out as Packed>::WIDTH;
+                    let mut out = vec![<$out as Packed>::default(); <$vec as Packed>::WIDTH / width];
+                    Widen::<$out>::widen(<$vec as Pattern>::from_slice(&a), &mut out);
+                    for i in 0..<$vec as Packed>::WIDTH {
+                        let $s = a[i];
+                        prop_assert!($same(out[i / width].extract((i % width) as u32), $scalar));
+                    }
+                }
+            }
+        }
+    }
+
+    // Abs
+    test_unary!(abs_i8s, lanes_i8, i8s, |x| x.abs(), |s| s.wrapping_abs() as u8, same);
+    test_unary!(abs_i16s, lanes_i16, i16s, |x| x.abs(), |s| s.wrapping_abs() as u16, same);
+    test_unary!(abs_i32s, lanes_i32, i32s, |x| x.abs(), |s| s.wrapping_abs() as u32, same);
+    test_unary!(abs_i64s, lanes_i64, i64s, |x| x.abs(), |s| s.wrapping_abs() as u64, same);
+    test_unary!(abs_f32s, lanes_f32, f32s, |x| x.abs(), |s| s.abs(), same_f32);
+    test_unary!(abs_f64s, lanes_f64, f64s, |x| x.abs(), |s| s.abs(), same_f64);
+
+    // Sqrt
+    test_unary!(sqrt_f32s, lanes_f32, f32s, |x| x.sqrt(), |s| s.sqrt(), same_f32);
+    test_unary!(sqrt_f64s, lanes_f64, f64s, |x| x.sqrt(), |s| s.sqrt(), same_f64);
+
+    // Round. Ties are rounded to even, unlike the scalar `round`.
+    test_unary!(round_f32s, lanes_f32, f32s, |x| x.round(), |s| round_even_f32(s), same_f32);
+    test_unary!(round_f64s, lanes_f64, f64s, |x| x.round(), |s| round_even_f64(s), same_f64);
+    test_unary!(ceil_f32s, lanes_f32, f32s, |x| x.ceil(), |s| s.ceil(), same_f32);
+    test_unary!(ceil_f64s, lanes_f64, f64s, |x| x.ceil(), |s| s.ceil(), same_f64);
+    test_unary!(floor_f32s, lanes_f32, f32s, |x| x.floor(), |s| s.floor(), same_f32);
+    test_unary!(floor_f64s, lanes_f64, f64s, |x| x.floor(), |s| s.floor(), same_f64);
+    test_unary!(trunc_f32s, lanes_f32, f32s, |x| x.trunc(), |s| s.trunc(), same_f32);
+    test_unary!(trunc_f64s, lanes_f64, f64s, |x| x.trunc(), |s| s.trunc(), same_f64);
+
+    // Recip & Rsqrt. The hardware flushes results outside of the normal range
+    // to zero or infinity, so only their signs are compared.
+    test_unary!(recip_f32s, lanes_f32, f32s, |x| x.recip(), |s| s.recip(),
+                |a: f32, b: f32| within_ulps_f32(a, b, APPROX_ULPS) ||
+                (!b.is_normal() && b.is_finite() && b != 0.0 && a.is_sign_negative() == b.is_sign_negative()) ||
+                (!s.is_normal() && s.is_finite() && s != 0.0 && a.is_infinite() && a.is_sign_negative() == b.is_sign_negative()));
+    test_unary!(rsqrt_f32s, lanes_f32, f32s, |x| x.rsqrt(), |s| s.sqrt().recip(),
+                |a: f32, b: f32| within_ulps_f32(a, b, APPROX_ULPS) ||
+                (!s.is_normal() && s.is_finite() && s > 0.0 && a.is_infinite() && !a.is_sign_negative()));
+
+    // Cmp. NaN is excluded, as the hardware returns the second operand.
+    test_binary!(max_u8s, lanes_u8, u8s, |x, y| x.max(y), |s, t| Ord::max(s, t), same);
+    test_binary!(max_i8s, lanes_i8, i8s, |x, y| x.max(y), |s, t| Ord::max(s, t), same);
+    test_binary!(max_u16s, lanes_u16, u16s, |x, y| x.max(y), |s, t| Ord::max(s, t), same);
+    test_binary!(max_i16s, lanes_i16, i16s, |x, y| x.max(y), |s, t| Ord::max(s, t), same);
+    test_binary!(max_u32s, lanes_u32, u32s, |x, y| x.max(y), |s, t| Ord::max(s, t), same);
+    test_binary!(max_i32s, lanes_i32, i32s, |x, y| x.max(y), |s, t| Ord::max(s, t), same);
+    test_binary!(max_f32s, lanes_f32, f32s, |x, y| x.max(y), |s, t| s.max(t),
+                 |a: f32, b: f32| s.is_nan() || t.is_nan() || a == b);
+    test_binary!(max_f64s, lanes_f64, f64s, |x, y| x.max(y), |s, t| s.max(t),
+                 |a: f64, b: f64| s.is_nan() || t.is_nan() || a == b);
+    test_binary!(min_u8s, lanes_u8, u8s, |x, y| x.min(y), |s, t| Ord::min(s, t), same);
+    test_binary!(min_i8s, lanes_i8, i8s, |x, y| x.min(y), |s, t| Ord::min(s, t), same);
+    test_binary!(min_u16s, lanes_u16, u16s, |x, y| x.min(y), |s, t| Ord::min(s, t), same);
+    test_binary!(min_i16s, lanes_i16, i16s, |x, y| x.min(y), |s, t| Ord::min(s, t), same);
+    test_binary!(min_u32s, lanes_u32, u32s, |x, y| x.min(y), |s, t| Ord::min(s, t), same);
+    test_binary!(min_i32s, lanes_i32, i32s, |x, y| x.min(y), |s, t| Ord::min(s, t), same);
+    test_binary!(min_f32s, lanes_f32, f32s, |x, y| x.min(y), |s, t| s.min(t),
+                 |a: f32, b: f32| s.is_nan() || t.is_nan() || a == b);
+    test_binary!(min_f64s, lanes_f64, f64s, |x, y| x.min(y), |s, t| s.min(t),
+                 |a: f64, b: f64| s.is_nan() || t.is_nan() || a == b);
+
+    // Eq
+    test_binary!(eq_u8s, lanes_u8, u8s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u8 } else { 0 }, same);
+    test_binary!(eq_i8s, lanes_i8, i8s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u8 } else { 0 }, same);
+    test_binary!(eq_u16s, lanes_u16, u16s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u16 } else { 0 }, same);
+    test_binary!(eq_i16s, lanes_i16, i16s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u16 } else { 0 }, same);
+    test_binary!(eq_u32s, lanes_u32, u32s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u32 } else { 0 }, same);
+    test_binary!(eq_i32s, lanes_i32, i32s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u32 } else { 0 }, same);
+    test_binary!(eq_f32s, lanes_f32, f32s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u32 } else { 0 }, same);
+    test_binary!(eq_u64s, lanes_u64, u64s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u64 } else { 0 }, same);
+    test_binary!(eq_i64s, lanes_i64, i64s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u64 } else { 0 }, same);
+    test_binary!(eq_f64s, lanes_f64, f64s, |x, y| x.eq_mask(y), |s, t| if s == t { !0u64 } else { 0 }, same);
+
+    // SaturatingAdd & SaturatingSub
+    test_binary!(saturating_add_u8s, lanes_u8, u8s, |x, y| x.saturating_add(y), |s, t| s.saturating_add(t), same);
+    test_binary!(saturating_add_i8s, lanes_i8, i8s, |x, y| x.saturating_add(y), |s, t| s.saturating_add(t), same);
+    test_binary!(saturating_add_u16s, lanes_u16, u16s, |x, y| x.saturating_add(y), |s, t| s.saturating_add(t), same);
+    test_binary!(saturating_add_i16s, lanes_i16, i16s, |x, y| x.saturating_add(y), |s, t| s.saturating_add(t), same);
+    test_binary!(saturating_sub_u8s, lanes_u8, u8s, |x, y| x.saturating_sub(y), |s, t| s.saturating_sub(t), same);
+    test_binary!(saturating_sub_i8s, lanes_i8, i8s, |x, y| x.saturating_sub(y), |s, t| s.saturating_sub(t), same);
+    test_binary!(saturating_sub_u16s, lanes_u16, u16s, |x, y| x.saturating_sub(y), |s, t| s.saturating_sub(t), same);
+    test_binary!(saturating_sub_i16s, lanes_i16, i16s, |x, y| x.saturating_sub(y), |s, t| s.saturating_sub(t), same);
+
+    // HAdd & HSub. The fallbacks panic on overflow in debug builds, so
+    // integers are kept within half of their range.
+    test_horizontal!(hadd_i16s, half_lanes_i16, i16s, hadd, |s, t| s + t, same);
+    test_horizontal!(hadd_i32s, half_lanes_i32, i32s, hadd, |s, t| s + t, same);
+    test_horizontal!(hadd_f32s, lanes_f32, f32s, hadd, |s, t| s + t, same_f32);
+    test_horizontal!(hadd_f64s, lanes_f64, f64s, hadd, |s, t| s + t, same_f64);
+    test_horizontal!(hsub_i16s, half_lanes_i16, i16s, hsub, |s, t| s - t, same);
+    test_horizontal!(hsub_i32s, half_lanes_i32, i32s, hsub, |s, t| s - t, same);
+    test_horizontal!(hsub_f32s, lanes_f32, f32s, hsub, |s, t| s - t, same_f32);
+    test_horizontal!(hsub_f64s, lanes_f64, f64s, hsub, |s, t| s - t, same_f64);
+
+    // SaturatingHAdd & SaturatingHSub
+    test_horizontal!(saturating_hadd_i16s, lanes_i16, i16s, saturating_hadd, |s, t| s.saturating_add(t), same);
+    test_horizontal!(saturating_hsub_i16s, lanes_i16, i16s, saturating_hsub, |s, t| s.saturating_sub(t), same);
+
+    // Downcast
+    test_downcast!(downcast_i32s, lanes_i32, i32s, i16s, |s| s.max(-0x8000).min(0x7FFF) as i16, same);
+    test_downcast!(downcast_i16s, lanes_i16, i16s, i8s, |s| s.max(-0x80).min(0x7F) as i8, same);
+    test_downcast!(downcast_u32s, lanes_u32, u32s, u16s, |s| s.min(0xFFFF) as u16, same);
+    test_downcast!(downcast_u16s, lanes_u16, u16s, u8s, |s| s.min(0xFF) as u8, same);
+    test_downcast!(downcast_i64s, lanes_i64, i64s, i32s, |s| s.max(-0x80000000).min(0x7FFFFFFF) as i32, same);
+    test_downcast!(downcast_u64s, lanes_u64, u64s, u32s, |s| s.min(0xFFFFFFFF) as u32, same);
+    test_downcast!(downcast_f64s, lanes_f64, f64s, f32s, |s| s as f32, same_f32);
+
+    // Upcast
+    test_upcast!(upcast_u8s, lanes_u8, u8s, u16s, |s| s as u16, same);
+    test_upcast!(upcast_i8s, lanes_i8, i8s, i16s, |s| s as i16, same);
+    test_upcast!(upcast_u16s, lanes_u16, u16s, u32s, |s| s as u32, same);
+    test_upcast!(upcast_i16s, lanes_i16, i16s, i32s, |s| s as i32, same);
+    test_upcast!(upcast_u32s, lanes_u32, u32s, u64s, |s| s as u64, same);
+    test_upcast!(upcast_i32s, lanes_i32, i32s, i64s, |s| s as i64, same);
+    test_upcast!(upcast_f32s, lanes_f32, f32s, f64s, |s| s as f64, same_f64);
+
+    // Reendianize
+    test_unary!(swap_bytes_u16s, lanes_u16, u16s, |x| x.swap_bytes(), |s| s.swap_bytes(), same);
+    test_unary!(swap_bytes_i16s, lanes_i16, i16s, |x| x.swap_bytes(), |s| s.swap_bytes(), same);
+    test_unary!(swap_bytes_u32s, lanes_u32, u32s, |x| x.swap_bytes(), |s| s.swap_bytes(), same);
+    test_unary!(swap_bytes_i32s, lanes_i32, i32s, |x| x.swap_bytes(), |s| s.swap_bytes(), same);
+    test_unary!(swap_bytes_u64s, lanes_u64, u64s, |x| x.swap_bytes(), |s| s.swap_bytes(), same);
+    test_unary!(swap_bytes_i64s, lanes_i64, i64s, |x| x.swap_bytes(), |s| s.swap_bytes(), same);
+
+    // Reverse
+    test_permute!(reverse_u8s, lanes_u8, u8s, reverse, |i| u8s::WIDTH - 1 - i, same);
+    test_permute!(reverse_i8s, lanes_i8, i8s, reverse, |i| i8s::WIDTH - 1 - i, same);
+    test_permute!(reverse_u16s, lanes_u16, u16s, reverse, |i| u16s::WIDTH - 1 - i, same);
+    test_permute!(reverse_i16s, lanes_i16, i16s, reverse, |i| i16s::WIDTH - 1 - i, same);
+    test_permute!(reverse_u32s, lanes_u32, u32s, reverse, |i| u32s::WIDTH - 1 - i, same);
+    test_permute!(reverse_i32s, lanes_i32, i32s, reverse, |i| i32s::WIDTH - 1 - i, same);
+    test_permute!(reverse_u64s, lanes_u64, u64s, reverse, |i| u64s::WIDTH - 1 - i, same);
+    test_permute!(reverse_i64s, lanes_i64, i64s, reverse, |i| i64s::WIDTH - 1 - i, same);
+    test_permute!(reverse_f32s, lanes_f32, f32s, reverse, |i| f32s::WIDTH - 1 - i, same_f32);
+    test_permute!(reverse_f64s, lanes_f64, f64s, reverse, |i| f64s::WIDTH - 1 - i, same_f64);
+
+    // Swizzle
+    test_permute!(flip_u8s, lanes_u8, u8s, flip, |i| i ^ 1, same);
+    test_permute!(flip_i8s, lanes_i8, i8s, flip, |i| i ^ 1, same);
+    test_permute!(flip_u16s, lanes_u16, u16s, flip, |i| i ^ 1, same);
+    test_permute!(flip_i16s, lanes_i16, i16s, flip, |i| i ^ 1, same);
+    test_permute!(flip_u32s, lanes_u32, u32s, flip, |i| i ^ 1, same);
+    test_permute!(flip_i32s, lanes_i32, i32s, flip, |i| i ^ 1, same);
+    test_permute!(flip_u64s, lanes_u64, u64s, flip, |i| i ^ 1, same);
+    test_permute!(flip_i64s, lanes_i64, i64s, flip, |i| i ^ 1, same);
+    test_permute!(flip_f32s, lanes_f32, f32s, flip, |i| i ^ 1, same_f32);
+    test_permute!(flip_f64s, lanes_f64, f64s, flip, |i| i ^ 1, same_f64);
+
+    // Merge
+    test_lanewise!(merge_halves_u8s, lanes_u8, u8s, |x, y| x.merge_halves(y), |i, s, t| if i < u8s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_i8s, lanes_i8, i8s, |x, y| x.merge_halves(y), |i, s, t| if i < i8s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_u16s, lanes_u16, u16s, |x, y| x.merge_halves(y), |i, s, t| if i < u16s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_i16s, lanes_i16, i16s, |x, y| x.merge_halves(y), |i, s, t| if i < i16s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_u32s, lanes_u32, u32s, |x, y| x.merge_halves(y), |i, s, t| if i < u32s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_i32s, lanes_i32, i32s, |x, y| x.merge_halves(y), |i, s, t| if i < i32s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_u64s, lanes_u64, u64s, |x, y| x.merge_halves(y), |i, s, t| if i < u64s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_i64s, lanes_i64, i64s, |x, y| x.merge_halves(y), |i, s, t| if i < i64s::WIDTH / 2 { s } else { t }, same);
+    test_lanewise!(merge_halves_f32s, lanes_f32, f32s, |x, y| x.merge_halves(y), |i, s, t| if i < f32s::WIDTH / 2 { s } else { t }, same_f32);
+    test_lanewise!(merge_halves_f64s, lanes_f64, f64s, |x, y| x.merge_halves(y), |i, s, t| if i < f64s::WIDTH / 2 { s } else { t }, same_f64);
+    test_lanewise!(merge_interleaved_u8s, lanes_u8, u8s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_i8s, lanes_i8, i8s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_u16s, lanes_u16, u16s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_i16s, lanes_i16, i16s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_u32s, lanes_u32, u32s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_i32s, lanes_i32, i32s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_u64s, lanes_u64, u64s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_i64s, lanes_i64, i64s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same);
+    test_lanewise!(merge_interleaved_f32s, lanes_f32, f32s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same_f32);
+    test_lanewise!(merge_interleaved_f64s, lanes_f64, f64s, |x, y| x.merge_interleaved(y), |i, s, t| if i % 2 == 0 { s } else { t }, same_f64);
+    test_partitioned!(merge_partitioned_u8s, lanes_u8, u8s, same);
+    test_partitioned!(merge_partitioned_i8s, lanes_i8, i8s, same);
+    test_partitioned!(merge_partitioned_u16s, lanes_u16, u16s, same);
+    test_partitioned!(merge_partitioned_i16s, lanes_i16, i16s, same);
+    test_partitioned!(merge_partitioned_u32s, lanes_u32, u32s, same);
+    test_partitioned!(merge_partitioned_i32s, lanes_i32, i32s, same);
+    test_partitioned!(merge_partitioned_u64s, lanes_u64, u64s, same);
+    test_partitioned!(merge_partitioned_i64s, lanes_i64, i64s, same);
+    test_partitioned!(merge_partitioned_f32s, lanes_f32, f32s, same_f32);
+    test_partitioned!(merge_partitioned_f64s, lanes_f64, f64s, same_f64);
+
+    // Destride
+    test_destride!(destride_u8s, lanes_u8, u8s, same);
+    test_destride!(destride_i8s, lanes_i8, i8s, same);
+    test_destride!(destride_u16s, lanes_u16, u16s, same);
+    test_destride!(destride_i16s, lanes_i16, i16s, same);
+    test_destride!(destride_u32s, lanes_u32, u32s, same);
+    test_destride!(destride_i32s, lanes_i32, i32s, same);
+    test_destride!(destride_u64s, lanes_u64, u64s, same);
+    test_destride!(destride_i64s, lanes_i64, i64s, same);
+    test_destride!(destride_f32s, lanes_f32, f32s, same_f32);
+    test_destride!(destride_f64s, lanes_f64, f64s, same_f64);
+
+    // Gather
+    test_gather!(gather_u8s, lanes_u8, u8s, same);
+    test_gather!(gather_i8s, lanes_i8, i8s, same);
+    test_gather!(gather_u16s, lanes_u16, u16s, same);
+    test_gather!(gather_i16s, lanes_i16, i16s, same);
+    test_gather!(gather_u32s, lanes_u32, u32s, same);
+    test_gather!(gather_i32s, lanes_i32, i32s, same);
+    test_gather!(gather_u64s, lanes_u64, u64s, same);
+    test_gather!(gather_i64s, lanes_i64, i64s, same);
+    test_gather!(gather_f32s, lanes_f32, f32s, same_f32);
+    test_gather!(gather_f64s, lanes_f64, f64s, same_f64);
+
+    // Sum. Integers are kept small enough that their sum never overflows, and
+    // floats are kept integral so that their sum is exact in any order.
+    test_reduce!(sum_u8s, sum_lanes_u8, u8s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_i8s, sum_lanes_i8, i8s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_u16s, sum_lanes_u16, u16s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_i16s, sum_lanes_i16, i16s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_u32s, sum_lanes_u32, u32s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_i32s, sum_lanes_i32, i32s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_u64s, sum_lanes_u64, u64s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_i64s, sum_lanes_i64, i64s, |x| x.sum(), |a| a.iter().fold(0, |acc, s| acc + s), same);
+    test_reduce!(sum_f32s, integral_lanes_f32, f32s, |x| x.sum(), |a| a.iter().fold(0.0, |acc, s| acc + s), same);
+    test_reduce!(sum_f64s, integral_lanes_f64, f64s, |x| x.sum(), |a| a.iter().fold(0.0, |acc, s| acc + s), same);
+
+    // UpcastSum
+    test_reduce!(sum_upcast_u8s, lanes_u8, u8s, |x| x.sum_upcast(), |a| a.iter().map(|&s| s as i64).sum::<i64>(), same);
+    test_reduce!(sum_upcast_i8s, lanes_i8, i8s, |x| x.sum_upcast(), |a| a.iter().map(|&s| s as i64).sum::<i64>(), same);
+    test_reduce!(sum_upcast_u16s, lanes_u16, u16s, |x| x.sum_upcast(), |a| a.iter().map(|&s| s as i64).sum::<i64>(), same);
+    test_reduce!(sum_upcast_i16s, lanes_i16, i16s, |x| x.sum_upcast(), |a| a.iter().map(|&s| s as i64).sum::<i64>(), same);
+    test_reduce!(sum_upcast_i32s, lanes_i32, i32s, |x| x.sum_upcast(), |a| a.iter().map(|&s| s as i64).sum::<i64>(), same);
+
+    // Product
+    test_reduce!(product_u32s, factor_lanes_u32, u32s, |x| x.product(), |a| a.iter().fold(1 as u32, |acc, s| acc * s), same);
+    test_reduce!(product_i32s, factor_lanes_i32, i32s, |x| x.product(), |a| a.iter().fold(1 as i32, |acc, s| acc * s), same);
+    test_reduce!(product_u64s, factor_lanes_u64, u64s, |x| x.product(), |a| a.iter().fold(1 as u64, |acc, s| acc * s), same);
+    test_reduce!(product_i64s, factor_lanes_i64, i64s, |x| x.product(), |a| a.iter().fold(1 as i64, |acc, s| acc * s), same);
+    test_reduce!(product_f32s, factor_lanes_f32, f32s, |x| x.product(), |a| a.iter().fold(1 as f32, |acc, s| acc * s), same);
+    test_reduce!(product_f64s, factor_lanes_f64, f64s, |x| x.product(), |a| a.iter().fold(1 as f64, |acc, s| acc * s), same);
+
+    // AddSub
+    test_lanewise!(addsub_f32s, lanes_f32, f32s, |x, y| x.addsub(y), |i, s, t| if i % 2 == 0 { s - t } else { s + t }, same_f32);
+    test_lanewise!(addsub_f64s, lanes_f64, f64s, |x, y| x.addsub(y), |i, s, t| if i % 2 == 0 { s - t } else { s + t }, same_f64);
+
+    // Cast. Floats are kept integral, as converting them to an integer which
+    // cannot hold them is undefined.
+    test_unary!(as_i8s_u8s, lanes_u8, u8s, |x| x.as_i8s(), |s| s as i8, same);
+    test_unary!(as_u8s_i8s, lanes_i8, i8s, |x| x.as_u8s(), |s| s as u8, same);
+    test_unary!(as_i16s_u16s, lanes_u16, u16s, |x| x.as_i16s(), |s| s as i16, same);
+    test_unary!(as_u16s_i16s, lanes_i16, i16s, |x| x.as_u16s(), |s| s as u16, same);
+    test_unary!(as_i32s_u32s, lanes_u32, u32s, |x| x.as_i32s(), |s| s as i32, same);
+    test_unary!(as_u32s_i32s, lanes_i32, i32s, |x| x.as_u32s(), |s| s as u32, same);
+    test_unary!(as_f32s_u32s, lanes_u32, u32s, |x| x.as_f32s(), |s| s as f32, same_f32);
+    test_unary!(as_f32s_i32s, lanes_i32, i32s, |x| x.as_f32s(), |s| s as f32, same_f32);
+    test_unary!(as_i64s_u64s, lanes_u64, u64s, |x| x.as_i64s(), |s| s as i64, same);
+    test_unary!(as_u64s_i64s, lanes_i64, i64s, |x| x.as_u64s(), |s| s as u64, same);
+    test_unary!(as_f64s_u64s, lanes_u64, u64s, |x| x.as_f64s(), |s| s as f64, same_f64);
+    test_unary!(as_f64s_i64s, lanes_i64, i64s, |x| x.as_f64s(), |s| s as f64, same_f64);
+    test_unary!(as_i32s_f32s, integral_lanes_f32, f32s, |x| x.as_i32s(), |s| s as i32, same);
+    test_unary!(as_i64s_f64s, integral_lanes_f64, f64s, |x| x.as_i64s(), |s| s as i64, same);
+
+    // Widen
+    test_widen!(widen_u8s_u16s, lanes_u8, u8s, u16s, |s| s as u16, same);
+    test_widen!(widen_u8s_u32s, lanes_u8, u8s, u32s, |s| s as u32, same);
+    test_widen!(widen_u8s_u64s, lanes_u8, u8s, u64s, |s| s as u64, same);
+    test_widen!(widen_u8s_f32s, lanes_u8, u8s, f32s, |s| s as f32, same_f32);
+    test_widen!(widen_i8s_i16s, lanes_i8, i8s, i16s, |s| s as i16, same);
+    test_widen!(widen_i8s_i32s, lanes_i8, i8s, i32s, |s| s as i32, same);
+    test_widen!(widen_i8s_i64s, lanes_i8, i8s, i64s, |s| s as i64, same);
+    test_widen!(widen_i8s_f32s, lanes_i8, i8s, f32s, |s| s as f32, same_f32);
+    test_widen!(widen_i8s_f64s, lanes_i8, i8s, f64s, |s| s as f64, same_f64);
+    test_widen!(widen_u16s_u32s, lanes_u16, u16s, u32s, |s| s as u32, same);
+    test_widen!(widen_u16s_u64s, lanes_u16, u16s, u64s, |s| s as u64, same);
+    test_widen!(widen_u16s_f32s, lanes_u16, u16s, f32s, |s| s as f32, same_f32);
+    test_widen!(widen_i16s_i32s, lanes_i16, i16s, i32s, |s| s as i32, same);
+    test_widen!(widen_i16s_i64s, lanes_i16, i16s, i64s, |s| s as i64, same);
+    test_widen!(widen_i16s_f32s, lanes_i16, i16s, f32s, |s| s as f32, same_f32);
+    test_widen!(widen_i16s_f64s, lanes_i16, i16s, f64s, |s| s as f64, same_f64);
+    test_widen!(widen_u32s_u64s, lanes_u32, u32s, u64s, |s| s as u64, same);
+    test_widen!(widen_i32s_i64s, lanes_i32, i32s, i64s, |s| s as i64, same);
+    test_widen!(widen_i32s_f64s, lanes_i32, i32s, f64s, |s| s as f64, same_f64);
+    test_widen!(widen_f32s_f64s, lanes_f32, f32s, f64s, |s| s as f64, same_f64);
+
+    proptest! {
+        #[test]
+        fn count_ones_u8s(a in lanes_u8()) {
+            let expected = a.iter().map(|s| s.count_ones() as usize).sum::<usize>();
+            prop_assert_eq!(u8s::from_slice(&a).count_ones(), expected);
+        }
+
+        #[test]
+        fn count_ones_i64s(a in lanes_i64()) {
+            let expected = a.iter().map(|s| s.count_ones() as usize).sum::<usize>();
+            prop_assert_eq!(i64s::from_slice(&a).count_ones(), expected);
+        }
+    }
+}
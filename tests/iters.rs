@@ -37,4 +37,26 @@ mod tests {
         assert_eq!(sum, 2 * 129);
     }
 
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn fixed_width_iteration() {
+        for len in 0..20 {
+            let vec: Vec<f32> = (0..len).map(|x| x as f32).collect();
+            let doubled = vec.simd_iter_as(f32x4::splat(0.0))
+                .simd_map(|v| v * f32x4::splat(2.0))
+                .scalar_collect();
+            assert_eq!(doubled, vec.iter().map(|x| x * 2.0).collect::<Vec<f32>>());
+
+            let mut bytes: Vec<u8> = (0..len).map(|x| x as u8).collect();
+            bytes.simd_iter_as_mut(u8x16::splat(0))
+                .simd_for_each(|x| *x += u8x16::splat(1));
+            assert_eq!(bytes, (0..len).map(|x| x as u8 + 1).collect::<Vec<u8>>());
+
+            let sum = vec.clone().into_simd_iter_as(f32x8::splat(0.0))
+                .simd_reduce(f32x8::splat(0.0), |acc, v| acc + v)
+                .sum();
+            assert_eq!(sum, vec.iter().sum::<f32>());
+        }
+    }
+
 }
@@ -67,4 +67,121 @@ mod tests {
         assert_eq!(result, 2 * 3 * 129);
     }
 
+    #[test]
+    fn try_zip_mismatch() {
+        let vec1 = [2u32; 129];
+        let vec2 = [3u32; 128];
+        let vec3 = [4u32; 129];
+        assert!((vec1.simd_iter(u32s(0)), vec3.simd_iter(u32s(0))).try_zip().is_ok());
+        let err = (vec1.simd_iter(u32s(0)), vec3.simd_iter(u32s(0)), vec2.simd_iter(u32s(0))).try_zip().err();
+        assert_eq!(err, Some(ZipLengthError { index: 2, expected: 129, found: 128 }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_mismatch() {
+        let vec1 = [2u32; 129];
+        let vec2 = [3u32; 128];
+        (vec1.simd_iter(u32s(0)), vec2.simd_iter(u32s(0))).zip();
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn zip_policies() {
+        for short in 0..40 {
+            for long in short..40 {
+                let a = (0..short).map(|i| i as u32).collect::<Vec<u32>>();
+                let b = (0..long).map(|i| i as u32 * 100).collect::<Vec<u32>>();
+
+                let shortest = (a.simd_iter(u32s(0)), b.simd_iter(u32s(0)))
+                    .zip_with_policy(ZipPolicy::Shortest)
+                    .simd_map(|(a, b)| a + b)
+                    .scalar_collect();
+                assert_eq!(shortest, (0..short).map(|i| i as u32 * 101).collect::<Vec<u32>>());
+
+                let padded = (b.simd_iter(u32s(0)), a.simd_iter(u32s(7)))
+                    .zip_with_policy(ZipPolicy::PadWithDefault)
+                    .simd_map(|(b, a)| a + b)
+                    .scalar_collect();
+                assert_eq!(padded, (0..long).map(|i| if i < short { i as u32 * 101 } else { i as u32 * 100 + 7 }).collect::<Vec<u32>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn zip_unpack() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| i as u32).collect::<Vec<u32>>();
+            let b = (0..len).map(|i| i as f32 * 0.5).collect::<Vec<f32>>();
+            let unpacked = (a.simd_iter(u32s(0)), b.simd_iter(f32s(0.0)))
+                .zip()
+                .unpack()
+                .collect::<Vec<(u32, f32)>>();
+            assert_eq!(unpacked, a.iter().cloned().zip(b.iter().cloned()).collect::<Vec<(u32, f32)>>());
+        }
+    }
+
+    #[test]
+    fn zip_simd_reduce_tuple() {
+        let vec1 = [2.0f32; 129];
+        let vec2 = [3.0f32; 129];
+        let (sum, sum_sq) = (vec1.simd_iter(f32s(0.0)), vec2.simd_iter(f32s(0.0))).zip()
+            .simd_reduce((f32s(0.0), f32s(0.0)), |(s, sq), (x, y)| (s + x + y, sq + x * x + y * y));
+        assert_eq!(sum.sum(), 5.0 * 129.0);
+        assert_eq!(sum_sq.sum(), 13.0 * 129.0);
+    }
+
+    #[test]
+    fn zip_simd_do_each() {
+        let vec1 = [2u32; 129];
+        let vec2 = [3u32; 129];
+        let mut total = 0;
+        (vec1.simd_iter(u32s(0)), vec2.simd_iter(u32s(0))).zip()
+            .simd_do_each(|(x, y)| total += (x * y).sum());
+        assert_eq!(total, 2 * 3 * 129);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn zip_map_tuple_collect() {
+        for len in 0..100 {
+            let a = (0..len).map(|i| i as f32).collect::<Vec<f32>>();
+            let b = (0..len).map(|i| i as f32 * 2.0).collect::<Vec<f32>>();
+            let (sums, products) = (a.simd_iter(f32s(0.0)), b.simd_iter(f32s(0.0))).zip()
+                .simd_map(|(a, b)| (a + b, a * b))
+                .scalar_collect();
+            assert_eq!(sums, (0..len).map(|i| i as f32 * 3.0).collect::<Vec<f32>>());
+            assert_eq!(products, (0..len).map(|i| (i * i) as f32 * 2.0).collect::<Vec<f32>>());
+        }
+    }
+
+    #[test]
+    fn zip_map_tuple_fill() {
+        let a = [3u32; 37];
+        let b = [5u32; 37];
+        let mut sums = [0u32; 37];
+        let mut diffs = [0u32; 37];
+        let mut products = [0u32; 37];
+        (a.simd_iter(u32s(0)), b.simd_iter(u32s(0))).zip()
+            .simd_map(|(a, b)| (a + b, b - a, a * b))
+            .scalar_fill((&mut sums[..], &mut diffs[..], &mut products[..]));
+        assert_eq!(&sums[..], &[8u32; 37][..]);
+        assert_eq!(&diffs[..], &[2u32; 37][..]);
+        assert_eq!(&products[..], &[15u32; 37][..]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn zip_next_back() {
+        let a = (0..67).map(|i| i as u32).collect::<Vec<u32>>();
+        let b = (0..67).map(|i| i as u32 * 2).collect::<Vec<u32>>();
+        let mut zip = (a.simd_iter(u32s(0)), b.simd_iter(u32s(0))).zip();
+        let width = u32s::WIDTH;
+        let (x, y) = zip.next_back().unwrap();
+        assert_eq!(x.extract(0), (67 - width) as u32);
+        assert_eq!(y.extract(0), (67 - width) as u32 * 2);
+        let sum = zip.simd_reduce(u32s(0), |acc, (x, y)| acc + x + y).sum();
+        assert_eq!(sum, (0..(67 - width) as u32).map(|i| i * 3).sum::<u32>());
+    }
 }